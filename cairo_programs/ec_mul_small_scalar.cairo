@@ -0,0 +1,20 @@
+%builtins range_check
+
+from starkware.cairo.common.cairo_secp.ec import EcPoint, ec_mul
+from starkware.cairo.common.cairo_secp.bigint import BigInt3
+
+func main{range_check_ptr: felt}() {
+    // secp256k1 generator
+    let g = EcPoint(
+        BigInt3(17117865558768631194064792, 12501176021340589225372855, 9198697782662356105779718),
+        BigInt3(6441780312434748884571320, 57953919405111227542741658, 5457536640262350763842127),
+    );
+
+    // 3 * G
+    let (res) = ec_mul(g, BigInt3(3, 0, 0));
+    assert res = EcPoint(
+        BigInt3(57545291876987742944507641, 75066192660561802595210765, 18828234277447069677687620),
+        BigInt3(2583640362791394057184882, 38197615293098406611150035, 4273588397735691711217203),
+    );
+    return ();
+}
@@ -0,0 +1,20 @@
+%builtins output ec_op
+
+from starkware.cairo.common.cairo_builtins import EcOpBuiltin
+from starkware.cairo.common.ec import ec_op
+from starkware.cairo.common.ec_point import EcPoint
+from starkware.cairo.common.serialize import serialize_word
+
+func main{output_ptr: felt*, ec_op_ptr: EcOpBuiltin*}() {
+    // STARK curve generator
+    let g = EcPoint(
+        x=0x1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca,
+        y=0x5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f,
+    );
+
+    // ec_op adds a random point before using the builtin and subtracts it afterwards
+    let (r) = ec_op(g, 3, g);
+    serialize_word(r.x);
+    serialize_word(r.y);
+    return ();
+}
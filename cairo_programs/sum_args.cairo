@@ -0,0 +1,21 @@
+from starkware.cairo.common.alloc import alloc
+
+// Returns a + b plus the sum of the arr_len elements of arr.
+func sum_args(a, b, arr: felt*, arr_len) -> (res: felt) {
+    if (arr_len == 0) {
+        return (res=a + b);
+    }
+
+    let (rest) = sum_args(a=a, b=b, arr=arr + 1, arr_len=arr_len - 1);
+    return (res=rest + [arr]);
+}
+
+func main() {
+    let (arr) = alloc();
+    assert [arr] = 3;
+    assert [arr + 1] = 4;
+
+    let (res) = sum_args(a=1, b=2, arr=arr, arr_len=2);
+    assert res = 10;
+    return ();
+}
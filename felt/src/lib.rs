@@ -1,6 +1,7 @@
 mod bigint_felt;
 
-use bigint_felt::FeltBigInt;
+use bigint_felt::{FeltBigInt, CAIRO_PRIME};
+use lazy_static::lazy_static;
 use num_bigint::{BigInt, BigUint, U64Digits};
 use num_integer::Integer;
 use num_traits::{Bounded, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
@@ -19,6 +20,12 @@ pub const PRIME_STR: &str = "0x8000000000000110000000000000000000000000000000000
 pub const FIELD_HIGH: u128 = (1 << 123) + (17 << 64);
 pub const FIELD_LOW: u128 = 1;
 
+lazy_static! {
+    // Inverse of the Montgomery radix 2**256 modulo the prime
+    static ref MONTGOMERY_R_INV: BigUint = (BigUint::one() << 256_u32)
+        .modpow(&(&*CAIRO_PRIME - 2_u32), &CAIRO_PRIME);
+}
+
 pub(crate) trait FeltOps {
     fn new<T: Into<FeltBigInt<FIELD_HIGH, FIELD_LOW>>>(value: T) -> Self;
     fn modpow(
@@ -51,6 +58,23 @@ macro_rules! felt_str {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ParseFeltError;
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MathError {
+    NotABoolean(Felt),
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathError::NotABoolean(value) => {
+                write!(f, "Expected a boolean (0 or 1), got {value}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
 #[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Clone, Deserialize, Default, Serialize)]
 pub struct Felt {
     value: FeltBigInt<FIELD_HIGH, FIELD_LOW>,
@@ -130,6 +154,39 @@ impl Felt {
     pub fn bits(&self) -> u64 {
         self.value.bits()
     }
+    /// Returns the prime of the field felts live in (`PRIME_STR` as an integer).
+    pub fn prime() -> BigUint {
+        CAIRO_PRIME.clone()
+    }
+    /// Returns the Montgomery form of the felt (`self * 2**256 mod PRIME`) as little-endian
+    /// 64-bit limbs.
+    pub fn to_montgomery(&self) -> [u64; 4] {
+        let montgomery = (self.to_biguint() << 256_u32).mod_floor(&CAIRO_PRIME);
+        let mut limbs = [0; 4];
+        for (limb, digit) in limbs.iter_mut().zip(montgomery.iter_u64_digits()) {
+            *limb = digit;
+        }
+        limbs
+    }
+    /// Builds a felt from its Montgomery form, given as little-endian 64-bit limbs.
+    pub fn from_montgomery(limbs: [u64; 4]) -> Self {
+        let montgomery = limbs
+            .iter()
+            .rev()
+            .fold(BigUint::zero(), |acc, &limb| (acc << 64_u32) + limb);
+        Felt::new(montgomery * &*MONTGOMERY_R_INV)
+    }
+    /// Reads the felt as a boolean flag: `0` is `false`, `1` is `true` and any other value is
+    /// an error.
+    pub fn to_bool(&self) -> Result<bool, MathError> {
+        if self.is_zero() {
+            Ok(false)
+        } else if self.is_one() {
+            Ok(true)
+        } else {
+            Err(MathError::NotABoolean(self.clone()))
+        }
+    }
 }
 
 impl Add for Felt {
@@ -779,6 +836,50 @@ mod test {
     use super::*;
     use proptest::prelude::*;
 
+    #[test]
+    fn prime_matches_minus_one() {
+        assert_eq!(Felt::prime() - 1_u32, Felt::new(-1).to_biguint());
+        assert_eq!(
+            Felt::prime(),
+            BigUint::parse_bytes(PRIME_STR[2..].as_bytes(), 16).unwrap()
+        );
+    }
+
+    #[test]
+    fn montgomery_representation_of_one() {
+        //2**256 mod PRIME
+        let one_montgomery = [
+            18446744073709551585,
+            18446744073709551615,
+            18446744073709551615,
+            576460752303422960,
+        ];
+        assert_eq!(Felt::one().to_montgomery(), one_montgomery);
+        assert_eq!(Felt::from_montgomery(one_montgomery), Felt::one());
+        assert_eq!(Felt::zero().to_montgomery(), [0; 4]);
+    }
+
+    #[test]
+    fn montgomery_round_trip() {
+        for value in [Felt::new(2), Felt::new(-1), Felt::new(u64::MAX) << 128_u32] {
+            assert_eq!(Felt::from_montgomery(value.to_montgomery()), value);
+        }
+    }
+
+    #[test]
+    fn to_bool_accepts_only_zero_and_one() {
+        assert_eq!(Felt::zero().to_bool(), Ok(false));
+        assert_eq!(Felt::one().to_bool(), Ok(true));
+        assert_eq!(
+            Felt::new(2).to_bool(),
+            Err(MathError::NotABoolean(Felt::new(2)))
+        );
+        assert_eq!(
+            Felt::new(-1).to_bool(),
+            Err(MathError::NotABoolean(Felt::new(-1)))
+        );
+    }
+
     proptest! {
         #[test]
         // Property-based test that ensures, for 100 felt values that are randomly generated each time tests are run, that a new felt doesn't fall outside the range [0, p].
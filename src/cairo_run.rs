@@ -1,16 +1,18 @@
 use crate::{
     hint_processor::hint_processor_definition::HintProcessor,
-    types::program::Program,
+    types::{program::Program, relocatable::MaybeRelocatable},
     vm::{
         errors::{
-            cairo_run_errors::CairoRunError, runner_errors::RunnerError, vm_exception::VmException,
+            cairo_run_errors::CairoRunError, runner_errors::RunnerError, trace_errors::TraceError,
+            vm_exception::VmException,
         },
-        runners::cairo_runner::CairoRunner,
+        runners::cairo_runner::{CairoArg, CairoRunConfig, CairoRunner},
         trace::trace_entry::RelocatedTraceEntry,
         vm_core::VirtualMachine,
     },
 };
-use felt::Felt;
+use felt::{Felt, ParseFeltError};
+use num_bigint::BigUint;
 use std::{
     fs::File,
     io::{self, BufWriter, Error, ErrorKind, Write},
@@ -31,7 +33,53 @@ pub fn cairo_run(
         Err(error) => return Err(CairoRunError::Program(error)),
     };
 
-    let mut cairo_runner = CairoRunner::new(&program, layout, proof_mode)?;
+    run_program(
+        &program,
+        trace_enabled,
+        print_output,
+        layout,
+        proof_mode,
+        CairoRunConfig::default(),
+        hint_executor,
+    )
+}
+
+/*
+   Runs a program given as the bytes of its compiled json, with the trace enabled and the
+   settings in `run_config`, and returns the relocated trace, as needed by prover integrations.
+*/
+pub fn cairo_run_and_get_trace(
+    program_bytes: &[u8],
+    entrypoint: &str,
+    layout: &str,
+    proof_mode: bool,
+    run_config: CairoRunConfig,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<Vec<RelocatedTraceEntry>, CairoRunError> {
+    let program = Program::from_reader(program_bytes, Some(entrypoint))?;
+    run_program(
+        &program,
+        true,
+        false,
+        layout,
+        proof_mode,
+        run_config,
+        hint_executor,
+    )?
+    .relocated_trace
+    .ok_or(CairoRunError::Trace(TraceError::TraceNotEnabled))
+}
+
+fn run_program(
+    program: &Program,
+    trace_enabled: bool,
+    print_output: bool,
+    layout: &str,
+    proof_mode: bool,
+    run_config: CairoRunConfig,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<CairoRunner, CairoRunError> {
+    let mut cairo_runner = CairoRunner::new_with_config(program, layout, proof_mode, run_config)?;
     let mut vm = VirtualMachine::new(trace_enabled);
     let end = cairo_runner.initialize(&mut vm)?;
 
@@ -54,6 +102,109 @@ pub fn cairo_run(
     Ok(cairo_runner)
 }
 
+/*
+   Runs the function `entrypoint` with the given arguments, which are pushed onto the stack after
+   the pointers of the builtins used by the program.
+   Returns the runner and the vm so the return values can be read from memory.
+*/
+pub fn cairo_run_with_args(
+    path: &Path,
+    entrypoint: &str,
+    args: &[CairoArg],
+    trace_enabled: bool,
+    print_output: bool,
+    layout: &str,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<(CairoRunner, VirtualMachine), CairoRunError> {
+    let program = Program::from_file(path, Some(entrypoint))?;
+    let entrypoint_pc = program.main.ok_or(RunnerError::MissingMain)?;
+
+    let mut cairo_runner = CairoRunner::new(&program, layout, false)?;
+    let mut vm = VirtualMachine::new(trace_enabled);
+    cairo_runner.initialize_builtins(&mut vm)?;
+    cairo_runner.initialize_segments(&mut vm, None);
+
+    let builtin_args: Vec<CairoArg> = vm
+        .builtin_runners
+        .iter()
+        .flat_map(|(_name, builtin_runner)| builtin_runner.initial_stack())
+        .map(CairoArg::from)
+        .collect();
+    let stack: Vec<&CairoArg> = builtin_args.iter().chain(args).collect();
+    cairo_runner
+        .run_from_entrypoint(entrypoint_pc, &stack, false, &mut vm, hint_executor)
+        .map_err(|err| VmException::from_vm_error(&cairo_runner, &vm, err))?;
+    cairo_runner.relocate(&mut vm)?;
+
+    if print_output {
+        write_output(&mut cairo_runner, &mut vm)?;
+    }
+
+    Ok((cairo_runner, vm))
+}
+
+/*
+   Parses program arguments given as comma separated felts, with arrays enclosed in brackets,
+   e.g. `1,2,[3,4,5],3`. Felts are written in decimal, or in hexadecimal with a `0x` prefix.
+*/
+pub fn parse_cairo_args(args: &str) -> Result<Vec<CairoArg>, ParseFeltError> {
+    let mut parsed = Vec::new();
+    if args.trim().is_empty() {
+        return Ok(parsed);
+    }
+
+    let mut array: Option<Vec<MaybeRelocatable>> = None;
+    for token in args.split(',').map(str::trim) {
+        let (opens, token) = match token.strip_prefix('[') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, token),
+        };
+        let (closes, token) = match token.strip_suffix(']') {
+            Some(rest) => (true, rest.trim_end()),
+            None => (false, token),
+        };
+
+        if opens {
+            if array.is_some() {
+                return Err(ParseFeltError);
+            }
+            array = Some(Vec::new());
+        }
+        if token.is_empty() {
+            // Only an empty array, `[]`, has no value inside
+            if !(opens && closes) {
+                return Err(ParseFeltError);
+            }
+        } else {
+            let value = MaybeRelocatable::from(parse_felt_arg(token)?);
+            match array.as_mut() {
+                Some(values) => values.push(value),
+                None => parsed.push(CairoArg::Single(value)),
+            }
+        }
+        if closes {
+            let values = array.take().ok_or(ParseFeltError)?;
+            parsed.push(CairoArg::Array(values));
+        }
+    }
+
+    if array.is_some() {
+        return Err(ParseFeltError);
+    }
+    Ok(parsed)
+}
+
+fn parse_felt_arg(token: &str) -> Result<Felt, ParseFeltError> {
+    let (digits, radix) = match token.strip_prefix("0x") {
+        Some(hex) => (hex, 16),
+        None => (token, 10),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(ParseFeltError);
+    }
+    Felt::parse_bytes(digits.as_bytes(), radix).ok_or(ParseFeltError)
+}
+
 pub fn write_output(
     cairo_runner: &mut CairoRunner,
     vm: &mut VirtualMachine,
@@ -88,17 +239,85 @@ pub fn write_binary_trace(
     buffer.flush()
 }
 
+/// Encoding used to serialize memory values, so memory dumps can target provers expecting
+/// different formats.
+pub trait FeltCodec {
+    fn encode(&self, f: &Felt) -> Vec<u8>;
+    fn decode(&self, bytes: &[u8]) -> Result<Felt, ParseFeltError>;
+}
+
+/// Encodes felts as 32 little endian bytes. This is the format used by the Cairo prover.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LittleEndianCodec;
+
+/// Encodes felts as 32 big endian bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BigEndianCodec;
+
+const FELT_ENCODING_SIZE: usize = 32;
+
+fn felt_to_bytes_be(f: &Felt) -> Vec<u8> {
+    let bytes = f.to_bytes_be();
+    let mut padded = vec![0; FELT_ENCODING_SIZE - bytes.len()];
+    padded.extend(bytes);
+    padded
+}
+
+fn felt_from_bytes_be(bytes: &[u8]) -> Result<Felt, ParseFeltError> {
+    // Only canonical representations are accepted, values aren't reduced modulo the prime
+    if bytes.len() != FELT_ENCODING_SIZE || BigUint::from_bytes_be(bytes) >= Felt::prime() {
+        return Err(ParseFeltError);
+    }
+    Ok(Felt::from_bytes_be(bytes))
+}
+
+impl FeltCodec for LittleEndianCodec {
+    fn encode(&self, f: &Felt) -> Vec<u8> {
+        let mut bytes = felt_to_bytes_be(f);
+        bytes.reverse();
+        bytes
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Felt, ParseFeltError> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        felt_from_bytes_be(&bytes)
+    }
+}
+
+impl FeltCodec for BigEndianCodec {
+    fn encode(&self, f: &Felt) -> Vec<u8> {
+        felt_to_bytes_be(f)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Felt, ParseFeltError> {
+        felt_from_bytes_be(bytes)
+    }
+}
+
 /*
    Writes a binary memory file with the relocated memory as input.
    The memory pairs (address, value) are encoded and concatenated in the file
    given by the path `memory_file`.
 
    * address -> 8-byte encoded
-   * value -> 32-byte encoded
+   * value -> 32-byte little endian encoded
 */
 pub fn write_binary_memory(
     relocated_memory: &[Option<Felt>],
     memory_file: &Path,
+) -> io::Result<()> {
+    write_encoded_memory(relocated_memory, memory_file, &LittleEndianCodec)
+}
+
+/*
+   Same as `write_binary_memory`, but values are encoded with the given `codec`.
+   Addresses are always written as 8 little endian bytes.
+*/
+pub fn write_encoded_memory(
+    relocated_memory: &[Option<Felt>],
+    memory_file: &Path,
+    codec: &dyn FeltCodec,
 ) -> io::Result<()> {
     let file = File::create(memory_file)?;
     let mut buffer = BufWriter::new(file);
@@ -110,7 +329,7 @@ pub fn write_binary_memory(
         match memory_cell {
             None => continue,
             Some(unwrapped_memory_cell) => {
-                encode_relocated_memory(&mut memory_bytes, i, unwrapped_memory_cell);
+                encode_relocated_memory(&mut memory_bytes, i, unwrapped_memory_cell, codec);
             }
         }
     }
@@ -120,14 +339,18 @@ pub fn write_binary_memory(
 }
 
 // encodes a given memory cell.
-fn encode_relocated_memory(memory_bytes: &mut Vec<u8>, addr: usize, memory_cell: &Felt) {
+fn encode_relocated_memory(
+    memory_bytes: &mut Vec<u8>,
+    addr: usize,
+    memory_cell: &Felt,
+    codec: &dyn FeltCodec,
+) {
     // append memory address to bytes vector using a 8 bytes representation
     let mut addr_bytes = (addr as u64).to_le_bytes().to_vec();
     memory_bytes.append(&mut addr_bytes);
 
-    // append memory value at address using a 32 bytes representation
-    let mut value_bytes = memory_cell.to_signed_bytes_le();
-    value_bytes.resize(32, 0);
+    // append memory value at address using the codec's representation
+    let mut value_bytes = codec.encode(memory_cell);
     memory_bytes.append(&mut value_bytes);
 }
 
@@ -308,6 +531,135 @@ mod tests {
         assert!(compare_files(cairo_rs_memory_path, expected_memory_path).is_ok());
     }
 
+    #[test]
+    fn memory_matches_python_dump() {
+        let program_path = Path::new("cairo_programs/struct.json");
+        let expected_memory_path = Path::new("cairo_programs/trace_memory/cairo_memory_struct");
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let (_, vm) = run_test_program(program_path, &mut hint_processor).unwrap();
+        assert_eq!(vm.memory.assert_matches_dump(expected_memory_path), Ok(()));
+    }
+
+    #[test]
+    fn parse_cairo_args_singles_and_arrays() {
+        assert_eq!(
+            parse_cairo_args("1, 0x10,[3,4, 5], [], [7]"),
+            Ok(vec![
+                CairoArg::Single(Felt::new(1).into()),
+                CairoArg::Single(Felt::new(16).into()),
+                CairoArg::Array(vec![
+                    Felt::new(3).into(),
+                    Felt::new(4).into(),
+                    Felt::new(5).into()
+                ]),
+                CairoArg::Array(Vec::new()),
+                CairoArg::Array(vec![Felt::new(7).into()]),
+            ])
+        );
+        assert_eq!(parse_cairo_args(""), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn parse_cairo_args_invalid() {
+        for args in ["1,,2", "[1,2", "1]", "[1,[2]]", "0x", "abc", "-1"] {
+            assert_eq!(parse_cairo_args(args), Err(ParseFeltError), "{args}");
+        }
+    }
+
+    #[test]
+    fn cairo_run_with_args_sums_arguments() {
+        let args = parse_cairo_args("1,2,[3,4,5],3").unwrap();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let (_, vm) = cairo_run_with_args(
+            Path::new("cairo_programs/sum_args.json"),
+            "sum_args",
+            &args,
+            false,
+            false,
+            "plain",
+            &mut hint_processor,
+        )
+        .unwrap();
+        //The function returns the sum as its only return value
+        assert_eq!(
+            vm.get_integer(&vm.get_ap().sub_usize(1).unwrap())
+                .unwrap()
+                .as_ref(),
+            &Felt::new(15)
+        );
+    }
+
+    #[test]
+    fn little_endian_codec_round_trip() {
+        let codec = LittleEndianCodec;
+        let value = Felt::new(0x0102_u32);
+        let bytes = codec.encode(&value);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(&bytes[..3], &[0x02, 0x01, 0x00]);
+        assert_eq!(codec.decode(&bytes), Ok(value));
+        let max = Felt::new(-1);
+        assert_eq!(codec.decode(&codec.encode(&max)), Ok(max));
+    }
+
+    #[test]
+    fn big_endian_codec_round_trip() {
+        let codec = BigEndianCodec;
+        let value = Felt::new(0x0102_u32);
+        let bytes = codec.encode(&value);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(&bytes[29..], &[0x00, 0x01, 0x02]);
+        assert_eq!(codec.decode(&bytes), Ok(value));
+        let max = Felt::new(-1);
+        assert_eq!(codec.decode(&codec.encode(&max)), Ok(max));
+    }
+
+    #[test]
+    fn codecs_reject_non_canonical_bytes() {
+        assert_eq!(LittleEndianCodec.decode(&[1, 2, 3]), Err(ParseFeltError));
+        assert_eq!(BigEndianCodec.decode(&[0xff; 32]), Err(ParseFeltError));
+    }
+
+    #[test]
+    fn cairo_run_and_get_trace_returns_every_step() {
+        let program_path = Path::new("cairo_programs/struct.json");
+        let program_bytes = std::fs::read(program_path).unwrap();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let trace = cairo_run_and_get_trace(
+            &program_bytes,
+            "main",
+            "plain",
+            false,
+            CairoRunConfig::default(),
+            &mut hint_processor,
+        )
+        .unwrap();
+        let (_, vm) = run_test_program(program_path, &mut hint_processor).unwrap();
+        assert_eq!(trace.len(), vm.current_step);
+    }
+
+    #[test]
+    fn cairo_run_and_get_trace_uses_run_config() {
+        //print.json declares the output builtin, which the plain layout doesn't have
+        let program_bytes = std::fs::read("cairo_programs/print.json").unwrap();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut run = |run_config: CairoRunConfig| {
+            cairo_run_and_get_trace(
+                &program_bytes,
+                "main",
+                "plain",
+                false,
+                run_config,
+                &mut hint_processor,
+            )
+        };
+        assert!(run(CairoRunConfig::default()).is_err());
+        let trace = run(CairoRunConfig {
+            allow_missing_builtins: true,
+            ..Default::default()
+        });
+        assert!(!trace.unwrap().is_empty());
+    }
+
     #[test]
     fn run_with_no_trace() {
         let program_path = Path::new("cairo_programs/struct.json");
@@ -2,7 +2,9 @@ use crate::{
     hint_processor::{
         builtin_hint_processor::{
             blake2s_hash::{blake2s_compress, IV},
-            hint_utils::{get_ptr_from_var_name, get_relocatable_from_var_name},
+            hint_utils::{
+                get_integer_from_var_name, get_ptr_from_var_name, get_relocatable_from_var_name,
+            },
         },
         hint_processor_definition::HintReference,
         hint_processor_utils::felt_to_u32,
@@ -121,6 +123,37 @@ pub fn finalize_blake2s(
     Ok(())
 }
 
+/* Implements Hint:
+    from starkware.cairo.common.cairo_blake2s.blake2s_utils import IV
+
+    _output_length = int(ids.output_length)
+    assert 0 < _output_length <= 32
+    initial_state = [IV[0] ^ 0x01010000 ^ _output_length] + IV[1:]
+    segments.write_arg(ids.blake2s_state, initial_state)
+
+The first word of the parameter block holds the output length, a zero key length,
+and a fanout and depth of 1.
+*/
+pub fn blake2s_init(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let blake2s_state = get_ptr_from_var_name("blake2s_state", vm, ids_data, ap_tracking)?;
+    let output_length =
+        get_integer_from_var_name("output_length", vm, ids_data, ap_tracking)?.into_owned();
+    let output_length = match output_length.to_u32() {
+        Some(length) if (1..=32).contains(&length) => length,
+        _ => return Err(HintError::InvalidBlake2sOutputLength(output_length)),
+    };
+    let mut initial_state = IV;
+    initial_state[0] = IV[0] ^ 0x01010000 ^ output_length;
+    let data = get_maybe_relocatable_array_from_u32(&initial_state.to_vec());
+    vm.load_data(&MaybeRelocatable::RelocatableValue(blake2s_state), &data)
+        .map_err(VirtualMachineError::MemoryError)?;
+    Ok(())
+}
+
 /* Implements Hint:
     B = 32
     MASK = 2 ** 32 - 1
@@ -424,6 +457,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn blake2s_init_default_output_length() {
+        let hint_code = "from starkware.cairo.common.cairo_blake2s.blake2s_utils import IV\n\n_output_length = int(ids.output_length)\nassert 0 < _output_length <= 32\ninitial_state = [IV[0] ^ 0x01010000 ^ _output_length] + IV[1:]\nsegments.write_arg(ids.blake2s_state, initial_state)";
+        //Create vm
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 2;
+        //Insert ids into memory
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), 32)];
+        add_segments!(vm, 1);
+        let ids_data = ids_data!["blake2s_state", "output_length"];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check the initial state: IV with the first word XORed with the parameter block
+        //(digest length = 32, key length = 0, fanout = 1, depth = 1)
+        let data = get_fixed_size_u32_array::<8>(
+            &vm.memory.get_integer_range(&relocatable!(2, 0), 8).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            data,
+            [
+                0x6B08E647, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB,
+                0x5BE0CD19
+            ]
+        );
+    }
+
+    #[test]
+    fn blake2s_init_invalid_output_length() {
+        let hint_code = "from starkware.cairo.common.cairo_blake2s.blake2s_utils import IV\n\n_output_length = int(ids.output_length)\nassert 0 < _output_length <= 32\ninitial_state = [IV[0] ^ 0x01010000 ^ _output_length] + IV[1:]\nsegments.write_arg(ids.blake2s_state, initial_state)";
+        //Create vm
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 2;
+        //Insert ids into memory
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), 33)];
+        add_segments!(vm, 1);
+        let ids_data = ids_data!["blake2s_state", "output_length"];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::InvalidBlake2sOutputLength(Felt::new(33)))
+        );
+    }
+
     #[test]
     fn blake2s_add_uint256_valid_zero() {
         let hint_code = "B = 32\nMASK = 2 ** 32 - 1\nsegments.write_arg(ids.data, [(ids.low >> (B * i)) & MASK for i in range(4)])\nsegments.write_arg(ids.data + 4, [(ids.high >> (B * i)) & MASK for i in range(4)]";
@@ -2,11 +2,13 @@ use crate::{
     hint_processor::{
         builtin_hint_processor::{
             blake2s_utils::{
-                blake2s_add_uint256, blake2s_add_uint256_bigend, compute_blake2s, finalize_blake2s,
+                blake2s_add_uint256, blake2s_add_uint256_bigend, blake2s_init, compute_blake2s,
+                finalize_blake2s,
             },
             cairo_keccak::keccak_hints::{
                 block_permutation, cairo_keccak_finalize, compare_bytes_in_word_nondet,
-                compare_keccak_full_rate_in_bytes_nondet, keccak_write_args,
+                compare_keccak_full_rate_in_bytes_nondet, finalize_keccak, keccak_pad,
+                keccak_squeeze, keccak_write_args,
             },
             dict_hint_utils::{
                 default_dict_new, dict_new, dict_read, dict_squash_copy_dict,
@@ -14,6 +16,7 @@ use crate::{
             },
             find_element_hint::{find_element, search_sorted_lower},
             hint_code,
+            jump_table::jump_table_offset,
             keccak_utils::{unsafe_keccak, unsafe_keccak_finalize},
             math_utils::*,
             memcpy_hint_utils::{
@@ -21,20 +24,26 @@ use crate::{
             },
             memset_utils::{memset_continue_loop, memset_enter_scope},
             pow_utils::pow,
+            print::{parse_print_hint, print_vars},
             secp::{
-                bigint_utils::{bigint_to_uint256, nondet_bigint3},
+                bigint_utils::{
+                    bigint_pack_div_mod, bigint_safe_div, bigint_to_uint256, nondet_bigint3,
+                },
                 ec_utils::{
                     compute_doubling_slope, compute_slope, ec_double_assign_new_x,
                     ec_double_assign_new_y, ec_mul_inner, ec_negate, fast_ec_add_assign_new_x,
                     fast_ec_add_assign_new_y,
                 },
                 field_utils::{
-                    is_zero_assign_scope_variables, is_zero_nondet, is_zero_pack, reduce,
-                    verify_zero,
+                    is_zero_assign_scope_variables, is_zero_nondet, is_zero_pack, reduce, sqrt_mod,
+                    verify_nonzero, verify_zero, verify_zero_with_external_const,
+                },
+                signature::{
+                    div_mod_n_packed_divmod, div_mod_n_packed_external_n, div_mod_n_safe_div,
+                    get_point_from_x,
                 },
-                signature::{div_mod_n_packed_divmod, div_mod_n_safe_div, get_point_from_x},
             },
-            segments::{relocate_segment, temporary_array},
+            segments::{array_length, relocate_segment, temporary_array},
             set::set_add,
             sha256_utils::{sha256_finalize, sha256_input, sha256_main},
             signature::verify_ecdsa_signature,
@@ -46,7 +55,9 @@ use crate::{
                 squash_dict_inner_used_accesses_assert,
             },
             uint256_utils::{
-                split_64, uint256_add, uint256_signed_nn, uint256_sqrt, uint256_unsigned_div_rem,
+                assert_uint256_le, assert_uint256_mul_no_overflow, split_64, uint256_add,
+                uint256_and, uint256_mul_div_mod, uint256_or, uint256_signed_nn, uint256_sqrt,
+                uint256_unsigned_div_rem, uint256_xor,
             },
             usort::{
                 usort_body, usort_enter_scope, verify_multiplicity_assert,
@@ -56,11 +67,12 @@ use crate::{
         hint_processor_definition::{HintProcessor, HintReference},
     },
     serde::deserialize_program::ApTracking,
-    types::exec_scope::ExecutionScopes,
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
 };
 use felt::Felt;
-use std::{any::Any, collections::HashMap, rc::Rc};
+use serde_json::json;
+use std::{any::Any, collections::HashMap, io::Write, rc::Rc, time::Instant};
 
 #[cfg(feature = "skip_next_instruction_hint")]
 use crate::hint_processor::builtin_hint_processor::skip_next_instruction::skip_next_instruction;
@@ -96,35 +108,53 @@ pub struct HintFunc(
 );
 pub struct BuiltinHintProcessor {
     pub extra_hints: HashMap<String, Rc<HintFunc>>,
+    print_sink: Box<dyn Write>,
+    hint_callback: Option<Box<dyn FnMut(&str, Relocatable)>>,
+    metrics_sink: Option<Box<dyn Write>>,
 }
 impl BuiltinHintProcessor {
     pub fn new_empty() -> Self {
-        BuiltinHintProcessor {
-            extra_hints: HashMap::new(),
-        }
+        BuiltinHintProcessor::new(HashMap::new())
     }
 
     pub fn new(extra_hints: HashMap<String, Rc<HintFunc>>) -> Self {
-        BuiltinHintProcessor { extra_hints }
+        BuiltinHintProcessor {
+            extra_hints,
+            print_sink: Box::new(std::io::stderr()),
+            hint_callback: None,
+            metrics_sink: None,
+        }
     }
 
     pub fn add_hint(&mut self, hint_code: String, hint_func: Rc<HintFunc>) {
         self.extra_hints.insert(hint_code, hint_func);
     }
-}
 
-impl HintProcessor for BuiltinHintProcessor {
-    fn execute_hint(
+    // Sets where the values of `print(ids.<name>)` debug hints are written (stderr by default)
+    pub fn set_print_sink(&mut self, print_sink: Box<dyn Write>) {
+        self.print_sink = print_sink;
+    }
+
+    // Sets a function called before executing each hint with its code and the current pc
+    pub fn set_hint_callback(&mut self, hint_callback: Box<dyn FnMut(&str, Relocatable)>) {
+        self.hint_callback = Some(hint_callback);
+    }
+
+    /// Streams an event for each executed hint to the sink, as a json line with the hint's code,
+    /// the pc and the execution time in nanoseconds. Disabled unless a sink is set.
+    /// Failing to write an event fails the hint with `HintError::MetricsSinkWriteFailed`, unless
+    /// the hint itself already failed.
+    pub fn set_metrics_sink(&mut self, metrics_sink: Box<dyn Write>) {
+        self.metrics_sink = Some(metrics_sink);
+    }
+
+    fn execute_hint_data(
         &mut self,
         vm: &mut VirtualMachine,
         exec_scopes: &mut ExecutionScopes,
-        hint_data: &Box<dyn Any>,
+        hint_data: &HintProcessorData,
         constants: &HashMap<String, Felt>,
     ) -> Result<(), HintError> {
-        let hint_data = hint_data
-            .downcast_ref::<HintProcessorData>()
-            .ok_or(HintError::WrongHintData)?;
-
         if let Some(hint_func) = self.extra_hints.get(&hint_data.code) {
             return hint_func.0(
                 vm,
@@ -155,11 +185,23 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::ASSERT_250_BITS => {
                 assert_250_bit(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::IS_250_BITS => is_250_bits(vm, &hint_data.ids_data, &hint_data.ap_tracking),
             hint_code::IS_POSITIVE => is_positive(vm, &hint_data.ids_data, &hint_data.ap_tracking),
             hint_code::SPLIT_INT_ASSERT_RANGE => {
                 split_int_assert_range(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
-            hint_code::SPLIT_INT => split_int(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::SPLIT_INT => split_int(
+                vm,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+                DigitOrder::LeastSignificantFirst,
+            ),
+            hint_code::SPLIT_INT_MOST_SIGNIFICANT_FIRST => split_int(
+                vm,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+                DigitOrder::MostSignificantFirst,
+            ),
             hint_code::ASSERT_NOT_EQUAL => {
                 assert_not_equal(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
@@ -184,16 +226,35 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::MEMSET_CONTINUE_LOOP => {
                 memset_continue_loop(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
             }
-            hint_code::SPLIT_FELT => split_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::SPLIT_FELT => {
+                split_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+            }
+            hint_code::JOIN_SPLIT_OUTPUT => {
+                join_split_output(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::ADD_OUTPUT_PAGE => {
+                add_output_page(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::UNSIGNED_DIV_REM => {
                 unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::SAFE_DIV => safe_div_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::IDS_DIFFERENCE => {
+                ids_difference(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::SIGNED_DIV_REM => {
                 signed_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
             hint_code::ASSERT_LT_FELT => {
                 assert_lt_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::ASSERT_LT_FELT_SIGNED => {
+                assert_lt_felt_signed(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::RECOVER_Y => recover_y(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::RANDOM_EC_POINT => {
+                random_ec_point(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::FIND_ELEMENT => {
                 find_element(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
             }
@@ -238,6 +299,12 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::VERIFY_ZERO => {
                 verify_zero(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
             }
+            hint_code::VERIFY_ZERO_EXTERNAL_SECP => verify_zero_with_external_const(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            ),
             hint_code::NONDET_BIGINT3 => nondet_bigint3(
                 vm,
                 exec_scopes,
@@ -255,6 +322,9 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::BLAKE2S_FINALIZE => {
                 finalize_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::BLAKE2S_INIT => {
+                blake2s_init(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::BLAKE2S_ADD_UINT256 => {
                 blake2s_add_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
@@ -326,9 +396,21 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::UINT256_SIGNED_NN => {
                 uint256_signed_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::ASSERT_UINT256_LE => {
+                assert_uint256_le(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::UINT256_UNSIGNED_DIV_REM => {
                 uint256_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::UINT256_MUL_DIV_MOD => {
+                uint256_mul_div_mod(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::ASSERT_UINT256_MUL_NO_OVERFLOW => {
+                assert_uint256_mul_no_overflow(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::UINT256_AND => uint256_and(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::UINT256_OR => uint256_or(vm, &hint_data.ids_data, &hint_data.ap_tracking),
+            hint_code::UINT256_XOR => uint256_xor(vm, &hint_data.ids_data, &hint_data.ap_tracking),
             hint_code::BIGINT_TO_UINT256 => {
                 bigint_to_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
             }
@@ -343,6 +425,20 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::IS_ZERO_ASSIGN_SCOPE_VARS => {
                 is_zero_assign_scope_variables(exec_scopes, constants)
             }
+            hint_code::VERIFY_NONZERO => verify_nonzero(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+                constants,
+            ),
+            hint_code::SQRT_MOD => sqrt_mod(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+                constants,
+            ),
             hint_code::DIV_MOD_N_PACKED_DIVMOD => div_mod_n_packed_divmod(
                 vm,
                 exec_scopes,
@@ -350,7 +446,19 @@ impl HintProcessor for BuiltinHintProcessor {
                 &hint_data.ap_tracking,
                 constants,
             ),
+            hint_code::DIV_MOD_N_PACKED_DIVMOD_EXTERNAL_N => div_mod_n_packed_external_n(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            ),
             hint_code::DIV_MOD_N_SAFE_DIV => div_mod_n_safe_div(exec_scopes, constants),
+            hint_code::BIGINT_PACK_DIV_MOD => {
+                bigint_pack_div_mod(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::BIGINT_SAFE_DIV => {
+                bigint_safe_div(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::GET_POINT_FROM_X => get_point_from_x(
                 vm,
                 exec_scopes,
@@ -411,12 +519,21 @@ impl HintProcessor for BuiltinHintProcessor {
                     constants,
                 )
             }
+            hint_code::KECCAK_PAD => {
+                keccak_pad(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+            }
             hint_code::BLOCK_PERMUTATION => {
                 block_permutation(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
             }
+            hint_code::KECCAK_SQUEEZE => {
+                keccak_squeeze(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+            }
             hint_code::CAIRO_KECCAK_FINALIZE => {
                 cairo_keccak_finalize(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
             }
+            hint_code::FINALIZE_KECCAK => {
+                finalize_keccak(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+            }
             hint_code::FAST_EC_ADD_ASSIGN_NEW_X => fast_ec_add_assign_new_x(
                 vm,
                 exec_scopes,
@@ -431,6 +548,12 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::RELOCATE_SEGMENT => {
                 relocate_segment(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::ARRAY_LENGTH => {
+                array_length(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
+            hint_code::JUMP_TABLE_OFFSET => {
+                jump_table_offset(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             hint_code::TEMPORARY_ARRAY => {
                 temporary_array(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
@@ -439,11 +562,58 @@ impl HintProcessor for BuiltinHintProcessor {
             }
             #[cfg(feature = "skip_next_instruction_hint")]
             hint_code::SKIP_NEXT_INSTRUCTION => skip_next_instruction(vm),
-            code => Err(HintError::UnknownHint(code.to_string())),
+            code => {
+                if let Some(names) = parse_print_hint(code) {
+                    return print_vars(
+                        vm,
+                        &names,
+                        &hint_data.ids_data,
+                        &hint_data.ap_tracking,
+                        self.print_sink.as_mut(),
+                    );
+                }
+                match parse_cairo1_hint(code) {
+                    Some(hint) => execute_cairo1_hint(vm, exec_scopes, &hint),
+                    None => Err(HintError::UnknownHint(code.to_string())),
+                }
+            }
         }
     }
 }
 
+impl HintProcessor for BuiltinHintProcessor {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, Felt>,
+    ) -> Result<(), HintError> {
+        let hint_data = hint_data
+            .downcast_ref::<HintProcessorData>()
+            .ok_or(HintError::WrongHintData)?;
+
+        if let Some(hint_callback) = self.hint_callback.as_mut() {
+            hint_callback(&hint_data.code, *vm.get_pc());
+        }
+
+        let start = self.metrics_sink.is_some().then(Instant::now);
+        let pc = *vm.get_pc();
+        let result = self.execute_hint_data(vm, exec_scopes, hint_data, constants);
+        if let (Some(metrics_sink), Some(start)) = (self.metrics_sink.as_mut(), start) {
+            let event = json!({
+                "code": hint_data.code,
+                "pc": pc.to_string(),
+                "duration_ns": start.elapsed().as_nanos() as u64,
+            });
+            let written = writeln!(metrics_sink, "{}", event)
+                .map_err(|err| HintError::MetricsSinkWriteFailed(err.to_string()));
+            return result.and(written);
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -865,4 +1035,117 @@ mod tests {
         );
         assert_eq!(exec_scopes.data.len(), 3);
     }
+
+    #[test]
+    fn hint_callback_fires_for_each_hint() {
+        let path = std::path::Path::new("cairo_programs/split_felt.json");
+        let program = crate::types::program::Program::from_file(path, Some("main")).unwrap();
+        let calls = Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let recorded = calls.clone();
+        hint_processor.set_hint_callback(Box::new(move |code, pc| {
+            recorded.borrow_mut().push((code.to_string(), pc))
+        }));
+        assert!(crate::cairo_run::cairo_run(
+            path,
+            "main",
+            false,
+            false,
+            "all",
+            false,
+            &mut hint_processor
+        )
+        .is_ok());
+        let calls = calls.borrow();
+        assert!(!calls.is_empty());
+        for (code, pc) in calls.iter() {
+            //The program is loaded in segment 0, so the pc offset indexes the program's hints
+            assert_eq!(pc.segment_index, 0);
+            assert!(program.hints[&pc.offset]
+                .iter()
+                .any(|hint| &hint.code == code));
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct CapturedSink(Rc<std::cell::RefCell<Vec<u8>>>);
+
+    impl Write for CapturedSink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct BrokenSink;
+
+    impl Write for BrokenSink {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn metrics_sink_write_errors_are_reported() {
+        let hint_code = "memory[ap] = segments.add()";
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        hint_processor.set_metrics_sink(Box::new(BrokenSink));
+        assert_eq!(
+            hint_processor.execute_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &any_box!(HintProcessorData::new_default(
+                    hint_code.to_string(),
+                    HashMap::new()
+                )),
+                &HashMap::new(),
+            ),
+            Err(HintError::MetricsSinkWriteFailed(
+                std::io::Error::from(std::io::ErrorKind::BrokenPipe).to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn metrics_sink_streams_one_json_line_per_hint() {
+        let path = std::path::Path::new("cairo_programs/split_felt.json");
+        let program = crate::types::program::Program::from_file(path, Some("main")).unwrap();
+        let sink = CapturedSink::default();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        hint_processor.set_metrics_sink(Box::new(sink.clone()));
+        assert!(crate::cairo_run::cairo_run(
+            path,
+            "main",
+            false,
+            false,
+            "all",
+            false,
+            &mut hint_processor
+        )
+        .is_ok());
+        let output = String::from_utf8(sink.0.borrow().clone()).unwrap();
+        let events: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(!events.is_empty());
+        for event in events.iter() {
+            let (segment, offset) = event["pc"].as_str().unwrap().split_once(':').unwrap();
+            assert_eq!(segment, "0");
+            assert!(program.hints[&offset.parse::<usize>().unwrap()]
+                .iter()
+                .any(|hint| event["code"] == hint.code));
+            assert!(event["duration_ns"].is_number());
+        }
+    }
 }
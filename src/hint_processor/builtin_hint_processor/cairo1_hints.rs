@@ -0,0 +1,257 @@
+use crate::{
+    types::{exec_scope::ExecutionScopes, instruction::Register, relocatable::Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+
+/*
+Programs compiled from Cairo 1 (Sierra -> CASM) carry their hints as structured values, which
+the compiler renders as python code when emitting a program for this runner. The hints used to
+allocate segments and `Box` values are recognized here from that rendering. Panic results need
+no hint: the compiled code writes the enum discriminant and payload with regular instructions.
+*/
+
+/// A `[ap + offset]` or `[fp + offset]` cell, as written by the Cairo 1 compiler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellRef {
+    pub register: Register,
+    pub offset: i32,
+}
+
+/// A Cairo 1 hint recognized by [`parse_cairo1_hint`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Cairo1Hint {
+    /// `memory[<dst>] = segments.add()`
+    AllocSegment { dst: CellRef },
+    /// Allocates `size` cells for a boxed value, all boxes sharing the `__boxed_segment` segment
+    AllocConstantSize { dst: CellRef, size: usize },
+}
+
+// Parses the `[ap + 0]`, `[fp + -3]` or `[fp - 3]` forms of a cell
+fn parse_cell_ref(cell: &str) -> Option<CellRef> {
+    let cell = cell.strip_prefix('[')?.strip_suffix(']')?.trim();
+    let (register, offset) = if let Some((register, offset)) = cell.split_once('+') {
+        (register, offset.trim().parse().ok()?)
+    } else if let Some((register, offset)) = cell.split_once('-') {
+        (register, -offset.trim().parse::<i32>().ok()?)
+    } else {
+        (cell, 0)
+    };
+    let register = match register.trim() {
+        "ap" => Register::AP,
+        "fp" => Register::FP,
+        _ => return None,
+    };
+    Some(CellRef { register, offset })
+}
+
+/*
+Recognizes the hints:
+%{ memory[<dst>] = segments.add() %}
+and
+%{
+    if '__boxed_segment' not in globals():
+        __boxed_segment = segments.add()
+    memory[<dst>] = __boxed_segment
+    __boxed_segment += <size>
+%}
+Indentation and blank lines are ignored. Any other code returns None.
+*/
+pub fn parse_cairo1_hint(code: &str) -> Option<Cairo1Hint> {
+    let lines: Vec<&str> = code
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    match lines.as_slice() {
+        [line] => {
+            let dst = line
+                .strip_prefix("memory")?
+                .strip_suffix("= segments.add()")?;
+            Some(Cairo1Hint::AllocSegment {
+                dst: parse_cell_ref(dst.trim())?,
+            })
+        }
+        ["if '__boxed_segment' not in globals():", "__boxed_segment = segments.add()", dst, size] =>
+        {
+            let dst = dst
+                .strip_prefix("memory")?
+                .strip_suffix("= __boxed_segment")?;
+            let size = size
+                .strip_prefix("__boxed_segment +=")?
+                .trim()
+                .parse()
+                .ok()?;
+            Some(Cairo1Hint::AllocConstantSize {
+                dst: parse_cell_ref(dst.trim())?,
+                size,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn cell_address(vm: &VirtualMachine, cell: &CellRef) -> Result<Relocatable, HintError> {
+    let base = match cell.register {
+        Register::AP => vm.get_ap(),
+        Register::FP => vm.get_fp(),
+    };
+    Ok(base.add_int(cell.offset)?)
+}
+
+// The boxed segment is kept in the current scope, as python keeps it in the hint's globals
+pub fn execute_cairo1_hint(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint: &Cairo1Hint,
+) -> Result<(), HintError> {
+    match hint {
+        Cairo1Hint::AllocSegment { dst } => {
+            let dst = cell_address(vm, dst)?;
+            let segment = vm.add_memory_segment();
+            vm.insert_value(&dst, segment)?;
+        }
+        Cairo1Hint::AllocConstantSize { dst, size } => {
+            let dst = cell_address(vm, dst)?;
+            let boxed_segment = if exec_scopes
+                .get_local_variables()?
+                .contains_key("__boxed_segment")
+            {
+                exec_scopes.get::<Relocatable>("__boxed_segment")?
+            } else {
+                vm.add_memory_segment()
+            };
+            vm.insert_value(&dst, boxed_segment)?;
+            exec_scopes.insert_value("__boxed_segment", boxed_segment + *size);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
+        serde::deserialize_program::{ApTracking, FlowTrackingData, HintParams},
+        types::{program::Program, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::runners::cairo_runner::CairoRunner,
+    };
+    use felt::Felt;
+    use std::collections::HashMap;
+
+    const ALLOC_BOX: &str = "
+        if '__boxed_segment' not in globals():
+            __boxed_segment = segments.add()
+        memory[ap + 0] = __boxed_segment
+        __boxed_segment += 2
+    ";
+
+    #[test]
+    fn parse_alloc_segment_hint() {
+        assert_eq!(
+            parse_cairo1_hint("memory[ap + 0] = segments.add()"),
+            Some(Cairo1Hint::AllocSegment {
+                dst: CellRef {
+                    register: Register::AP,
+                    offset: 0
+                }
+            })
+        );
+        assert_eq!(
+            parse_cairo1_hint("memory[fp + -3] = segments.add()"),
+            Some(Cairo1Hint::AllocSegment {
+                dst: CellRef {
+                    register: Register::FP,
+                    offset: -3
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn parse_alloc_constant_size_hint() {
+        assert_eq!(
+            parse_cairo1_hint(ALLOC_BOX),
+            Some(Cairo1Hint::AllocConstantSize {
+                dst: CellRef {
+                    register: Register::AP,
+                    offset: 0
+                },
+                size: 2
+            })
+        );
+    }
+
+    #[test]
+    fn parse_cairo1_hint_rejects_other_code() {
+        assert_eq!(parse_cairo1_hint(""), None);
+        assert_eq!(parse_cairo1_hint("memory[bp + 0] = segments.add()"), None);
+        assert_eq!(parse_cairo1_hint("print(ids.x)"), None);
+        assert_eq!(
+            parse_cairo1_hint(&ALLOC_BOX.replace("+= 2", "+= two")),
+            None
+        );
+    }
+
+    #[test]
+    fn alloc_constant_size_shares_the_boxed_segment() {
+        let mut vm = vm!();
+        vm.run_context.ap = 0;
+        add_segments!(vm, 2);
+        let mut exec_scopes = ExecutionScopes::new();
+        let hint = parse_cairo1_hint(ALLOC_BOX).unwrap();
+        execute_cairo1_hint(&mut vm, &mut exec_scopes, &hint).unwrap();
+        vm.run_context.ap = 1;
+        execute_cairo1_hint(&mut vm, &mut exec_scopes, &hint).unwrap();
+        //Both boxes live in the same new segment, two cells apart
+        check_memory![vm.memory, ((1, 0), (2, 0)), ((1, 1), (2, 2))];
+        assert_eq!(
+            exec_scopes.get::<Relocatable>("__boxed_segment"),
+            Ok(Relocatable::from((2, 4)))
+        );
+    }
+
+    #[test]
+    fn run_program_returning_a_boxed_value() {
+        //Minimal CASM for `fn main() -> Box<felt252> { BoxTrait::new(5) }`:
+        //  %{ boxed segment allocation into [ap + 0], size 1 %}
+        //  [ap + 1] = 5
+        //  [ap + 1] = [[ap + 0]], ap++
+        //  ret
+        let program = Program {
+            data: vec![
+                MaybeRelocatable::from(Felt::new(0x400680017fff8001_i64)),
+                MaybeRelocatable::from(Felt::new(5)),
+                MaybeRelocatable::from(Felt::new(0x4800800080008001_i64)),
+                MaybeRelocatable::from(Felt::new(0x208b7fff7fff7ffe_i64)),
+            ],
+            main: Some(0),
+            hints: HashMap::from([(
+                0,
+                vec![HintParams {
+                    code: ALLOC_BOX.replace("+= 2", "+= 1"),
+                    accessible_scopes: Vec::new(),
+                    flow_tracking_data: FlowTrackingData {
+                        ap_tracking: ApTracking::new(),
+                        reference_ids: HashMap::new(),
+                    },
+                }],
+            )]),
+            ..Default::default()
+        };
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut cairo_runner = cairo_runner!(program, "plain");
+        let mut vm = vm!();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        assert_eq!(
+            cairo_runner.run_until_pc(end, &mut vm, &mut hint_processor),
+            Ok(())
+        );
+        //The returned pointer points to the boxed 5
+        let boxed = vm.get_return_values(1).unwrap()[0]
+            .get_relocatable()
+            .unwrap();
+        assert_eq!(vm.get_integer(&boxed).unwrap().as_ref(), &Felt::new(5));
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     hint_processor::{
-        builtin_hint_processor::hint_utils::{
-            get_integer_from_var_name, get_ptr_from_var_name, insert_value_into_ap,
+        builtin_hint_processor::{
+            cairo_keccak::keccak_permutation::keccak_f1600,
+            hint_utils::{get_integer_from_var_name, get_ptr_from_var_name, insert_value_into_ap},
         },
         hint_processor_definition::HintReference,
     },
@@ -112,6 +113,51 @@ pub fn compare_keccak_full_rate_in_bytes_nondet(
     insert_value_into_ap(vm, value)
 }
 
+/*
+Implements hint:
+    %{
+        _rate = int(ids.KECCAK_FULL_RATE_IN_BYTES)
+        _pad_len = _rate - ids.n_bytes % _rate
+        padding = [0x01] + [0] * (_pad_len - 1)
+        padding[-1] |= 0x80
+        segments.write_arg(ids.input + ids.n_bytes, padding)
+    %}
+*/
+pub fn keccak_pad(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
+) -> Result<(), HintError> {
+    let input_ptr = get_ptr_from_var_name("input", vm, ids_data, ap_tracking)?;
+    let n_bytes = get_integer_from_var_name("n_bytes", vm, ids_data, ap_tracking)?;
+    let n_bytes_usize = n_bytes
+        .to_usize()
+        .ok_or_else(|| HintError::InvalidKeccakByteCount(n_bytes.clone().into_owned()))?;
+
+    let rate = constants
+        .get(KECCAK_FULL_RATE_IN_BYTES)
+        .ok_or(HintError::MissingConstant(KECCAK_FULL_RATE_IN_BYTES))?;
+    let rate = match rate.to_usize() {
+        Some(rate) if rate > 0 && rate <= 200 => rate,
+        _ => return Err(HintError::InvalidKeccakRate(rate.clone())),
+    };
+
+    // An input filling its last block exactly gets a whole block of padding
+    let pad_len = rate - n_bytes_usize % rate;
+    let mut padding = vec![0_u8; pad_len];
+    padding[0] = 0x01;
+    padding[pad_len - 1] |= 0x80;
+
+    let padding: Vec<MaybeRelocatable> = padding
+        .into_iter()
+        .map(|byte| Felt::new(byte).into())
+        .collect();
+    vm.write_arg(&(input_ptr + n_bytes_usize), &padding)
+        .map_err(VirtualMachineError::MemoryError)?;
+    Ok(())
+}
+
 /*
 Implements hint:
     %{
@@ -154,9 +200,8 @@ pub fn block_permutation(
         .try_into()
         .map_err(|_| VirtualMachineError::SliceToArrayError)?;
 
-    // this function of the keccak crate is the one used instead of keccak_func from
-    // keccak_utils.py
-    keccak::f1600(&mut u64_values);
+    // this function is the one used instead of keccak_func from keccak_utils.py
+    keccak_f1600(&mut u64_values);
 
     let bigint_values = u64_array_to_mayberelocatable_vec(&u64_values);
 
@@ -166,6 +211,73 @@ pub fn block_permutation(
     Ok(())
 }
 
+/*
+Implements hint:
+    %{
+        from starkware.cairo.common.cairo_keccak.keccak_utils import keccak_func
+        _rate = int(ids.KECCAK_FULL_RATE_IN_BYTES)
+        assert 0 < _rate <= 200
+        _state = memory.get_range(ids.keccak_state, 25)
+        _output = []
+        while True:
+            _output += b''.join(w.to_bytes(8, 'little') for w in _state)[:_rate]
+            if len(_output) >= ids.n_bytes:
+                break
+            _state = keccak_func(_state)
+        segments.write_arg(ids.output, _output[:ids.n_bytes])
+    %}
+Squeezes ids.n_bytes bytes out of the 25-lane state at ids.keccak_state, one byte per cell.
+Outputs longer than the rate permute the state again for each extra block.
+*/
+pub fn keccak_squeeze(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
+) -> Result<(), HintError> {
+    let rate = constants
+        .get(KECCAK_FULL_RATE_IN_BYTES)
+        .ok_or(HintError::MissingConstant(KECCAK_FULL_RATE_IN_BYTES))?;
+    let rate = match rate.to_usize() {
+        Some(rate) if rate > 0 && rate <= 200 => rate,
+        _ => return Err(HintError::InvalidKeccakRate(rate.clone())),
+    };
+
+    let n_bytes = get_integer_from_var_name("n_bytes", vm, ids_data, ap_tracking)?;
+    let n_bytes = n_bytes
+        .to_usize()
+        .ok_or_else(|| HintError::InvalidKeccakByteCount(n_bytes.clone().into_owned()))?;
+
+    let state_ptr = get_ptr_from_var_name("keccak_state", vm, ids_data, ap_tracking)?;
+    let values = vm
+        .get_range(&MaybeRelocatable::RelocatableValue(state_ptr), 25)
+        .map_err(VirtualMachineError::MemoryError)?;
+    let mut state: [u64; 25] = maybe_reloc_vec_to_u64_array(&values)?
+        .try_into()
+        .map_err(|_| VirtualMachineError::SliceToArrayError)?;
+
+    let mut output = Vec::with_capacity(n_bytes);
+    loop {
+        output.extend(
+            state
+                .iter()
+                .flat_map(|lane| lane.to_le_bytes())
+                .take(rate)
+                .map(|byte| MaybeRelocatable::from(Felt::new(byte))),
+        );
+        if output.len() >= n_bytes {
+            break;
+        }
+        keccak_f1600(&mut state);
+    }
+    output.truncate(n_bytes);
+
+    let output_ptr = get_ptr_from_var_name("output", vm, ids_data, ap_tracking)?;
+    vm.write_arg(&output_ptr, &output)
+        .map_err(VirtualMachineError::MemoryError)?;
+    Ok(())
+}
+
 /* Implements hint:
     %{
         # Add dummy pairs of input and output.
@@ -207,7 +319,7 @@ pub fn cairo_keccak_finalize(
     let mut inp = vec![0; keccak_state_size_felts]
         .try_into()
         .map_err(|_| VirtualMachineError::SliceToArrayError)?;
-    keccak::f1600(&mut inp);
+    keccak_f1600(&mut inp);
 
     let mut padding = vec![Felt::zero().into(); keccak_state_size_felts];
     padding.extend(u64_array_to_mayberelocatable_vec(&inp));
@@ -226,6 +338,80 @@ pub fn cairo_keccak_finalize(
     Ok(())
 }
 
+/* Implements hint:
+    %{
+        from starkware.cairo.common.cairo_keccak.keccak_utils import keccak_func
+        _keccak_state_size_felts = int(ids.KECCAK_STATE_SIZE_FELTS)
+        _block_size = int(ids.BLOCK_SIZE)
+        assert 0 <= _keccak_state_size_felts < 100
+        assert 0 <= _block_size < 10
+        assert 0 <= ids.n <= _block_size, f'n = {ids.n} is greater than BLOCK_SIZE = {_block_size}.'
+        output = []
+        for i in range(ids.n):
+            inp = memory.get_range(ids.input + i * _keccak_state_size_felts, _keccak_state_size_felts)
+            output += inp + keccak_func(inp)
+        segments.write_arg(ids.keccak_ptr_end, output)
+    %}
+*/
+pub fn finalize_keccak(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
+) -> Result<(), HintError> {
+    let keccak_state_size_felts = constants
+        .get(KECCAK_STATE_SIZE_FELTS)
+        .ok_or(HintError::MissingConstant(KECCAK_STATE_SIZE_FELTS))?;
+    let block_size = constants
+        .get(BLOCK_SIZE)
+        .ok_or(HintError::MissingConstant(BLOCK_SIZE))?;
+
+    if keccak_state_size_felts >= &Felt::new(100_i32) {
+        return Err(HintError::InvalidKeccakStateSizeFelts(
+            keccak_state_size_felts.clone(),
+        ));
+    }
+
+    if block_size >= &Felt::new(10_i32) {
+        return Err(HintError::InvalidBlockSize(block_size.clone()));
+    }
+
+    let n = get_integer_from_var_name("n", vm, ids_data, ap_tracking)?;
+    if n.as_ref() > block_size {
+        return Err(HintError::KeccakBlockCountExceeded(
+            n.into_owned(),
+            block_size.clone(),
+        ));
+    }
+
+    let keccak_state_size_felts = keccak_state_size_felts.to_usize().unwrap();
+    let n = n.to_usize().unwrap();
+    let input = get_ptr_from_var_name("input", vm, ids_data, ap_tracking)?;
+
+    let mut output = Vec::with_capacity(2 * n * keccak_state_size_felts);
+    for i in 0..n {
+        let values = vm
+            .get_range(
+                &MaybeRelocatable::RelocatableValue(input + i * keccak_state_size_felts),
+                keccak_state_size_felts,
+            )
+            .map_err(VirtualMachineError::MemoryError)?;
+        let mut state: [u64; 25] = maybe_reloc_vec_to_u64_array(&values)?
+            .try_into()
+            .map_err(|_| VirtualMachineError::SliceToArrayError)?;
+        output.extend(u64_array_to_mayberelocatable_vec(&state));
+        keccak_f1600(&mut state);
+        output.extend(u64_array_to_mayberelocatable_vec(&state));
+    }
+
+    let keccak_ptr_end = get_ptr_from_var_name("keccak_ptr_end", vm, ids_data, ap_tracking)?;
+
+    vm.write_arg(&keccak_ptr_end, &output)
+        .map_err(VirtualMachineError::MemoryError)?;
+
+    Ok(())
+}
+
 // Helper function to transform a vector of MaybeRelocatables into a vector
 // of u64. Raises error if there are None's or if MaybeRelocatables are not Bigints.
 pub(crate) fn maybe_reloc_vec_to_u64_array(
@@ -257,8 +443,9 @@ mod tests {
     use crate::{
         any_box,
         hint_processor::{
-            builtin_hint_processor::builtin_hint_processor_definition::{
-                BuiltinHintProcessor, HintProcessorData,
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
             },
             hint_processor_definition::{HintProcessor, HintReference},
         },
@@ -288,6 +475,31 @@ mod tests {
         assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
     }
 
+    #[test]
+    fn keccak_write_args_word_order() {
+        let hint_code = hint_code::KECCAK_WRITE_ARGS;
+        let mut vm = vm_with_range_check!();
+        //ids.low = 2 * 2**64 + 1, ids.high = 4 * 2**64 + 3
+        vm.memory = memory![
+            ((1, 0), ("36893488147419103233", 10)),
+            ((1, 1), ("73786976294838206467", 10)),
+            ((1, 2), (2, 0))
+        ];
+        vm.memory.data.push(Vec::new());
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["low", "high", "inputs"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Little endian 64-bit words, starting with the least significant word of low
+        check_memory![
+            vm.memory,
+            ((2, 0), 1),
+            ((2, 1), 2),
+            ((2, 2), 3),
+            ((2, 3), 4)
+        ];
+        assert_eq!(vm.memory.data[2].len(), 4);
+    }
+
     #[test]
     fn keccak_write_args_write_error() {
         let hint_code = "segments.write_arg(ids.inputs, [ids.low % 2 ** 64, ids.low // 2 ** 64])\nsegments.write_arg(ids.inputs + 2, [ids.high % 2 ** 64, ids.high // 2 ** 64])";
@@ -358,6 +570,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compare_bytes_in_word_nondet_word_boundary() {
+        let constants = HashMap::from([(BYTES_IN_WORD.to_string(), Felt::new(8))]);
+        for (n_bytes, expected) in [(7, 1), (8, 0), (9, 0)] {
+            let mut vm = vm_with_range_check!();
+            vm.memory = memory![((1, 0), n_bytes)];
+            run_context!(vm, 0, 1, 1);
+            let ids_data = ids_data!["n_bytes"];
+            assert_eq!(
+                run_hint!(
+                    vm,
+                    ids_data,
+                    hint_code::COMPARE_BYTES_IN_WORD_NONDET,
+                    exec_scopes_ref!(),
+                    &constants
+                ),
+                Ok(())
+            );
+            check_memory![vm.memory, ((1, 1), expected)];
+        }
+    }
+
+    #[test]
+    fn compare_keccak_full_rate_in_bytes_nondet_rate_boundary() {
+        let constants = HashMap::from([(KECCAK_FULL_RATE_IN_BYTES.to_string(), Felt::new(136))]);
+        for (n_bytes, expected) in [(135, 0), (136, 1), (137, 1)] {
+            let mut vm = vm_with_range_check!();
+            vm.memory = memory![((1, 0), n_bytes)];
+            run_context!(vm, 0, 1, 1);
+            let ids_data = ids_data!["n_bytes"];
+            assert_eq!(
+                run_hint!(
+                    vm,
+                    ids_data,
+                    hint_code::COMPARE_KECCAK_FULL_RATE_IN_BYTES_NONDET,
+                    exec_scopes_ref!(),
+                    &constants
+                ),
+                Ok(())
+            );
+            check_memory![vm.memory, ((1, 1), expected)];
+        }
+    }
+
+    #[test]
+    fn compare_bytes_in_word_nondet_missing_constant() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), 7)];
+        run_context!(vm, 0, 1, 1);
+        let ids_data = ids_data!["n_bytes"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::COMPARE_BYTES_IN_WORD_NONDET),
+            Err(HintError::MissingConstant(BYTES_IN_WORD))
+        );
+    }
+
     #[test]
     fn block_permutation_valid_test() {
         let hint_code =
@@ -384,4 +652,298 @@ mod tests {
             Ok(())
         );
     }
+
+    fn keccak_pad_constants() -> HashMap<String, Felt> {
+        HashMap::from([(KECCAK_FULL_RATE_IN_BYTES.to_string(), Felt::new(136))])
+    }
+
+    #[test]
+    fn keccak_pad_partial_block() {
+        let hint_code = hint_code::KECCAK_PAD;
+        let mut vm = vm!();
+        //ids.input points to 3 input bytes
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((1, 1), 3),
+            ((2, 0), 0xaa),
+            ((2, 1), 0xbb),
+            ((2, 2), 0xcc)
+        ];
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["input", "n_bytes"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &keccak_pad_constants()
+            ),
+            Ok(())
+        );
+        //The padding completes the 136-byte block
+        assert_eq!(vm.memory.data[2].len(), 136);
+        check_memory![
+            vm.memory,
+            ((2, 2), 0xcc),
+            ((2, 3), 0x01),
+            ((2, 4), 0),
+            ((2, 134), 0),
+            ((2, 135), 0x80)
+        ];
+    }
+
+    #[test]
+    fn keccak_pad_single_byte() {
+        let hint_code = hint_code::KECCAK_PAD;
+        let mut vm = vm!();
+        //135 bytes leave room for a single padding byte, holding both padding bits
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), 135)];
+        vm.memory.data.push(Vec::new());
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["input", "n_bytes"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &keccak_pad_constants()
+            ),
+            Ok(())
+        );
+        assert_eq!(vm.memory.data[2].len(), 136);
+        check_memory![vm.memory, ((2, 135), 0x81)];
+    }
+
+    #[test]
+    fn keccak_pad_exact_fit_block() {
+        let hint_code = hint_code::KECCAK_PAD;
+        let mut vm = vm!();
+        //A full block of input requires an extra padding block
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), 136)];
+        vm.memory.data.push(Vec::new());
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["input", "n_bytes"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &keccak_pad_constants()
+            ),
+            Ok(())
+        );
+        assert_eq!(vm.memory.data[2].len(), 272);
+        check_memory![vm.memory, ((2, 136), 0x01), ((2, 137), 0), ((2, 271), 0x80)];
+    }
+
+    #[test]
+    fn keccak_pad_invalid_rate_and_n_bytes() {
+        let hint_code = hint_code::KECCAK_PAD;
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), 3)];
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["input", "n_bytes"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data.clone(),
+                hint_code,
+                exec_scopes_ref!(),
+                &HashMap::from([(KECCAK_FULL_RATE_IN_BYTES.to_string(), Felt::zero())])
+            ),
+            Err(HintError::InvalidKeccakRate(Felt::zero()))
+        );
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), (-1))];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &keccak_pad_constants()
+            ),
+            Err(HintError::InvalidKeccakByteCount(Felt::new(-1)))
+        );
+    }
+
+    fn finalize_keccak_constants() -> HashMap<String, Felt> {
+        HashMap::from([
+            (KECCAK_STATE_SIZE_FELTS.to_string(), Felt::new(25)),
+            (BLOCK_SIZE.to_string(), Felt::new(3)),
+        ])
+    }
+
+    #[test]
+    fn finalize_keccak_multiple_blocks() {
+        let mut vm = vm!();
+        //ids.n = 2, ids.input points to segment 2 and ids.keccak_ptr_end to segment 3
+        vm.memory = memory![((1, 0), 2), ((1, 1), (2, 0)), ((1, 2), (3, 0))];
+        vm.memory.data.push(Vec::new());
+        vm.memory.data.push(Vec::new());
+        let first_block: [u64; 25] = core::array::from_fn(|i| i as u64);
+        let second_block = [u64::MAX; 25];
+        let mut inputs = u64_array_to_mayberelocatable_vec(&first_block);
+        inputs.extend(u64_array_to_mayberelocatable_vec(&second_block));
+        vm.write_arg(&Relocatable::from((2, 0)), &inputs).unwrap();
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["n", "input", "keccak_ptr_end"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::FINALIZE_KECCAK,
+                exec_scopes_ref!(),
+                &finalize_keccak_constants()
+            ),
+            Ok(())
+        );
+        //Each block is written followed by its permutation
+        let mut expected = Vec::new();
+        for block in [first_block, second_block] {
+            let mut state = block;
+            keccak_f1600(&mut state);
+            expected.extend_from_slice(&block);
+            expected.extend_from_slice(&state);
+        }
+        let written: Vec<u64> = (0..100)
+            .map(|i| vm.get_integer(&(3, i).into()).unwrap().to_u64().unwrap())
+            .collect();
+        assert_eq!(written, expected);
+        assert_eq!(vm.memory.data[3].len(), 100);
+    }
+
+    #[test]
+    fn finalize_keccak_too_many_blocks() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 4), ((1, 1), (2, 0)), ((1, 2), (3, 0))];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["n", "input", "keccak_ptr_end"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::FINALIZE_KECCAK,
+                exec_scopes_ref!(),
+                &finalize_keccak_constants()
+            ),
+            Err(HintError::KeccakBlockCountExceeded(
+                Felt::new(4),
+                Felt::new(3)
+            ))
+        );
+    }
+
+    //Writes the 25 lanes of the state to segment 2 and points ids.keccak_state, ids.output and
+    //ids.n_bytes at them, with the output going to segment 3
+    fn keccak_squeeze_vm(state: &[u64; 25], n_bytes: usize) -> VirtualMachine {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), (3, 0)), ((1, 2), n_bytes)];
+        let state = u64_array_to_mayberelocatable_vec(state);
+        vm.memory.data.push(Vec::new());
+        vm.memory.data.push(Vec::new());
+        vm.write_arg(&Relocatable::from((2, 0)), &state).unwrap();
+        vm.run_context.fp = 3;
+        vm
+    }
+
+    fn squeezed_bytes(vm: &VirtualMachine, n_bytes: usize) -> Vec<u8> {
+        (0..n_bytes)
+            .map(|i| vm.get_integer(&(3, i).into()).unwrap().to_u8().unwrap())
+            .collect()
+    }
+
+    fn lanes_to_bytes(state: &[u64; 25]) -> Vec<u8> {
+        state.iter().flat_map(|lane| lane.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn keccak_squeeze_256_bit_digest() {
+        //State after absorbing the padded empty message
+        let mut state = [0_u64; 25];
+        state[0] = 0x01;
+        state[16] = 0x80 << 56;
+        keccak_f1600(&mut state);
+        let mut vm = keccak_squeeze_vm(&state, 32);
+        let ids_data = ids_data!["keccak_state", "output", "n_bytes"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::KECCAK_SQUEEZE,
+                exec_scopes_ref!(),
+                &keccak_pad_constants()
+            ),
+            Ok(())
+        );
+        //keccak256("")
+        assert_eq!(
+            squeezed_bytes(&vm, 32),
+            [
+                0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7,
+                0x03, 0xc0, 0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04,
+                0x5d, 0x85, 0xa4, 0x70
+            ]
+        );
+        assert_eq!(vm.memory.data[3].len(), 32);
+    }
+
+    #[test]
+    fn keccak_squeeze_longer_than_rate() {
+        let mut state = [0_u64; 25];
+        keccak_f1600(&mut state);
+        let mut vm = keccak_squeeze_vm(&state, 200);
+        let ids_data = ids_data!["keccak_state", "output", "n_bytes"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::KECCAK_SQUEEZE,
+                exec_scopes_ref!(),
+                &keccak_pad_constants()
+            ),
+            Ok(())
+        );
+        //The first block is the rate part of the given state, the rest comes from permuting it
+        let mut expected = lanes_to_bytes(&state)[..136].to_vec();
+        keccak_f1600(&mut state);
+        expected.extend_from_slice(&lanes_to_bytes(&state)[..64]);
+        assert_eq!(squeezed_bytes(&vm, 200), expected);
+        assert_eq!(vm.memory.data[3].len(), 200);
+    }
+
+    #[test]
+    fn keccak_squeeze_invalid_rate() {
+        let mut vm = keccak_squeeze_vm(&[0; 25], 32);
+        let ids_data = ids_data!["keccak_state", "output", "n_bytes"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::KECCAK_SQUEEZE,
+                exec_scopes_ref!(),
+                &HashMap::from([(KECCAK_FULL_RATE_IN_BYTES.to_string(), Felt::new(201))])
+            ),
+            Err(HintError::InvalidKeccakRate(Felt::new(201)))
+        );
+    }
+
+    #[test]
+    fn keccak_squeeze_invalid_n_bytes() {
+        let mut vm = keccak_squeeze_vm(&[0; 25], 0);
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), (3, 0)), ((1, 2), (-1))];
+        let ids_data = ids_data!["keccak_state", "output", "n_bytes"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::KECCAK_SQUEEZE,
+                exec_scopes_ref!(),
+                &keccak_pad_constants()
+            ),
+            Err(HintError::InvalidKeccakByteCount(Felt::new(-1)))
+        );
+    }
 }
@@ -0,0 +1,115 @@
+// Keccak-f[1600] permutation, as specified in FIPS-202.
+// The state is an array of 25 lanes, where lane (x, y) is stored at index x + 5 * y.
+
+/// Round constants applied to lane (0, 0) in the iota step of each of the 24 rounds.
+pub const KECCAK_ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, indexed by lane (x + 5 * y).
+pub const KECCAK_ROTATION_OFFSETS: [u32; 25] = [
+    0, 1, 62, 28, 27, //
+    36, 44, 6, 55, 20, //
+    3, 10, 43, 25, 39, //
+    41, 45, 15, 21, 8, //
+    18, 2, 61, 56, 14, //
+];
+
+/// Applies the 24 rounds of the Keccak-f[1600] permutation to the state in place.
+pub fn keccak_f1600(state: &mut [u64; 25]) {
+    for round_constant in KECCAK_ROUND_CONSTANTS {
+        // Theta
+        let columns: [u64; 5] = std::array::from_fn(|x| {
+            state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20]
+        });
+        for x in 0..5 {
+            let d = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // Rho and pi
+        let mut rotated = [0_u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                rotated[y + 5 * ((2 * x + 3 * y) % 5)] =
+                    state[x + 5 * y].rotate_left(KECCAK_ROTATION_OFFSETS[x + 5 * y]);
+            }
+        }
+
+        // Chi
+        for x in 0..5 {
+            for y in 0..5 {
+                state[x + 5 * y] = rotated[x + 5 * y]
+                    ^ (!rotated[(x + 1) % 5 + 5 * y] & rotated[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // Iota
+        state[0] ^= round_constant;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_constants_match_fips_202() {
+        assert_eq!(
+            KECCAK_ROUND_CONSTANTS[..6],
+            [
+                0x0000000000000001,
+                0x0000000000008082,
+                0x800000000000808A,
+                0x8000000080008000,
+                0x000000000000808B,
+                0x0000000080000001,
+            ]
+        );
+        assert_eq!(KECCAK_ROUND_CONSTANTS[23], 0x8000000080008008);
+    }
+
+    #[test]
+    fn keccak_f1600_zero_state() {
+        let mut state = [0_u64; 25];
+        keccak_f1600(&mut state);
+        assert_eq!(state[0], 0xF1258F7940E1DDE7);
+        assert_eq!(state[1], 0x84D5CCF933C0478A);
+        assert_eq!(state[2], 0xD598261EA65AA9EE);
+    }
+
+    #[test]
+    fn keccak_f1600_matches_keccak_crate() {
+        let mut state: [u64; 25] =
+            std::array::from_fn(|i| (i as u64 + 1).wrapping_mul(0x9E3779B97F4A7C15));
+        let mut expected = state;
+        keccak_f1600(&mut state);
+        keccak::f1600(&mut expected);
+        assert_eq!(state, expected);
+    }
+}
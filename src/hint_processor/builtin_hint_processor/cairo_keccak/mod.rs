@@ -1 +1,2 @@
 pub mod keccak_hints;
+pub mod keccak_permutation;
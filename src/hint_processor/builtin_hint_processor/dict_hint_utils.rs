@@ -40,8 +40,7 @@ fn copy_initial_dict(
        memory[ap] = __dict_manager.new_dict(segments, initial_dict)
        del initial_dict
 
-For now, the functionality to create a dictionary from a previously defined initial_dict (using a hint)
-is not available
+initial_dict is defined by an earlier hint, such as the one at the start of dict_squash.
 */
 pub fn dict_new(
     vm: &mut VirtualMachine,
@@ -58,7 +57,9 @@ pub fn dict_new(
         exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)));
         base
     };
-    insert_value_into_ap(vm, base)
+    insert_value_into_ap(vm, base)?;
+    exec_scopes.delete_variable("initial_dict");
+    Ok(())
 }
 
 /*Implements hint:
@@ -111,6 +112,7 @@ pub fn dict_read(
     let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
     let dict_manager_ref = exec_scopes.get_dict_manager()?;
     let mut dict = dict_manager_ref.borrow_mut();
+    //Fails with MismatchedDictPtr if the access stream was tampered with
     let tracker = dict.get_tracker_mut(&dict_ptr)?;
     tracker.current_ptr.offset += DICT_ACCESS_SIZE;
     let value = tracker.get_value(&key)?;
@@ -132,7 +134,7 @@ pub fn dict_write(
     let key = get_maybe_relocatable_from_var_name("key", vm, ids_data, ap_tracking)?;
     let new_value = get_maybe_relocatable_from_var_name("new_value", vm, ids_data, ap_tracking)?;
     let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
-    //Get tracker for dictionary
+    //Get tracker for dictionary, ids.dict_ptr has to match its current_ptr
     let dict_manager_ref = exec_scopes.get_dict_manager()?;
     let mut dict = dict_manager_ref.borrow_mut();
     let tracker = dict.get_tracker_mut(&dict_ptr)?;
@@ -441,6 +443,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_dict_read_tampered_dict_ptr() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 3;
+        //ids.dict_ptr skips the first access
+        vm.memory = memory![((1, 0), 5), ((1, 2), (2, 3))];
+        add_segments!(vm, 1);
+        let ids_data = ids_data!["key", "value", "dict_ptr"];
+        let mut exec_scopes = ExecutionScopes::new();
+        dict_manager!(&mut exec_scopes, 2, (5, 12));
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::DICT_READ, &mut exec_scopes),
+            Err(HintError::MismatchedDictPtr(
+                relocatable!(2, 0),
+                relocatable!(2, 3)
+            ))
+        );
+        //Neither the tracker nor ids.value were updated
+        check_dict_ptr!(&exec_scopes, 2, (2, 0));
+        assert_eq!(vm.memory.get(&MaybeRelocatable::from((1, 1))), Ok(None));
+    }
+
+    #[test]
+    fn run_dict_write_tampered_dict_ptr() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 3;
+        let mut exec_scopes = ExecutionScopes::new();
+        dict_manager!(&mut exec_scopes, 2, (5, 12));
+        //ids.dict_ptr points behind the tracker's current_ptr
+        vm.memory = memory![((1, 0), 5), ((1, 1), 17), ((1, 2), (2, 0))];
+        add_segments!(vm, 1);
+        let ids_data = ids_data!["key", "new_value", "dict_ptr"];
+        //A correct pointer is accepted and advances the tracker
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::DICT_WRITE, &mut exec_scopes),
+            Ok(())
+        );
+        check_dict_ptr!(&exec_scopes, 2, (2, 3));
+        //Replaying the same access is rejected
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::DICT_WRITE, &mut exec_scopes),
+            Err(HintError::MismatchedDictPtr(
+                relocatable!(2, 3),
+                relocatable!(2, 0)
+            ))
+        );
+        check_dictionary![exec_scopes, 2, (5, 17)];
+    }
+
     #[test]
     fn run_dict_write_default_valid_empty_dict() {
         let hint_code = "dict_tracker = __dict_manager.get_tracker(ids.dict_ptr)\ndict_tracker.current_ptr += ids.DictAccess.SIZE\nids.dict_ptr.prev_value = dict_tracker.data[ids.key]\ndict_tracker.data[ids.key] = ids.new_value";
@@ -830,6 +883,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_dict_squash_copy_dict_mismatched_dict_ptr() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 3))];
+        add_segments!(vm, 1);
+        let ids_data = ids_data!["dict_accesses_end"];
+        let mut exec_scopes = ExecutionScopes::new();
+        dict_manager!(exec_scopes, 2, (1, 2));
+        //The tracker's current_ptr is still at (2, 0)
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::DICT_SQUASH_COPY_DICT,
+                &mut exec_scopes
+            ),
+            Err(HintError::MismatchedDictPtr(
+                relocatable!(2, 0),
+                relocatable!(2, 3)
+            ))
+        );
+        //No scope was entered
+        assert_eq!(exec_scopes.data.len(), 1);
+    }
+
+    #[test]
+    fn run_dict_squash_copy_dict_then_dict_new() {
+        let mut vm = vm!();
+        //Initialize fp and ap
+        vm.run_context.fp = 1;
+        vm.run_context.ap = 1;
+        vm.memory = memory![((1, 0), (2, 0))];
+        add_segments!(vm, 1);
+        let ids_data = ids_data!["dict_accesses_end"];
+        let mut exec_scopes = ExecutionScopes::new();
+        dict_manager!(exec_scopes, 2, (1, 2), (3, 4));
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::DICT_SQUASH_COPY_DICT,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        //The squashed dict is created inside the new scope from the copy of the dict
+        assert_eq!(
+            run_hint!(vm, HashMap::new(), hint_code::DICT_NEW, &mut exec_scopes),
+            Ok(())
+        );
+        let squashed_dict_base = vm.get_relocatable(&relocatable!(1, 1)).unwrap();
+        let dict_manager = exec_scopes.get_dict_manager().unwrap();
+        assert_eq!(dict_manager.borrow().trackers.len(), 2);
+        assert_eq!(
+            dict_manager
+                .borrow()
+                .get_tracker(&squashed_dict_base)
+                .unwrap()
+                .get_dictionary_copy(),
+            HashMap::from([
+                (MaybeRelocatable::from(1), MaybeRelocatable::from(2)),
+                (MaybeRelocatable::from(3), MaybeRelocatable::from(4))
+            ])
+        );
+        //initial_dict was consumed by dict_new
+        assert!(!exec_scopes
+            .get_local_variables()
+            .unwrap()
+            .contains_key("initial_dict"));
+    }
+
     #[test]
     fn run_dict_squash_copy_dict_invalid_no_dict() {
         let hint_code = "# Prepare arguments for dict_new. In particular, the same dictionary values should be copied\n# to the new (squashed) dictionary.\nvm_enter_scope({\n    # Make __dict_manager accessible.\n    '__dict_manager': __dict_manager,\n    # Create a copy of the dict, in case it changes in the future.\n    'initial_dict': dict(__dict_manager.get_dict(ids.dict_accesses_end)),\n})";
@@ -57,7 +57,7 @@ pub fn find_element(
             return Err(HintError::ValueOutOfRange(n_elms.into_owned()));
         }
 
-        if let Ok(find_element_max_size) = exec_scopes.get_ref::<Felt>("find_element_max_size") {
+        if let Ok(find_element_max_size) = exec_scopes.get_ref::<Felt>("__find_element_max_size") {
             if n_elms.as_ref() > find_element_max_size {
                 return Err(HintError::FindElemMaxSize(
                     find_element_max_size.clone(),
@@ -95,7 +95,7 @@ pub fn search_sorted_lower(
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
 ) -> Result<(), HintError> {
-    let find_element_max_size = exec_scopes.get::<Felt>("find_element_max_size");
+    let find_element_max_size = exec_scopes.get::<Felt>("__find_element_max_size");
     let n_elms = get_integer_from_var_name("n_elms", vm, ids_data, ap_tracking)?;
     let rel_array_ptr = get_relocatable_from_var_name("array_ptr", vm, ids_data, ap_tracking)?;
     let elm_size = get_integer_from_var_name("elm_size", vm, ids_data, ap_tracking)?;
@@ -345,13 +345,23 @@ mod tests {
     #[test]
     fn find_elm_n_elms_gt_max_size() {
         let (mut vm, ids_data) = init_vm_ids_data(HashMap::new());
-        let mut exec_scopes = scope![("find_element_max_size", Felt::one())];
+        let mut exec_scopes = scope![("__find_element_max_size", Felt::one())];
         assert_eq!(
             run_hint!(vm, ids_data, hint_code::FIND_ELEMENT, &mut exec_scopes),
             Err(HintError::FindElemMaxSize(Felt::one(), Felt::new(2)))
         );
     }
 
+    #[test]
+    fn find_elm_n_elms_within_max_size() {
+        let (mut vm, ids_data) = init_vm_ids_data(HashMap::new());
+        let mut exec_scopes = scope![("__find_element_max_size", Felt::new(2))];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::FIND_ELEMENT, &mut exec_scopes),
+            Ok(())
+        );
+    }
+
     #[test]
     fn find_elm_key_not_int() {
         let relocatable = MaybeRelocatable::from((1, 4));
@@ -465,7 +475,7 @@ mod tests {
     #[test]
     fn search_sorted_lower_n_elms_gt_max_size() {
         let (mut vm, ids_data) = init_vm_ids_data(HashMap::new());
-        let mut exec_scopes = scope![("find_element_max_size", Felt::one())];
+        let mut exec_scopes = scope![("__find_element_max_size", Felt::one())];
         assert_eq!(
             run_hint!(
                 vm,
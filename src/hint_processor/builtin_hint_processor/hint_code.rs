@@ -69,6 +69,25 @@ assert_integer(ids.b)
 assert (ids.a % PRIME) < (ids.b % PRIME), \
     f'a = {ids.a % PRIME} is not less than b = {ids.b % PRIME}.'"#;
 
+pub(crate) const ASSERT_LT_FELT_SIGNED: &str = r#"from starkware.cairo.common.math_utils import as_int
+assert as_int(ids.a, PRIME) < as_int(ids.b, PRIME), \
+    f'a = {as_int(ids.a, PRIME)} is not less than b = {as_int(ids.b, PRIME)}.'"#;
+
+pub(crate) const RECOVER_Y: &str = r#"from starkware.crypto.signature.signature import ALPHA, BETA, FIELD_PRIME
+from starkware.python.math_utils import recover_y
+y = recover_y(ids.x, ALPHA, BETA, FIELD_PRIME)
+ids.y = y if y % 2 == ids.parity else FIELD_PRIME - y"#;
+
+pub(crate) const RANDOM_EC_POINT: &str = r#"from starkware.crypto.signature.signature import ALPHA, BETA, FIELD_PRIME
+from starkware.python.math_utils import random_ec_point
+from starkware.python.utils import to_bytes
+
+# Define a seed for random_ec_point that's dependent on all the input, so that:
+#   (1) The added point s is deterministic.
+#   (2) It's hard to choose inputs for which the builtin will fail.
+seed = b"".join(map(to_bytes, [ids.p.x, ids.p.y, ids.m, ids.q.x, ids.q.y]))
+ids.s.x, ids.s.y = random_ec_point(FIELD_PRIME, ALPHA, BETA, seed)"#;
+
 pub(crate) const SPLIT_INT_ASSERT_RANGE: &str =
     "assert ids.value == 0, 'split_int(): value is out of range.'";
 
@@ -81,9 +100,14 @@ assert value < ids.UPPER_BOUND, f'{value} is outside of the range [0, 2**250).'
 # Calculation for the assertion.
 ids.high, ids.low = divmod(ids.value, ids.SHIFT)"#;
 
+pub(crate) const IS_250_BITS: &str = r#"ids.is_250 = 1 if ids.value < 2**250 else 0"#;
+
 pub(crate) const SPLIT_INT: &str = r#"memory[ids.output] = res = (int(ids.value) % PRIME) % ids.base
 assert res < ids.bound, f'split_int(): Limb {res} is out of range.'"#;
 
+pub(crate) const SPLIT_INT_MOST_SIGNIFICANT_FIRST: &str = r#"memory[ids.output + ids.n - 1] = res = (int(ids.value) % PRIME) % ids.base
+assert res < ids.bound, f'split_int(): Limb {res} is out of range.'"#;
+
 pub(crate) const SPLIT_64: &str = r#"ids.low = ids.a & ((1<<64) - 1)
 ids.high = ids.a >> 64"#;
 
@@ -94,6 +118,15 @@ assert_integer(ids.value)
 ids.low = ids.value & ((1 << 128) - 1)
 ids.high = ids.value >> 128"#;
 
+pub(crate) const JOIN_SPLIT_OUTPUT: &str = r#"assert ids.low < 2**128 and ids.high < 2**128
+memory[ids.output_ptr] = ids.high * 2**128 + ids.low
+ids.new_output_ptr = ids.output_ptr + 1"#;
+
+pub(crate) const ADD_OUTPUT_PAGE: &str = r#"output_builtin.add_page(
+    page_id=ids.page_id,
+    page_start=ids.page_start,
+    page_size=ids.output_ptr - ids.page_start)"#;
+
 pub(crate) const SQRT: &str = r#"from starkware.python.math_utils import isqrt
 value = ids.value % PRIME
 assert value < 2 ** 250, f"value={value} is outside of the range [0, 2**250)."
@@ -106,6 +139,11 @@ assert 0 < ids.div <= PRIME // range_check_builtin.bound, \
     f'div={hex(ids.div)} is out of the valid range.'
 ids.q, ids.r = divmod(ids.value, ids.div)"#;
 
+pub(crate) const SAFE_DIV: &str = r#"from starkware.python.math_utils import safe_div
+ids.result = safe_div(ids.a, ids.b)"#;
+
+pub(crate) const IDS_DIFFERENCE: &str = "ids.res = ids.a - ids.b";
+
 pub(crate) const SIGNED_DIV_REM: &str = r#"from starkware.cairo.common.math_utils import as_int, assert_integer
 
 assert_integer(ids.div)
@@ -286,6 +324,10 @@ ids.root.high = 0"#;
 pub(crate) const UINT256_SIGNED_NN: &str =
     "memory[ap] = 1 if 0 <= (ids.a.high % PRIME) < 2 ** 127 else 0";
 
+pub(crate) const ASSERT_UINT256_LE: &str = r#"a = (ids.a.high << 128) + ids.a.low
+b = (ids.b.high << 128) + ids.b.low
+assert a <= b, f'a = {a} is not less than or equal to b = {b}.'"#;
+
 pub(crate) const UINT256_UNSIGNED_DIV_REM: &str = r#"a = (ids.a.high << 128) + ids.a.low
 div = (ids.div.high << 128) + ids.div.low
 quotient, remainder = divmod(a, div)
@@ -295,6 +337,40 @@ ids.quotient.high = quotient >> 128
 ids.remainder.low = remainder & ((1 << 128) - 1)
 ids.remainder.high = remainder >> 128"#;
 
+pub(crate) const UINT256_MUL_DIV_MOD: &str = r#"a = (ids.a.high << 128) + ids.a.low
+b = (ids.b.high << 128) + ids.b.low
+div = (ids.div.high << 128) + ids.div.low
+quotient, remainder = divmod(a * b, div)
+
+ids.quotient_low.low = quotient & ((1 << 128) - 1)
+ids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)
+ids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)
+ids.quotient_high.high = quotient >> 384
+ids.remainder.low = remainder & ((1 << 128) - 1)
+ids.remainder.high = remainder >> 128"#;
+
+pub(crate) const ASSERT_UINT256_MUL_NO_OVERFLOW: &str = r#"a = (ids.a.high << 128) + ids.a.low
+b = (ids.b.high << 128) + ids.b.low
+res = a * b
+assert res < 2 ** 256, f'a * b = {res} overflows a uint256.'
+ids.res.low = res & ((1 << 128) - 1)
+ids.res.high = res >> 128"#;
+
+pub(crate) const UINT256_AND: &str = r#"assert ids.a.low < 2**128 and ids.a.high < 2**128
+assert ids.b.low < 2**128 and ids.b.high < 2**128
+ids.res.low = ids.a.low & ids.b.low
+ids.res.high = ids.a.high & ids.b.high"#;
+
+pub(crate) const UINT256_OR: &str = r#"assert ids.a.low < 2**128 and ids.a.high < 2**128
+assert ids.b.low < 2**128 and ids.b.high < 2**128
+ids.res.low = ids.a.low | ids.b.low
+ids.res.high = ids.a.high | ids.b.high"#;
+
+pub(crate) const UINT256_XOR: &str = r#"assert ids.a.low < 2**128 and ids.a.high < 2**128
+assert ids.b.low < 2**128 and ids.b.high < 2**128
+ids.res.low = ids.a.low ^ ids.b.low
+ids.res.high = ids.a.high ^ ids.b.high"#;
+
 pub(crate) const USORT_ENTER_SCOPE: &str =
     "vm_enter_scope(dict(__usort_max_size = globals().get('__usort_max_size')))";
 pub(crate) const USORT_BODY: &str = r#"from collections import defaultdict
@@ -349,6 +425,13 @@ output = blake2s_compress(
 padding = (modified_iv + message + [0, 0xffffffff] + output) * (_n_packed_instances - 1)
 segments.write_arg(ids.blake2s_ptr_end, padding)"#;
 
+pub(crate) const BLAKE2S_INIT: &str = r#"from starkware.cairo.common.cairo_blake2s.blake2s_utils import IV
+
+_output_length = int(ids.output_length)
+assert 0 < _output_length <= 32
+initial_state = [IV[0] ^ 0x01010000 ^ _output_length] + IV[1:]
+segments.write_arg(ids.blake2s_state, initial_state)"#;
+
 pub(crate) const BLAKE2S_ADD_UINT256: &str = r#"B = 32
 MASK = 2 ** 32 - 1
 segments.write_arg(ids.data, [(ids.low >> (B * i)) & MASK for i in range(4)])
@@ -369,6 +452,12 @@ q, r = divmod(pack(ids.val, PRIME), SECP_P)
 assert r == 0, f"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}."
 ids.q = q % PRIME"#;
 
+pub(crate) const VERIFY_ZERO_EXTERNAL_SECP: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+q, r = divmod(pack(ids.val, PRIME), SECP_P)
+assert r == 0, f"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}."
+ids.q = q % PRIME"#;
+
 pub(crate) const REDUCE: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack
 
 value = pack(ids.x, PRIME) % SECP_P"#;
@@ -411,6 +500,21 @@ from starkware.python.math_utils import div_mod
 
 value = x_inv = div_mod(1, x, SECP_P)"#;
 
+pub(crate) const VERIFY_NONZERO: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack
+from starkware.python.math_utils import div_mod
+
+x = pack(ids.x, PRIME) % SECP_P
+assert x != 0, f"verify_nonzero: {x} = 0 mod SECP_P."
+value = x_inv = div_mod(1, x, SECP_P)"#;
+
+pub(crate) const SQRT_MOD: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack, split
+from starkware.python.math_utils import is_quad_residue, sqrt
+
+x = pack(ids.x, PRIME) % SECP_P
+assert is_quad_residue(x, SECP_P), f"sqrt_mod: {x} is not a quadratic residue modulo {SECP_P}."
+value = y = sqrt(x, SECP_P)
+segments.write_arg(ids.y.address_, split(y))"#;
+
 pub(crate) const DIV_MOD_N_PACKED_DIVMOD: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import N, pack
 from starkware.python.math_utils import div_mod, safe_div
 
@@ -418,8 +522,29 @@ a = pack(ids.a, PRIME)
 b = pack(ids.b, PRIME)
 value = res = div_mod(a, b, N)"#;
 
+pub(crate) const DIV_MOD_N_PACKED_DIVMOD_EXTERNAL_N: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+value = res = div_mod(a, b, N)"#;
+
 pub(crate) const DIV_MOD_N_SAFE_DIV: &str = r#"value = k = safe_div(res * b - a, N)"#;
 
+pub(crate) const BIGINT_PACK_DIV_MOD: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.cairo.common.math_utils import as_int
+from starkware.python.math_utils import div_mod, safe_div
+
+p = pack(ids.P, PRIME)
+x = pack(ids.x, PRIME) + as_int(ids.x.d3, PRIME) * ids.BASE ** 3 + as_int(ids.x.d4, PRIME) * ids.BASE ** 4
+y = pack(ids.y, PRIME)
+
+value = res = div_mod(x, y, p)"#;
+
+pub(crate) const BIGINT_SAFE_DIV: &str = r#"k = safe_div(res * y - x, p)
+value = k if k > 0 else 0 - k
+ids.flag = 1 if k > 0 else 0"#;
+
 pub(crate) const GET_POINT_FROM_X: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack
 
 x_cube_int = pack(ids.x_cube, PRIME) % SECP_P
@@ -504,6 +629,12 @@ pub(crate) const COMPARE_BYTES_IN_WORD_NONDET: &str =
 pub(crate) const COMPARE_KECCAK_FULL_RATE_IN_BYTES_NONDET: &str =
     r#"memory[ap] = to_felt_or_relocatable(ids.n_bytes >= ids.KECCAK_FULL_RATE_IN_BYTES)"#;
 
+pub(crate) const KECCAK_PAD: &str = r#"_rate = int(ids.KECCAK_FULL_RATE_IN_BYTES)
+_pad_len = _rate - ids.n_bytes % _rate
+padding = [0x01] + [0] * (_pad_len - 1)
+padding[-1] |= 0x80
+segments.write_arg(ids.input + ids.n_bytes, padding)"#;
+
 pub(crate) const BLOCK_PERMUTATION: &str = r#"from starkware.cairo.common.cairo_keccak.keccak_utils import keccak_func
 _keccak_state_size_felts = int(ids.KECCAK_STATE_SIZE_FELTS)
 assert 0 <= _keccak_state_size_felts < 100
@@ -512,6 +643,18 @@ output_values = keccak_func(memory.get_range(
     ids.keccak_ptr - _keccak_state_size_felts, _keccak_state_size_felts))
 segments.write_arg(ids.keccak_ptr, output_values)"#;
 
+pub(crate) const KECCAK_SQUEEZE: &str = r#"from starkware.cairo.common.cairo_keccak.keccak_utils import keccak_func
+_rate = int(ids.KECCAK_FULL_RATE_IN_BYTES)
+assert 0 < _rate <= 200
+_state = memory.get_range(ids.keccak_state, 25)
+_output = []
+while True:
+    _output += b''.join(w.to_bytes(8, 'little') for w in _state)[:_rate]
+    if len(_output) >= ids.n_bytes:
+        break
+    _state = keccak_func(_state)
+segments.write_arg(ids.output, _output[:ids.n_bytes])"#;
+
 pub(crate) const CAIRO_KECCAK_FINALIZE: &str = r#"# Add dummy pairs of input and output.
 _keccak_state_size_felts = int(ids.KECCAK_STATE_SIZE_FELTS)
 _block_size = int(ids.BLOCK_SIZE)
@@ -521,6 +664,18 @@ inp = [0] * _keccak_state_size_felts
 padding = (inp + keccak_func(inp)) * _block_size
 segments.write_arg(ids.keccak_ptr_end, padding)"#;
 
+pub(crate) const FINALIZE_KECCAK: &str = r#"from starkware.cairo.common.cairo_keccak.keccak_utils import keccak_func
+_keccak_state_size_felts = int(ids.KECCAK_STATE_SIZE_FELTS)
+_block_size = int(ids.BLOCK_SIZE)
+assert 0 <= _keccak_state_size_felts < 100
+assert 0 <= _block_size < 10
+assert 0 <= ids.n <= _block_size, f'n = {ids.n} is greater than BLOCK_SIZE = {_block_size}.'
+output = []
+for i in range(ids.n):
+    inp = memory.get_range(ids.input + i * _keccak_state_size_felts, _keccak_state_size_felts)
+    output += inp + keccak_func(inp)
+segments.write_arg(ids.keccak_ptr_end, output)"#;
+
 pub(crate) const FAST_EC_ADD_ASSIGN_NEW_X: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack
 
 slope = pack(ids.slope, PRIME)
@@ -538,6 +693,13 @@ pub(crate) const EC_MUL_INNER: &str = r#"memory[ap] = (ids.scalar % PRIME) % 2"#
 pub(crate) const RELOCATE_SEGMENT: &str =
     r#"memory.add_relocation_rule(src_ptr=ids.src_ptr, dest_ptr=ids.dest_ptr)"#;
 
+pub(crate) const ARRAY_LENGTH: &str = r#"assert (ids.arr_end - ids.arr_start) % ids.elm_size == 0
+ids.len = (ids.arr_end - ids.arr_start) // ids.elm_size"#;
+
+pub(crate) const JUMP_TABLE_OFFSET: &str = r#"assert 0 <= ids.selector < ids.table_len, \
+    f'Selector {ids.selector} is out of the jump table range [0, {ids.table_len}).'
+ids.offset = memory[ids.table + ids.selector]"#;
+
 pub(crate) const TEMPORARY_ARRAY: &str = r#"ids.temporary_array = segments.add_temp_segment()"#;
 pub(crate) const VERIFY_ECDSA_SIGNATURE: &str =
     r#"ecdsa_builtin.add_signature(ids.ecdsa_ptr.address_, (ids.signature_r, ids.signature_s))"#;
@@ -0,0 +1,113 @@
+use crate::{
+    types::exec_scope::ExecutionScopes,
+    vm::errors::{exec_scope_errors::ExecScopeError, hint_errors::HintError},
+};
+use rand_core::{impls, Error, RngCore, SeedableRng};
+use std::{
+    any::Any,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Name of the main scope variable holding the generator used by nondeterministic hints
+pub const HINT_RNG: &str = "__hint_rng";
+
+/// Pseudorandom generator (SplitMix64) consumed by hints whose result is not determined by the
+/// program. Seeding it makes runs containing such hints reproducible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HintRng {
+    state: u64,
+}
+
+impl RngCore for HintRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for HintRng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        HintRng {
+            state: u64::from_le_bytes(seed),
+        }
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        HintRng { state }
+    }
+}
+
+/// Returns the generator stored in the main execution scope. If the run wasn't given a seed,
+/// a generator seeded from the system clock is created on first use.
+pub fn get_hint_rng(exec_scopes: &mut ExecutionScopes) -> Result<&mut HintRng, HintError> {
+    let main_scope = exec_scopes
+        .data
+        .first_mut()
+        .ok_or(HintError::FromScopeError(ExecScopeError::NoScopeError))?;
+    let rng = main_scope.entry(HINT_RNG.to_string()).or_insert_with(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64);
+        Box::new(HintRng::seed_from_u64(seed)) as Box<dyn Any>
+    });
+    rng.downcast_mut::<HintRng>()
+        .ok_or_else(|| HintError::VariableNotInScopeError(HINT_RNG.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = HintRng::seed_from_u64(42);
+        let mut b = HintRng::seed_from_u64(42);
+        let seq_a: Vec<u64> = (0..4).map(|_| a.next_u64()).collect();
+        let seq_b: Vec<u64> = (0..4).map(|_| b.next_u64()).collect();
+        assert_eq!(seq_a, seq_b);
+        assert_ne!(HintRng::seed_from_u64(43).next_u64(), seq_a[0]);
+    }
+
+    #[test]
+    fn splitmix64_reference_value() {
+        //First output of SplitMix64 seeded with 0
+        assert_eq!(HintRng::seed_from_u64(0).next_u64(), 0xE220A8397B1DCDAF);
+    }
+
+    #[test]
+    fn get_hint_rng_uses_main_scope() {
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value(HINT_RNG, HintRng::seed_from_u64(7));
+        exec_scopes.enter_scope(HashMap::new());
+        let value = get_hint_rng(&mut exec_scopes).unwrap().next_u64();
+        assert_eq!(value, HintRng::seed_from_u64(7).next_u64());
+        //The state advanced in the main scope
+        assert_ne!(get_hint_rng(&mut exec_scopes).unwrap().next_u64(), value);
+    }
+
+    #[test]
+    fn get_hint_rng_without_seed() {
+        let mut exec_scopes = ExecutionScopes::new();
+        assert!(get_hint_rng(&mut exec_scopes).is_ok());
+        assert!(exec_scopes.data[0].contains_key(HINT_RNG));
+    }
+}
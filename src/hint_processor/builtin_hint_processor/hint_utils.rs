@@ -5,10 +5,10 @@ use crate::hint_processor::hint_processor_utils::compute_addr_from_reference;
 use crate::hint_processor::hint_processor_utils::{
     get_integer_from_reference, get_maybe_relocatable_from_reference,
 };
-use crate::serde::deserialize_program::ApTracking;
+use crate::serde::deserialize_program::{ApTracking, Identifier, Member};
 use crate::types::relocatable::MaybeRelocatable;
 use crate::types::relocatable::Relocatable;
-use crate::vm::errors::hint_errors::HintError;
+use crate::vm::errors::{hint_errors::HintError, vm_errors::VirtualMachineError};
 use crate::vm::vm_core::VirtualMachine;
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -96,6 +96,33 @@ pub fn get_integer_from_var_name<'a>(
     get_integer_from_reference(vm, reference, ap_tracking)
 }
 
+//Gets the n consecutive integers stored at the array the given ids variable points to.
+//Fails if any of the cells is empty or holds a relocatable value
+pub fn get_integer_range_from_var_name<'a>(
+    var_name: &str,
+    n: usize,
+    vm: &'a VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Vec<Cow<'a, Felt>>, HintError> {
+    let ptr = get_ptr_from_var_name(var_name, vm, ids_data, ap_tracking)?;
+    (0..n)
+        .map(|i| -> Result<Cow<'a, Felt>, HintError> {
+            let addr = ptr + i;
+            match vm
+                .memory
+                .get(&addr)
+                .map_err(VirtualMachineError::MemoryError)?
+            {
+                Some(Cow::Borrowed(MaybeRelocatable::Int(int))) => Ok(Cow::Borrowed(int)),
+                Some(Cow::Owned(MaybeRelocatable::Int(int))) => Ok(Cow::Owned(int)),
+                Some(_) => Err(VirtualMachineError::ExpectedInteger(addr.into()).into()),
+                None => Err(VirtualMachineError::MemoryGet(addr.into()).into()),
+            }
+        })
+        .collect()
+}
+
 //Gets the value of a variable name as a MaybeRelocatable
 pub fn get_maybe_relocatable_from_var_name<'a>(
     var_name: &str,
@@ -107,6 +134,26 @@ pub fn get_maybe_relocatable_from_var_name<'a>(
     get_maybe_relocatable_from_reference(vm, reference, ap_tracking)
 }
 
+//Subtracts the value of b_name from the value of a_name. Two pointers into the same segment give
+//the distance between them and two integers their difference, other combinations fail
+pub fn get_difference_from_var_names(
+    a_name: &str,
+    b_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Felt, HintError> {
+    let a = get_maybe_relocatable_from_var_name(a_name, vm, ids_data, ap_tracking)?;
+    let b = get_maybe_relocatable_from_var_name(b_name, vm, ids_data, ap_tracking)?;
+    match (&a, &b) {
+        (MaybeRelocatable::Int(_), MaybeRelocatable::Int(_))
+        | (MaybeRelocatable::RelocatableValue(_), MaybeRelocatable::RelocatableValue(_)) => {
+            Ok(a.sub(&b)?.get_int_ref()?.clone())
+        }
+        _ => Err(HintError::InvalidSubOperands(a, b)),
+    }
+}
+
 pub fn get_reference_from_var_name<'a>(
     var_name: &str,
     ids_data: &'a HashMap<String, HintReference>,
@@ -114,13 +161,84 @@ pub fn get_reference_from_var_name<'a>(
     ids_data.get(var_name).ok_or(HintError::FailedToGetIds)
 }
 
+//Gets the address of a nested struct member given by a dotted path, such as "point.x.d0".
+//Member offsets are looked up in the program's struct identifiers, and pointers crossed along
+//the path are dereferenced, as in `ids.point_ptr.x`
+pub fn get_relocatable_field(
+    path: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    identifiers: &HashMap<String, Identifier>,
+) -> Result<Relocatable, HintError> {
+    let mut fields = path.split('.');
+    let var_name = fields.next().unwrap_or(path);
+    let reference = get_reference_from_var_name(var_name, ids_data)?;
+    let mut cairo_type = reference
+        .cairo_type
+        .as_deref()
+        .ok_or_else(|| HintError::MissingReferenceType(var_name.to_string()))?;
+    let mut addr = compute_addr_from_reference(reference, vm, ap_tracking)?;
+
+    for field in fields {
+        while let Some(pointee) = cairo_type.strip_suffix('*') {
+            addr = vm.get_relocatable(&addr)?;
+            cairo_type = pointee;
+        }
+        let member = get_struct_member(cairo_type, field, identifiers)?;
+        addr = addr + member.offset;
+        cairo_type = &member.cairo_type;
+    }
+    Ok(addr)
+}
+
+//Inserts value into the struct member found field_offset cells after the struct's base address
+pub fn insert_struct_field(
+    base: Relocatable,
+    field_offset: usize,
+    value: impl Into<MaybeRelocatable>,
+    vm: &mut VirtualMachine,
+) -> Result<(), HintError> {
+    vm.insert_value(&(base + field_offset), value)
+        .map_err(HintError::Internal)
+}
+
+//Inserts value into the member called field_name of the struct of type struct_type at base,
+//with the member's offset looked up in the program's struct identifiers
+pub fn insert_struct_field_by_name(
+    base: Relocatable,
+    struct_type: &str,
+    field_name: &str,
+    value: impl Into<MaybeRelocatable>,
+    vm: &mut VirtualMachine,
+    identifiers: &HashMap<String, Identifier>,
+) -> Result<(), HintError> {
+    let member = get_struct_member(struct_type, field_name, identifiers)?;
+    insert_struct_field(base, member.offset, value, vm)
+}
+
+fn get_struct_member<'a>(
+    struct_type: &str,
+    field_name: &str,
+    identifiers: &'a HashMap<String, Identifier>,
+) -> Result<&'a Member, HintError> {
+    identifiers
+        .get(struct_type)
+        .and_then(|identifier| identifier.members.as_ref())
+        .ok_or_else(|| HintError::UnknownStruct(struct_type.to_string()))?
+        .get(field_name)
+        .ok_or_else(|| {
+            HintError::UnknownStructMember(struct_type.to_string(), field_name.to_string())
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         hint_processor::hint_processor_definition::HintReference,
         relocatable,
-        serde::deserialize_program::OffsetValue,
+        serde::deserialize_program::{Member, OffsetValue},
         utils::test_utils::*,
         vm::{
             errors::{memory_errors::MemoryError, vm_errors::VirtualMachineError},
@@ -250,4 +368,267 @@ mod tests {
             )))
         );
     }
+
+    #[test]
+    fn get_integer_range_from_var_name_valid() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), 1), ((2, 1), 2), ((2, 2), 3)];
+        let hint_ref = HintReference::new_simple(0);
+        let ids_data = HashMap::from([("data".to_string(), hint_ref)]);
+
+        assert_eq!(
+            get_integer_range_from_var_name("data", 3, &vm, &ids_data, &ApTracking::new()),
+            Ok(vec![
+                Cow::Borrowed(&Felt::new(1)),
+                Cow::Borrowed(&Felt::new(2)),
+                Cow::Borrowed(&Felt::new(3))
+            ])
+        );
+    }
+
+    #[test]
+    fn get_integer_range_from_var_name_invalid() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), 1), ((2, 1), (0, 0)), ((2, 2), 3)];
+        let hint_ref = HintReference::new_simple(0);
+        let ids_data = HashMap::from([("data".to_string(), hint_ref)]);
+
+        assert_eq!(
+            get_integer_range_from_var_name("data", 3, &vm, &ids_data, &ApTracking::new()),
+            Err(HintError::Internal(VirtualMachineError::ExpectedInteger(
+                MaybeRelocatable::from((2, 1))
+            )))
+        );
+    }
+
+    #[test]
+    fn get_integer_range_from_var_name_unallocated_cell() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), 1), ((2, 1), 2)];
+        let hint_ref = HintReference::new_simple(0);
+        let ids_data = HashMap::from([("data".to_string(), hint_ref)]);
+
+        assert_eq!(
+            get_integer_range_from_var_name("data", 3, &vm, &ids_data, &ApTracking::new()),
+            Err(HintError::Internal(VirtualMachineError::MemoryGet(
+                MaybeRelocatable::from((2, 2))
+            )))
+        );
+    }
+
+    #[test]
+    fn get_integer_range_from_var_name_missing_ids() {
+        let vm = vm!();
+        assert_eq!(
+            get_integer_range_from_var_name("data", 3, &vm, &HashMap::new(), &ApTracking::new()),
+            Err(HintError::FailedToGetIds)
+        );
+    }
+
+    fn ec_point_identifiers() -> HashMap<String, Identifier> {
+        let struct_identifier = |members: &[(&str, &str, usize)]| Identifier {
+            pc: None,
+            type_: Some(String::from("struct")),
+            value: None,
+            full_name: None,
+            members: Some(
+                members
+                    .iter()
+                    .map(|(name, cairo_type, offset)| {
+                        (
+                            name.to_string(),
+                            Member {
+                                cairo_type: cairo_type.to_string(),
+                                offset: *offset,
+                            },
+                        )
+                    })
+                    .collect(),
+            ),
+        };
+        HashMap::from([
+            (
+                String::from("starkware.cairo.common.cairo_secp.bigint.BigInt3"),
+                struct_identifier(&[("d0", "felt", 0), ("d1", "felt", 1), ("d2", "felt", 2)]),
+            ),
+            (
+                String::from("starkware.cairo.common.cairo_secp.ec.EcPoint"),
+                struct_identifier(&[
+                    ("x", "starkware.cairo.common.cairo_secp.bigint.BigInt3", 0),
+                    ("y", "starkware.cairo.common.cairo_secp.bigint.BigInt3", 3),
+                ]),
+            ),
+        ])
+    }
+
+    #[test]
+    fn get_relocatable_field_nested_struct() {
+        let mut vm = vm!();
+        vm.run_context.fp = 6;
+        let mut hint_ref = HintReference::new_simple(-6);
+        hint_ref.cairo_type = Some(String::from("starkware.cairo.common.cairo_secp.ec.EcPoint"));
+        let ids_data = HashMap::from([("point".to_string(), hint_ref)]);
+        let identifiers = ec_point_identifiers();
+
+        assert_eq!(
+            get_relocatable_field("point", &vm, &ids_data, &ApTracking::new(), &identifiers),
+            Ok(relocatable!(1, 0))
+        );
+        assert_eq!(
+            get_relocatable_field(
+                "point.x.d0",
+                &vm,
+                &ids_data,
+                &ApTracking::new(),
+                &identifiers
+            ),
+            Ok(relocatable!(1, 0))
+        );
+        assert_eq!(
+            get_relocatable_field(
+                "point.y.d1",
+                &vm,
+                &ids_data,
+                &ApTracking::new(),
+                &identifiers
+            ),
+            Ok(relocatable!(1, 4))
+        );
+    }
+
+    #[test]
+    fn get_relocatable_field_through_pointer() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0))];
+        let mut hint_ref = HintReference::new_simple(0);
+        hint_ref.cairo_type = Some(String::from(
+            "starkware.cairo.common.cairo_secp.ec.EcPoint*",
+        ));
+        let ids_data = HashMap::from([("point_ptr".to_string(), hint_ref)]);
+        let identifiers = ec_point_identifiers();
+
+        assert_eq!(
+            get_relocatable_field(
+                "point_ptr.y.d2",
+                &vm,
+                &ids_data,
+                &ApTracking::new(),
+                &identifiers
+            ),
+            Ok(relocatable!(2, 5))
+        );
+    }
+
+    #[test]
+    fn get_relocatable_field_unknown_member() {
+        let mut vm = vm!();
+        vm.run_context.fp = 6;
+        let mut hint_ref = HintReference::new_simple(-6);
+        hint_ref.cairo_type = Some(String::from("starkware.cairo.common.cairo_secp.ec.EcPoint"));
+        let ids_data = HashMap::from([("point".to_string(), hint_ref)]);
+        let identifiers = ec_point_identifiers();
+
+        assert_eq!(
+            get_relocatable_field(
+                "point.z.d0",
+                &vm,
+                &ids_data,
+                &ApTracking::new(),
+                &identifiers
+            ),
+            Err(HintError::UnknownStructMember(
+                String::from("starkware.cairo.common.cairo_secp.ec.EcPoint"),
+                String::from("z")
+            ))
+        );
+    }
+
+    #[test]
+    fn insert_struct_fields_and_read_back() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let base = relocatable!(1, 0);
+        let identifiers = ec_point_identifiers();
+
+        assert_eq!(insert_struct_field(base, 0, Felt::new(7), &mut vm), Ok(()));
+        assert_eq!(
+            insert_struct_field_by_name(
+                base,
+                "starkware.cairo.common.cairo_secp.ec.EcPoint",
+                "y",
+                Felt::new(11),
+                &mut vm,
+                &identifiers
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            vm.get_integer(&relocatable!(1, 0)).unwrap().as_ref(),
+            &Felt::new(7)
+        );
+        assert_eq!(
+            vm.get_integer(&relocatable!(1, 3)).unwrap().as_ref(),
+            &Felt::new(11)
+        );
+    }
+
+    #[test]
+    fn insert_struct_field_by_name_unknown_struct() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        assert_eq!(
+            insert_struct_field_by_name(
+                relocatable!(1, 0),
+                "__main__.Unknown",
+                "x",
+                Felt::new(1),
+                &mut vm,
+                &ec_point_identifiers()
+            ),
+            Err(HintError::UnknownStruct(String::from("__main__.Unknown")))
+        );
+    }
+
+    #[test]
+    fn get_difference_from_var_names_pointers() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 7)), ((1, 1), (2, 3))];
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["end", "start"];
+        assert_eq!(
+            get_difference_from_var_names("end", "start", &vm, &ids_data, &ApTracking::new()),
+            Ok(Felt::new(4))
+        );
+    }
+
+    #[test]
+    fn get_difference_from_var_names_integers() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 3), ((1, 1), 10)];
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["a", "b"];
+        assert_eq!(
+            get_difference_from_var_names("a", "b", &vm, &ids_data, &ApTracking::new()),
+            Ok(Felt::new(-7))
+        );
+    }
+
+    #[test]
+    fn get_difference_from_var_names_invalid_operands() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 7)), ((1, 1), 3), ((1, 2), (3, 0))];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["ptr", "n", "other_ptr"];
+        assert_eq!(
+            get_difference_from_var_names("ptr", "n", &vm, &ids_data, &ApTracking::new()),
+            Err(HintError::InvalidSubOperands(
+                MaybeRelocatable::from((2, 7)),
+                MaybeRelocatable::from(Felt::new(3))
+            ))
+        );
+        assert_eq!(
+            get_difference_from_var_names("ptr", "other_ptr", &vm, &ids_data, &ApTracking::new()),
+            Err(HintError::Internal(VirtualMachineError::DiffIndexSub))
+        );
+    }
 }
@@ -0,0 +1,106 @@
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use num_traits::ToPrimitive;
+use std::collections::HashMap;
+
+/*
+This hint doesn't belong to the Cairo common library, it's used by switch-style code that
+jumps to the branch stored at position `selector` of a table of relative offsets.
+
+Implements hint:
+%{
+    assert 0 <= ids.selector < ids.table_len, \
+        f'Selector {ids.selector} is out of the jump table range [0, {ids.table_len}).'
+    ids.offset = memory[ids.table + ids.selector]
+%}
+*/
+pub fn jump_table_offset(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let selector = get_integer_from_var_name("selector", vm, ids_data, ap_tracking)?;
+    let table_len = get_integer_from_var_name("table_len", vm, ids_data, ap_tracking)?;
+    let table = get_ptr_from_var_name("table", vm, ids_data, ap_tracking)?;
+
+    let index = match selector.to_usize() {
+        Some(index) if selector.as_ref() < table_len.as_ref() => index,
+        _ => {
+            return Err(HintError::JumpTableSelectorOutOfRange(
+                selector.into_owned(),
+                table_len.into_owned(),
+            ))
+        }
+    };
+    let offset = vm.get_integer(&(table + index))?.into_owned();
+    insert_value_from_var_name("offset", offset, vm, ids_data, ap_tracking)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{errors::memory_errors::MemoryError, vm_memory::memory::Memory},
+    };
+    use felt::Felt;
+    use std::any::Any;
+
+    fn jump_table_vm(selector: i32) -> VirtualMachine {
+        let mut vm = vm!();
+        vm.run_context.fp = 4;
+        //ids.selector, ids.table_len, ids.table, and a table of offsets [10, 20, -5]
+        vm.memory = memory![
+            ((1, 0), selector),
+            ((1, 1), 3),
+            ((1, 2), (2, 0)),
+            ((2, 0), 10),
+            ((2, 1), 20),
+            ((2, 2), (-5))
+        ];
+        vm
+    }
+
+    #[test]
+    fn run_jump_table_offset_each_branch() {
+        for (selector, offset) in [(0, Felt::new(10)), (1, Felt::new(20)), (2, Felt::new(-5))] {
+            let mut vm = jump_table_vm(selector);
+            let ids_data = ids_data!["selector", "table_len", "table", "offset"];
+            assert_eq!(
+                run_hint!(vm, ids_data, hint_code::JUMP_TABLE_OFFSET),
+                Ok(())
+            );
+            assert_eq!(vm.get_integer(&(1, 3).into()).unwrap().as_ref(), &offset);
+        }
+    }
+
+    #[test]
+    fn run_jump_table_offset_selector_out_of_range() {
+        let mut vm = jump_table_vm(3);
+        let ids_data = ids_data!["selector", "table_len", "table", "offset"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::JUMP_TABLE_OFFSET),
+            Err(HintError::JumpTableSelectorOutOfRange(
+                Felt::new(3),
+                Felt::new(3)
+            ))
+        );
+    }
+}
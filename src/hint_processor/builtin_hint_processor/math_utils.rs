@@ -1,27 +1,34 @@
 use crate::{
     any_box,
     hint_processor::{
-        builtin_hint_processor::hint_utils::{
-            get_address_from_var_name, get_integer_from_var_name, get_ptr_from_var_name,
-            insert_value_from_var_name, insert_value_into_ap,
+        builtin_hint_processor::{
+            hint_rng::get_hint_rng,
+            hint_utils::{
+                get_address_from_var_name, get_difference_from_var_names,
+                get_integer_from_var_name, get_ptr_from_var_name, get_relocatable_from_var_name,
+                insert_struct_field, insert_value_from_var_name, insert_value_into_ap,
+            },
         },
         hint_processor_definition::HintReference,
     },
-    math_utils::isqrt,
+    math_utils::{cmp_signed, isqrt, safe_div, sqrt_mod_prime},
     serde::deserialize_program::ApTracking,
     types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
     vm::{
         errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        runners::builtin_runner::stark_curve_beta,
         vm_core::VirtualMachine,
     },
 };
 use felt::{Felt, PRIME_STR};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint, Sign};
 use num_integer::Integer;
 use num_traits::One;
-use num_traits::{Num, Signed, Zero};
+use num_traits::{Num, Signed, ToPrimitive, Zero};
+use rand_core::RngCore;
 use std::{
     any::Any,
+    cmp::Ordering,
     collections::HashMap,
     ops::{Shl, Shr},
 };
@@ -108,7 +115,7 @@ pub fn assert_le_felt(
             lengths_and_indices[0].0.clone(),
             div_prime_by_bound(Felt::new(3_i32))?,
             lengths_and_indices[1].0.clone(),
-            div_prime_by_bound(Felt::new(3_i32))?,
+            div_prime_by_bound(Felt::new(2_i32))?,
         ));
     }
 
@@ -196,35 +203,12 @@ pub fn assert_not_equal(
     //Check that the ids are in memory
     match (vm.get_maybe(&a_addr), vm.get_maybe(&b_addr)) {
         (Ok(Some(maybe_rel_a)), Ok(Some(maybe_rel_b))) => {
-            let maybe_rel_a = maybe_rel_a;
-            let maybe_rel_b = maybe_rel_b;
-            match (maybe_rel_a, maybe_rel_b) {
-                (MaybeRelocatable::Int(a), MaybeRelocatable::Int(b)) => {
-                    if (&a - &b).is_zero() {
-                        return Err(HintError::AssertNotEqualFail(
-                            MaybeRelocatable::Int(a),
-                            MaybeRelocatable::Int(b),
-                        ));
-                    };
-                    Ok(())
-                }
-                (MaybeRelocatable::RelocatableValue(a), MaybeRelocatable::RelocatableValue(b)) => {
-                    if a.segment_index != b.segment_index {
-                        Err(VirtualMachineError::DiffIndexComp(a, b))?;
-                    };
-                    if a.offset == b.offset {
-                        return Err(HintError::AssertNotEqualFail(
-                            MaybeRelocatable::RelocatableValue(a),
-                            MaybeRelocatable::RelocatableValue(b),
-                        ));
-                    };
-                    Ok(())
-                }
-                (maybe_rel_a, maybe_rel_b) => Err(VirtualMachineError::DiffTypeComparison(
-                    maybe_rel_a,
-                    maybe_rel_b,
-                ))?,
+            //Unlike the original hint, non-comparable values (an integer and a relocatable, or
+            //relocatables from different segments) are considered different instead of failing
+            if maybe_rel_a == maybe_rel_b {
+                return Err(HintError::AssertNotEqualFail(maybe_rel_a, maybe_rel_b));
             }
+            Ok(())
         }
         _ => Err(HintError::FailedToGetIds),
     }
@@ -288,12 +272,25 @@ pub fn split_int_assert_range(
     Ok(())
 }
 
+/// Order in which `split_int` lays out the limbs of the decomposed value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DigitOrder {
+    /// `output[0]` holds the least significant limb, as in the common library's `split_int`.
+    #[default]
+    LeastSignificantFirst,
+    /// `output[0]` holds the most significant limb. The recursion keeps `output` fixed and the
+    /// hint places each limb at `output[n - 1]`.
+    MostSignificantFirst,
+}
+
 //Implements hint: memory[ids.output] = res = (int(ids.value) % PRIME) % ids.base
 //        assert res < ids.bound, f'split_int(): Limb {res} is out of range.'
+//With DigitOrder::MostSignificantFirst, the limb is written to memory[ids.output + ids.n - 1]
 pub fn split_int(
     vm: &mut VirtualMachine,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
+    order: DigitOrder,
 ) -> Result<(), HintError> {
     let value = get_integer_from_var_name("value", vm, ids_data, ap_tracking)?;
     let base = get_integer_from_var_name("base", vm, ids_data, ap_tracking)?;
@@ -301,9 +298,20 @@ pub fn split_int(
     let base = base.as_ref();
     let bound = bound.as_ref();
     let output = get_ptr_from_var_name("output", vm, ids_data, ap_tracking)?;
+    let output = match order {
+        DigitOrder::LeastSignificantFirst => output,
+        DigitOrder::MostSignificantFirst => {
+            let n = get_integer_from_var_name("n", vm, ids_data, ap_tracking)?;
+            let n = n
+                .to_usize()
+                .filter(|n| *n > 0)
+                .ok_or_else(|| HintError::InvalidSplitIntLimbCount(n.into_owned()))?;
+            output + (n - 1)
+        }
+    };
     //Main Logic
     let res = value.mod_floor(base);
-    if &res > bound {
+    if &res >= bound {
         return Err(HintError::SplitIntLimbOutOfRange(res));
     }
     vm.insert_value(&output, res).map_err(HintError::Internal)
@@ -321,7 +329,7 @@ pub fn is_positive(
     let range_check_builtin = vm.get_range_check_builtin()?;
     //Main logic (assert a is positive)
     match &range_check_builtin._bound {
-        Some(bound) if &value.abs() > bound => {
+        Some(bound) if &value.abs() >= bound => {
             return Err(HintError::ValueOutsideValidRange(value.into_owned()))
         }
         _ => {}
@@ -348,19 +356,112 @@ pub fn split_felt(
     vm: &mut VirtualMachine,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
 ) -> Result<(), HintError> {
+    const MAX_HIGH: &str = "starkware.cairo.common.math.split_felt.MAX_HIGH";
+    const MAX_LOW: &str = "starkware.cairo.common.math.split_felt.MAX_LOW";
+
+    let max_high = constants
+        .get(MAX_HIGH)
+        .ok_or(HintError::MissingConstant(MAX_HIGH))?;
+    let max_low = constants
+        .get(MAX_LOW)
+        .ok_or(HintError::MissingConstant(MAX_LOW))?;
+    //assert ids.MAX_HIGH < 2**128 and ids.MAX_LOW < 2**128
+    //assert PRIME - 1 == ids.MAX_HIGH * 2**128 + ids.MAX_LOW
+    let max_high = max_high.to_biguint();
+    let max_low = max_low.to_biguint();
+    let part_bound = BigUint::one().shl(128_u32);
+    if max_high >= part_bound {
+        return Err(HintError::SplitFeltConstantsCheckFailed(
+            "MAX_HIGH < 2**128".to_string(),
+        ));
+    }
+    if max_low >= part_bound {
+        return Err(HintError::SplitFeltConstantsCheckFailed(
+            "MAX_LOW < 2**128".to_string(),
+        ));
+    }
+    if Felt::prime() - 1_u32 != max_high * part_bound + max_low {
+        return Err(HintError::SplitFeltConstantsCheckFailed(
+            "PRIME - 1 == MAX_HIGH * 2**128 + MAX_LOW".to_string(),
+        ));
+    }
     let value = get_integer_from_var_name("value", vm, ids_data, ap_tracking)?;
     let value = value.as_ref();
     //Main logic
     //assert_integer(ids.value) (done by match)
     // ids.low = ids.value & ((1 << 128) - 1)
     // ids.high = ids.value >> 128
+    //Both operations act on the canonical representation in [0, PRIME), as ids.value does in
+    //python, so "negative" felts are split as the big values they are
     let low: Felt = value & ((Felt::one().shl(128_u32)) - Felt::one());
     let high: Felt = value.shr(128_u32);
     insert_value_from_var_name("high", high, vm, ids_data, ap_tracking)?;
     insert_value_from_var_name("low", low, vm, ids_data, ap_tracking)
 }
 
+/*
+Joins the halves produced by split_felt, as done by the bootloader when writing back split
+program outputs.
+
+Implements hint:
+%{
+    assert ids.low < 2**128 and ids.high < 2**128
+    memory[ids.output_ptr] = ids.high * 2**128 + ids.low
+    ids.new_output_ptr = ids.output_ptr + 1
+%}
+*/
+pub fn join_split_output(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let low = get_integer_from_var_name("low", vm, ids_data, ap_tracking)?;
+    let high = get_integer_from_var_name("high", vm, ids_data, ap_tracking)?;
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+    for (name, part) in [("low", &low), ("high", &high)] {
+        if part.bits() > 128 {
+            return Err(HintError::JoinOutputPartOutOfRange(
+                String::from(name),
+                part.clone().into_owned(),
+            ));
+        }
+    }
+    let value = high.as_ref().shl(128_u32) + low.as_ref();
+    vm.insert_value(&output_ptr, value)?;
+    insert_value_from_var_name("new_output_ptr", output_ptr + 1, vm, ids_data, ap_tracking)
+}
+
+/*
+Registers the output written since ids.page_start as a public memory page of the output builtin,
+as done by the bootloader when splitting the output of its tasks into pages.
+
+Implements hint:
+%{
+    output_builtin.add_page(
+        page_id=ids.page_id,
+        page_start=ids.page_start,
+        page_size=ids.output_ptr - ids.page_start)
+%}
+*/
+pub fn add_output_page(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let page_id = get_integer_from_var_name("page_id", vm, ids_data, ap_tracking)?;
+    let page_id = page_id
+        .to_usize()
+        .ok_or_else(|| HintError::InvalidOutputPageId(page_id.into_owned()))?;
+    let page_start = get_ptr_from_var_name("page_start", vm, ids_data, ap_tracking)?;
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+    let page_size = output_ptr.sub(&page_start)?;
+    vm.get_output_builtin()?
+        .add_page(page_id, page_start, page_size)
+        .map_err(|err| HintError::Internal(VirtualMachineError::RunnerError(err)))
+}
+
 //Implements hint: from starkware.python.math_utils import isqrt
 //        value = ids.value % PRIME
 //        assert value < 2 ** 250, f"value={value} is outside of the range [0, 2**250)."
@@ -473,10 +574,51 @@ pub fn unsigned_div_rem(
     }
 
     let (q, r) = value.div_mod_floor(div.as_ref());
+    //The cairo code range checks q, which must then be below the builtin's bound
+    if let Some(builtin_bound) = &builtin._bound {
+        if &q >= builtin_bound {
+            return Err(HintError::QuotientOutOfRange(q, builtin_bound.clone()));
+        }
+    }
     insert_value_from_var_name("r", r, vm, ids_data, ap_tracking)?;
     insert_value_from_var_name("q", q, vm, ids_data, ap_tracking)
 }
 
+// Fills the two range-checked cells right before ids.range_check_ptr with `first` and `second`,
+// for the functions that bind their outputs to [range_check_ptr] and [range_check_ptr + 1] and
+// advance the pointer by 2 before running the hint. Nothing is written when the pointer is not
+// accessible from the hint
+/*
+Implements hint:
+%{
+    from starkware.python.math_utils import safe_div
+    ids.result = safe_div(ids.a, ids.b)
+%}
+a and b are taken as integers in [0, PRIME), so the division is exact instead of being a
+field division by the inverse of b.
+*/
+pub fn safe_div_felt(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b = get_integer_from_var_name("b", vm, ids_data, ap_tracking)?;
+    let result = safe_div(a.as_ref(), b.as_ref())?;
+    insert_value_from_var_name("result", result, vm, ids_data, ap_tracking)
+}
+
+//Implements hint: ids.res = ids.a - ids.b
+//a and b are either two integers or two pointers into the same segment
+pub fn ids_difference(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let res = get_difference_from_var_names("a", "b", vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("res", res, vm, ids_data, ap_tracking)
+}
+
 //Implements hint: from starkware.cairo.common.math_utils import as_int
 //        # Correctness check.
 //        value = as_int(ids.value, PRIME) % PRIME
@@ -489,19 +631,40 @@ pub fn assert_250_bit(
     ap_tracking: &ApTracking,
 ) -> Result<(), HintError> {
     //Declare constant values
-    let upper_bound = Felt::one().shl(250u32);
+    let high_bound = Felt::one().shl(122u32);
     let shift = Felt::one().shl(128u32);
     let value = get_integer_from_var_name("value", vm, ids_data, ap_tracking)?;
     //Main logic
-    //can be deleted
-    if value.as_ref() > &upper_bound {
-        return Err(HintError::ValueOutside250BitRange(value.into_owned()));
-    }
+    //value < 2**250 holds exactly when high < 2**122, as low < 2**128 by construction
     let (high, low) = value.div_rem(&shift);
+    if high >= high_bound {
+        return Err(HintError::Assert250BitPartOutOfRange(
+            String::from("high"),
+            high,
+        ));
+    }
     insert_value_from_var_name("high", high, vm, ids_data, ap_tracking)?;
     insert_value_from_var_name("low", low, vm, ids_data, ap_tracking)
 }
 
+/*
+Implements hint:
+%{ ids.is_250 = 1 if ids.value < 2**250 else 0 %}
+*/
+pub fn is_250_bits(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let value = get_integer_from_var_name("value", vm, ids_data, ap_tracking)?;
+    let is_250 = if value.bits() <= 250 {
+        Felt::one()
+    } else {
+        Felt::zero()
+    };
+    insert_value_from_var_name("is_250", is_250, vm, ids_data, ap_tracking)
+}
+
 /*
 Implements hint:
 %{
@@ -530,6 +693,105 @@ pub fn assert_lt_felt(
     Ok(())
 }
 
+/*
+Implements hint:
+%{
+    from starkware.cairo.common.math_utils import as_int
+    assert as_int(ids.a, PRIME) < as_int(ids.b, PRIME), \
+        f'a = {as_int(ids.a, PRIME)} is not less than b = {as_int(ids.b, PRIME)}.'
+%}
+Signed counterpart of assert_lt_felt, values above PRIME // 2 are taken as negative.
+*/
+pub fn assert_lt_felt_signed(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b = get_integer_from_var_name("b", vm, ids_data, ap_tracking)?;
+    if cmp_signed(&a, &b) != Ordering::Less {
+        return Err(HintError::AssertLtFeltSigned(a.to_bigint(), b.to_bigint()));
+    }
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.crypto.signature.signature import ALPHA, BETA, FIELD_PRIME
+    from starkware.python.math_utils import recover_y
+    y = recover_y(ids.x, ALPHA, BETA, FIELD_PRIME)
+    ids.y = y if y % 2 == ids.parity else FIELD_PRIME - y
+%}
+*/
+pub fn recover_y(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let x = get_integer_from_var_name("x", vm, ids_data, ap_tracking)?.into_owned();
+    let parity = get_integer_from_var_name("parity", vm, ids_data, ap_tracking)?.to_bool()?;
+    let prime = BigInt::from_str_radix(&PRIME_STR[2..], 16)
+        .map_err(|_| VirtualMachineError::CouldntParsePrime(PRIME_STR.to_string()))?;
+    // y^2 = x^3 + alpha * x + beta, with alpha = 1
+    let y_square = &x * &x * &x + &x + stark_curve_beta();
+    // Felt::sqrt is the integer square root of the felt's representative, not a root modulo the
+    // prime, so it can't be used here
+    let y = sqrt_mod_prime(&y_square.to_bigint(), &prime)
+        .ok_or(HintError::RecoverYPointNotOnCurve(x))?;
+    // y and prime - y always have different parities, as the prime is odd
+    let y = if y.is_odd() == parity { y } else { prime - y };
+    insert_value_from_var_name("y", Felt::new(y), vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hint:
+%{
+    from starkware.crypto.signature.signature import ALPHA, BETA, FIELD_PRIME
+    from starkware.python.math_utils import random_ec_point
+    from starkware.python.utils import to_bytes
+
+    # Define a seed for random_ec_point that's dependent on all the input, so that:
+    #   (1) The added point s is deterministic.
+    #   (2) It's hard to choose inputs for which the builtin will fail.
+    seed = b"".join(map(to_bytes, [ids.p.x, ids.p.y, ids.m, ids.q.x, ids.q.y]))
+    ids.s.x, ids.s.y = random_ec_point(FIELD_PRIME, ALPHA, BETA, seed)
+%}
+The sequence of python's seeded generator can't be reproduced, so the point is drawn from the
+run's hint generator instead, which CairoRunConfig::hint_seed makes deterministic. Any point on
+the curve gives the same result for ec_op.
+*/
+pub fn random_ec_point(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let prime: BigInt = Felt::prime().into();
+    let rng = get_hint_rng(exec_scopes)?;
+    let mut bytes = [0_u8; 32];
+    let (x, y) = loop {
+        // x = random.randrange(FIELD_PRIME), drawing 252-bit values until one is below the prime
+        rng.fill_bytes(&mut bytes);
+        bytes[0] &= 0x0f;
+        let x = BigInt::from_bytes_be(Sign::Plus, &bytes);
+        if x >= prime {
+            continue;
+        }
+        // y^2 = x^3 + alpha * x + beta, with alpha = 1
+        let x = Felt::new(x);
+        let y_square = &x * &x * &x + &x + stark_curve_beta();
+        if let Some(y) = sqrt_mod_prime(&y_square.to_bigint(), &prime) {
+            // python's sqrt returns the smallest root
+            let y = (&prime - &y).min(y);
+            break (x, Felt::new(y));
+        }
+    };
+    let s = get_relocatable_from_var_name("s", vm, ids_data, ap_tracking)?;
+    insert_struct_field(s, 0, x, vm)?;
+    insert_struct_field(s, 1, y, vm)
+}
+
 fn div_prime_by_bound(bound: Felt) -> Result<Felt, VirtualMachineError> {
     let prime = BigUint::from_str_radix(&PRIME_STR[2..], 16)
         .map_err(|_| VirtualMachineError::CouldntParsePrime(PRIME_STR.to_string()))?;
@@ -544,7 +806,8 @@ mod tests {
         any_box,
         hint_processor::builtin_hint_processor::{
             builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
-            hint_code::ASSERT_LE_FELT,
+            hint_code::{self, ASSERT_LE_FELT},
+            hint_rng::{HintRng, HINT_RNG},
         },
         hint_processor::hint_processor_definition::HintProcessor,
         relocatable,
@@ -552,12 +815,15 @@ mod tests {
         types::relocatable::Relocatable,
         utils::test_utils::*,
         vm::{
-            errors::memory_errors::MemoryError, runners::builtin_runner::RangeCheckBuiltinRunner,
-            vm_core::VirtualMachine, vm_memory::memory::Memory,
+            errors::memory_errors::MemoryError,
+            runners::builtin_runner::{OutputBuiltinRunner, PageInfo, RangeCheckBuiltinRunner},
+            vm_core::VirtualMachine,
+            vm_memory::memory::Memory,
         },
     };
-    use felt::felt_str;
+    use felt::{felt_str, MathError};
     use num_traits::Zero;
+    use rand_core::SeedableRng;
     use std::{any::Any, ops::Shl};
 
     #[test]
@@ -719,6 +985,63 @@ mod tests {
         //Hint would return an error if the assertion fails
     }
 
+    // Runs the main assert_le_felt hint and returns the excluded arc
+    fn run_assert_le_felt_excluded(a: Felt, b: Felt) -> Felt {
+        let constants = HashMap::from([
+            (
+                "starkware.cairo.common.math.assert_le_felt.PRIME_OVER_3_HIGH".to_string(),
+                felt_str!("4000000000000088000000000000001", 16),
+            ),
+            (
+                "starkware.cairo.common.math.assert_le_felt.PRIME_OVER_2_HIGH".to_string(),
+                felt_str!("2AAAAAAAAAAAAB05555555555555556", 16),
+            ),
+        ]);
+        let mut vm = vm_with_range_check!();
+        let mut exec_scopes = ExecutionScopes::new();
+        //Initialize fp
+        vm.run_context.fp = 3;
+        add_segments!(vm, 3);
+        //Insert ids into memory
+        vm.insert_value(&Relocatable::from((1, 0)), a).unwrap();
+        vm.insert_value(&Relocatable::from((1, 1)), b).unwrap();
+        vm.insert_value(&Relocatable::from((1, 2)), Relocatable::from((2, 0)))
+            .unwrap();
+        let ids_data = ids_data!["a", "b", "range_check_ptr"];
+        assert_eq!(
+            run_hint!(vm, ids_data, ASSERT_LE_FELT, &mut exec_scopes, &constants),
+            Ok(())
+        );
+        exec_scopes.get::<Felt>("excluded").unwrap()
+    }
+
+    #[test]
+    fn run_assert_le_felt_excludes_arc_0() {
+        //a = PRIME - 3 is the longest arc
+        assert_eq!(
+            run_assert_le_felt_excluded(Felt::new(-3), Felt::new(-2)),
+            Felt::zero()
+        );
+    }
+
+    #[test]
+    fn run_assert_le_felt_excludes_arc_1() {
+        //b - a = PRIME - 3 is the longest arc
+        assert_eq!(
+            run_assert_le_felt_excluded(Felt::one(), Felt::new(-2)),
+            Felt::one()
+        );
+    }
+
+    #[test]
+    fn run_assert_le_felt_excludes_arc_2() {
+        //PRIME - 1 - b = PRIME - 3 is the longest arc
+        assert_eq!(
+            run_assert_le_felt_excluded(Felt::one(), Felt::new(2)),
+            Felt::new(2)
+        );
+    }
+
     #[test]
     fn is_le_felt_hint_true() {
         let hint_code = "memory[ap] = 0 if (ids.a % PRIME) <= (ids.b % PRIME) else 1";
@@ -1085,13 +1408,7 @@ mod tests {
         vm.memory = memory![((1, 8), (2, 0)), ((1, 9), (1, 0))];
         let ids_data = ids_data!["a", "b"];
         //Execute the hint
-        assert_eq!(
-            run_hint!(vm, ids_data, hint_code),
-            Err(HintError::Internal(VirtualMachineError::DiffIndexComp(
-                relocatable!(2, 0),
-                relocatable!(1, 0)
-            )))
-        );
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
     }
 
     #[test]
@@ -1104,15 +1421,18 @@ mod tests {
         vm.memory = memory![((1, 8), (1, 0)), ((1, 9), 1)];
         let ids_data = ids_data!["a", "b"];
         //Execute the hint
-        assert_eq!(
-            run_hint!(vm, ids_data, hint_code),
-            Err(HintError::Internal(
-                VirtualMachineError::DiffTypeComparison(
-                    MaybeRelocatable::from((1, 0)),
-                    MaybeRelocatable::from(Felt::one())
-                )
-            ))
-        );
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+    }
+
+    #[test]
+    fn run_assert_not_equal_integer_and_relocatable() {
+        let hint_code = hint_code::ASSERT_NOT_EQUAL;
+        let mut vm = vm!();
+        vm.run_context.fp = 10;
+        //The integer matches the offset of the relocatable
+        vm.memory = memory![((1, 8), 0), ((1, 9), (1, 0))];
+        let ids_data = ids_data!["a", "b"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
     }
 
     #[test]
@@ -1230,6 +1550,106 @@ mod tests {
         check_memory![vm.memory, ((2, 0), 2)];
     }
 
+    #[test]
+    fn run_split_int_decomposes_least_significant_first() {
+        //split_int calls the hint once per limb, dividing the value by the base in between
+        let mut vm = vm!();
+        add_segments!(vm, 3);
+        let ids_data = ids_data!["output", "value", "base", "bound"];
+        let mut value = 4321;
+        for i in 0..4 {
+            //Each iteration uses a new frame
+            vm.run_context.fp = 4 * (i + 1);
+            let frame = Relocatable::from((1, 4 * i));
+            vm.insert_value(&frame, Relocatable::from((2, i))).unwrap();
+            vm.insert_value(&(frame + 1), Felt::new(value)).unwrap();
+            vm.insert_value(&(frame + 2), Felt::new(10)).unwrap();
+            vm.insert_value(&(frame + 3), Felt::new(10)).unwrap();
+            assert_eq!(
+                run_hint!(vm, ids_data.clone(), hint_code::SPLIT_INT),
+                Ok(())
+            );
+            value /= 10;
+        }
+        check_memory![
+            vm.memory,
+            ((2, 0), 1),
+            ((2, 1), 2),
+            ((2, 2), 3),
+            ((2, 3), 4)
+        ];
+    }
+
+    #[test]
+    fn run_split_int_decomposes_most_significant_first() {
+        //The recursion keeps ids.output fixed and decrements ids.n after each limb
+        let mut vm = vm!();
+        add_segments!(vm, 3);
+        let ids_data = ids_data!["output", "n", "value", "base", "bound"];
+        let mut value = 4321;
+        for i in 0..4 {
+            //Each iteration uses a new frame
+            vm.run_context.fp = 5 * (i + 1);
+            let frame = Relocatable::from((1, 5 * i));
+            vm.insert_value(&frame, Relocatable::from((2, 0))).unwrap();
+            vm.insert_value(&(frame + 1), Felt::new(4 - i)).unwrap();
+            vm.insert_value(&(frame + 2), Felt::new(value)).unwrap();
+            vm.insert_value(&(frame + 3), Felt::new(10)).unwrap();
+            vm.insert_value(&(frame + 4), Felt::new(10)).unwrap();
+            assert_eq!(
+                run_hint!(
+                    vm,
+                    ids_data.clone(),
+                    hint_code::SPLIT_INT_MOST_SIGNIFICANT_FIRST
+                ),
+                Ok(())
+            );
+            value /= 10;
+        }
+        check_memory![
+            vm.memory,
+            ((2, 0), 4),
+            ((2, 1), 3),
+            ((2, 2), 2),
+            ((2, 3), 1)
+        ];
+    }
+
+    #[test]
+    fn run_split_int_most_significant_first_no_limbs_left() {
+        let mut vm = vm!();
+        vm.run_context.fp = 5;
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((1, 1), 0),
+            ((1, 2), 7),
+            ((1, 3), 10),
+            ((1, 4), 10)
+        ];
+        add_segments!(vm, 2);
+        let ids_data = ids_data!["output", "n", "value", "base", "bound"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::SPLIT_INT_MOST_SIGNIFICANT_FIRST),
+            Err(HintError::InvalidSplitIntLimbCount(Felt::zero()))
+        );
+    }
+
+    #[test]
+    fn run_split_int_limb_equal_to_bound() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 4;
+        //Insert ids into memory
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), 9), ((1, 2), 10), ((1, 3), 9)];
+        add_segments!(vm, 2);
+        let ids_data = ids_data!["output", "value", "base", "bound"];
+        //The limb must be strictly below the bound
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::SPLIT_INT),
+            Err(HintError::SplitIntLimbOutOfRange(Felt::new(9)))
+        );
+    }
+
     #[test]
     fn run_split_int_invalid() {
         let hint_code = "memory[ids.output] = res = (int(ids.value) % PRIME) % ids.base\nassert res < ids.bound, f'split_int(): Limb {res} is out of range.'";
@@ -1287,6 +1707,56 @@ mod tests {
         check_memory![vm.memory, ((1, 1), 0)];
     }
 
+    #[test]
+    fn run_is_positive_hint_zero() {
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 2;
+        //Insert ids.value into memory
+        vm.memory = memory![((1, 0), 0)];
+        let ids_data = ids_data!["value", "is_positive"];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code::IS_POSITIVE), Ok(()));
+        //Zero isn't positive
+        check_memory![vm.memory, ((1, 1), 0)];
+    }
+
+    #[test]
+    fn run_is_positive_hint_large_negative() {
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 2;
+        //Insert ids.value = -(2**128 - 1) into memory
+        vm.memory = memory![(
+            (1, 0),
+            (
+                "3618502788666131213697322783095070105282824848410658236509717448704103809026",
+                10
+            )
+        )];
+        let ids_data = ids_data!["value", "is_positive"];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code::IS_POSITIVE), Ok(()));
+        check_memory![vm.memory, ((1, 1), 0)];
+    }
+
+    #[test]
+    fn run_is_positive_hint_value_equal_to_bound() {
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 2;
+        //Insert ids.value = 2**128 into memory
+        vm.memory = memory![((1, 0), ("340282366920938463463374607431768211456", 10))];
+        let ids_data = ids_data!["value", "is_positive"];
+        //The bound itself is outside of the valid range, as in `abs(value) < rc_bound`
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::IS_POSITIVE),
+            Err(HintError::ValueOutsideValidRange(felt_str!(
+                "340282366920938463463374607431768211456"
+            )))
+        );
+    }
+
     #[test]
     fn run_is_positive_hint_outside_valid_range() {
         let hint_code =
@@ -1488,19 +1958,142 @@ mod tests {
     }
 
     #[test]
-    fn signed_div_rem_success() {
-        let hint_code = "from starkware.cairo.common.math_utils import as_int, assert_integer\n\nassert_integer(ids.div)\nassert 0 < ids.div <= PRIME // range_check_builtin.bound, \\\n    f'div={hex(ids.div)} is out of the valid range.'\n\nassert_integer(ids.bound)\nassert ids.bound <= range_check_builtin.bound // 2, \\\n    f'bound={hex(ids.bound)} is out of the valid range.'\n\nint_value = as_int(ids.value, PRIME)\nq, ids.r = divmod(int_value, ids.div)\n\nassert -ids.bound <= q < ids.bound, \\\n    f'{int_value} / {ids.div} = {q} is out of the range [{-ids.bound}, {ids.bound}).'\n\nids.biased_q = q + ids.bound";
+    fn unsigned_div_rem_writes_range_check_cells() {
         let mut vm = vm_with_range_check!();
-        //Initialize fp
-        vm.run_context.fp = 6;
-        //Insert ids into memory
-        vm.memory = memory![((1, 3), 5), ((1, 4), 10), ((1, 5), 29)];
-        //Create ids
-        let ids_data = ids_data!["r", "biased_q", "range_check_ptr", "div", "value", "bound"];
-        //Execute the hint
-        assert!(run_hint!(vm, ids_data, hint_code).is_ok());
-        check_memory![vm.memory, ((1, 0), 0), ((1, 1), 31)];
-    }
+        vm.run_context.fp = 3;
+        //ids.range_check_ptr already points past the two cells holding r and q
+        vm.memory = memory![((1, 0), 5), ((1, 1), 17), ((1, 2), (2, 2))];
+        vm.memory.data.push(Vec::new());
+        //ids.r and ids.q are bound to [range_check_ptr - 2] and [range_check_ptr - 1]
+        let ids_data = HashMap::from([
+            ("div".to_string(), HintReference::new_simple(-3)),
+            ("value".to_string(), HintReference::new_simple(-2)),
+            ("range_check_ptr".to_string(), HintReference::new_simple(-1)),
+            ("r".to_string(), HintReference::new(-1, -2, true, true)),
+            ("q".to_string(), HintReference::new(-1, -1, true, true)),
+        ]);
+        assert_eq!(run_hint!(vm, ids_data, hint_code::UNSIGNED_DIV_REM), Ok(()));
+        check_memory![vm.memory, ((2, 0), 2), ((2, 1), 3)];
+    }
+
+    #[test]
+    fn unsigned_div_rem_quotient_out_of_range() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 4;
+        //(2**129) // 1 doesn't fit in a range-checked cell
+        vm.memory = memory![
+            ((1, 2), 1),
+            ((1, 3), ("680564733841876926926749214863536422912", 10))
+        ];
+        let ids_data = ids_data!["r", "q", "div", "value"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UNSIGNED_DIV_REM),
+            Err(HintError::QuotientOutOfRange(
+                felt_str!("680564733841876926926749214863536422912"),
+                felt_str!("340282366920938463463374607431768211456")
+            ))
+        );
+    }
+
+    #[test]
+    fn safe_div_exact() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        vm.memory = memory![((1, 0), 91), ((1, 1), 7)];
+        let ids_data = ids_data!["a", "b", "result"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::SAFE_DIV), Ok(()));
+        check_memory![vm.memory, ((1, 2), 13)];
+    }
+
+    #[test]
+    fn safe_div_not_divisible() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        vm.memory = memory![((1, 0), 92), ((1, 1), 7)];
+        let ids_data = ids_data!["a", "b", "result"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::SAFE_DIV),
+            Err(HintError::Internal(VirtualMachineError::SafeDivFail(
+                Felt::new(92),
+                Felt::new(7)
+            )))
+        );
+    }
+
+    #[test]
+    fn safe_div_by_zero() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        vm.memory = memory![((1, 0), 92), ((1, 1), 0)];
+        let ids_data = ids_data!["a", "b", "result"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::SAFE_DIV),
+            Err(HintError::Internal(VirtualMachineError::DividedByZero))
+        );
+    }
+
+    #[test]
+    fn ids_difference_pointers() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        vm.memory = memory![((1, 0), (2, 5)), ((1, 1), (2, 1))];
+        let ids_data = ids_data!["a", "b", "res"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::IDS_DIFFERENCE), Ok(()));
+        check_memory![vm.memory, ((1, 2), 4)];
+    }
+
+    #[test]
+    fn ids_difference_integers() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        vm.memory = memory![((1, 0), 12), ((1, 1), 5)];
+        let ids_data = ids_data!["a", "b", "res"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::IDS_DIFFERENCE), Ok(()));
+        check_memory![vm.memory, ((1, 2), 7)];
+    }
+
+    #[test]
+    fn signed_div_rem_success() {
+        let hint_code = "from starkware.cairo.common.math_utils import as_int, assert_integer\n\nassert_integer(ids.div)\nassert 0 < ids.div <= PRIME // range_check_builtin.bound, \\\n    f'div={hex(ids.div)} is out of the valid range.'\n\nassert_integer(ids.bound)\nassert ids.bound <= range_check_builtin.bound // 2, \\\n    f'bound={hex(ids.bound)} is out of the valid range.'\n\nint_value = as_int(ids.value, PRIME)\nq, ids.r = divmod(int_value, ids.div)\n\nassert -ids.bound <= q < ids.bound, \\\n    f'{int_value} / {ids.div} = {q} is out of the range [{-ids.bound}, {ids.bound}).'\n\nids.biased_q = q + ids.bound";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 6;
+        //Insert ids into memory
+        vm.memory = memory![((1, 3), 5), ((1, 4), 10), ((1, 5), 29)];
+        //Create ids
+        let ids_data = ids_data!["r", "biased_q", "range_check_ptr", "div", "value", "bound"];
+        //Execute the hint
+        assert!(run_hint!(vm, ids_data, hint_code).is_ok());
+        check_memory![vm.memory, ((1, 0), 0), ((1, 1), 31)];
+    }
+
+    #[test]
+    fn signed_div_rem_writes_range_check_cells() {
+        let hint_code = "from starkware.cairo.common.math_utils import as_int, assert_integer\n\nassert_integer(ids.div)\nassert 0 < ids.div <= PRIME // range_check_builtin.bound, \\\n    f'div={hex(ids.div)} is out of the valid range.'\n\nassert_integer(ids.bound)\nassert ids.bound <= range_check_builtin.bound // 2, \\\n    f'bound={hex(ids.bound)} is out of the valid range.'\n\nint_value = as_int(ids.value, PRIME)\nq, ids.r = divmod(int_value, ids.div)\n\nassert -ids.bound <= q < ids.bound, \\\n    f'{int_value} / {ids.div} = {q} is out of the range [{-ids.bound}, {ids.bound}).'\n\nids.biased_q = q + ids.bound";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 7;
+        //Insert ids into memory, ids.range_check_ptr already points past the two cells holding
+        //r and biased_q
+        vm.memory = memory![((1, 3), (2, 2)), ((1, 4), 7), ((1, 5), (-10)), ((1, 6), 29)];
+        //Range check segment
+        vm.memory.data.push(Vec::new());
+        //Create ids, r and biased_q are [range_check_ptr - 2] and [range_check_ptr - 1]
+        let ids_data = HashMap::from([
+            ("r".to_string(), HintReference::new(-4, -2, true, true)),
+            (
+                "biased_q".to_string(),
+                HintReference::new(-4, -1, true, true),
+            ),
+            ("range_check_ptr".to_string(), HintReference::new_simple(-4)),
+            ("div".to_string(), HintReference::new_simple(-3)),
+            ("value".to_string(), HintReference::new_simple(-2)),
+            ("bound".to_string(), HintReference::new_simple(-1)),
+        ]);
+        //Execute the hint
+        assert!(run_hint!(vm, ids_data, hint_code).is_ok());
+        check_memory![vm.memory, ((2, 0), 4), ((2, 1), 27)];
+    }
 
     #[test]
     fn signed_div_rem_negative_quotient() {
@@ -1632,10 +2225,114 @@ mod tests {
         //Execute the hint
         assert_eq!(
             run_hint!(vm, ids_data, hint_code),
-            Err(HintError::ValueOutside250BitRange(Felt::one().shl(251_u32)))
+            Err(HintError::Assert250BitPartOutOfRange(
+                String::from("high"),
+                Felt::one().shl(123_u32)
+            ))
+        );
+    }
+
+    #[test]
+    fn run_assert_250_bit_high_part_too_large() {
+        let hint_code = hint_code::ASSERT_250_BITS;
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        //ids.value = 2**250
+        vm.memory = memory![(
+            (1, 0),
+            (
+                "1809251394333065553493296640760748560207343510400633813116524750123642650624",
+                10
+            )
+        )];
+        let ids_data = ids_data!["value", "high", "low"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::Assert250BitPartOutOfRange(
+                String::from("high"),
+                Felt::one().shl(122_u32)
+            ))
         );
     }
 
+    #[test]
+    fn run_assert_250_bit_largest_value() {
+        let hint_code = hint_code::ASSERT_250_BITS;
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        //ids.value = 2**250 - 1
+        vm.memory = memory![(
+            (1, 0),
+            (
+                "1809251394333065553493296640760748560207343510400633813116524750123642650623",
+                10
+            )
+        )];
+        let ids_data = ids_data!["value", "high", "low"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        let high = Felt::one().shl(122_u32) - Felt::one();
+        let low = Felt::one().shl(128_u32) - Felt::one();
+        assert_eq!(vm.get_integer(&(1, 1).into()).unwrap().as_ref(), &high);
+        assert_eq!(vm.get_integer(&(1, 2).into()).unwrap().as_ref(), &low);
+    }
+
+    #[test]
+    fn run_is_250_bits_largest_250_bit_value() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        //ids.value = 2**250 - 1
+        vm.memory = memory![(
+            (1, 0),
+            (
+                "1809251394333065553493296640760748560207343510400633813116524750123642650623",
+                10
+            )
+        )];
+        let ids_data = ids_data!["value", "is_250"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::IS_250_BITS), Ok(()));
+        check_memory![vm.memory, ((1, 1), 1)];
+    }
+
+    #[test]
+    fn run_is_250_bits_251_bit_value() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        //ids.value = 2**250
+        vm.memory = memory![(
+            (1, 0),
+            (
+                "1809251394333065553493296640760748560207343510400633813116524750123642650624",
+                10
+            )
+        )];
+        let ids_data = ids_data!["value", "is_250"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::IS_250_BITS), Ok(()));
+        check_memory![vm.memory, ((1, 1), 0)];
+    }
+
+    #[test]
+    fn run_is_250_bits_zero() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.memory = memory![((1, 0), 0)];
+        let ids_data = ids_data!["value", "is_250"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::IS_250_BITS), Ok(()));
+        check_memory![vm.memory, ((1, 1), 1)];
+    }
+
+    fn split_felt_constants() -> HashMap<String, Felt> {
+        HashMap::from([
+            (
+                "starkware.cairo.common.math.split_felt.MAX_HIGH".to_string(),
+                felt_str!("10633823966279327296825105735305134080"),
+            ),
+            (
+                "starkware.cairo.common.math.split_felt.MAX_LOW".to_string(),
+                Felt::zero(),
+            ),
+        ])
+    }
+
     #[test]
     fn run_split_felt_ok() {
         let hint_code =
@@ -1655,7 +2352,16 @@ mod tests {
             ("high".to_string(), HintReference::new(-3, 1, true, true)),
         ]);
         //Execute the hint
-        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &split_felt_constants()
+            ),
+            Ok(())
+        );
         //Check hint memory inserts
         check_memory![
             vm.memory,
@@ -1664,6 +2370,263 @@ mod tests {
         ];
     }
 
+    #[test]
+    fn run_split_felt_writes_range_check_cells() {
+        let mut vm = vm_with_range_check!();
+        //ids.value = 5 * 2**128 + 7
+        //ids.range_check_ptr already points past the two cells holding low and high
+        vm.memory = memory![
+            ((1, 0), ("1701411834604692317316873037158841057287", 10)),
+            ((1, 1), (2, 2))
+        ];
+        add_segments!(vm, 1);
+        //Initialize fp
+        vm.run_context.fp = 2;
+        //ids.low and ids.high are bound to [range_check_ptr - 2] and [range_check_ptr - 1]
+        let ids_data = HashMap::from([
+            ("value".to_string(), HintReference::new_simple(-2)),
+            ("range_check_ptr".to_string(), HintReference::new_simple(-1)),
+            ("low".to_string(), HintReference::new(-1, -2, true, true)),
+            ("high".to_string(), HintReference::new(-1, -1, true, true)),
+        ]);
+        //Execute the hint
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SPLIT_FELT,
+                exec_scopes_ref!(),
+                &split_felt_constants()
+            ),
+            Ok(())
+        );
+        //Check hint memory inserts
+        check_memory![vm.memory, ((2, 0), 7), ((2, 1), 5)];
+    }
+
+    #[test]
+    fn run_split_felt_value_near_prime() {
+        let mut vm = vm_with_range_check!();
+        //ids.value = -5, which is split from its canonical value PRIME - 5
+        vm.memory = memory![((1, 0), (-5))];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["value", "low", "high"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SPLIT_FELT,
+                exec_scopes_ref!(),
+                &split_felt_constants()
+            ),
+            Ok(())
+        );
+        //low = 2**128 - 4, high = 2**123 + 17 * 2**64 - 1
+        check_memory![
+            vm.memory,
+            ((1, 1), ("340282366920938463463374607431768211452", 10)),
+            ((1, 2), ("10633823966279327296825105735305134079", 10))
+        ];
+    }
+
+    #[test]
+    fn run_split_felt_max_high_too_big() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), 7)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["value", "low", "high"];
+        let mut constants = split_felt_constants();
+        constants.insert(
+            "starkware.cairo.common.math.split_felt.MAX_HIGH".to_string(),
+            felt_str!("340282366920938463463374607431768211456"),
+        );
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SPLIT_FELT,
+                exec_scopes_ref!(),
+                &constants
+            ),
+            Err(HintError::SplitFeltConstantsCheckFailed(
+                "MAX_HIGH < 2**128".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn run_split_felt_max_low_too_big() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), 7)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["value", "low", "high"];
+        let mut constants = split_felt_constants();
+        constants.insert(
+            "starkware.cairo.common.math.split_felt.MAX_LOW".to_string(),
+            felt_str!("340282366920938463463374607431768211456"),
+        );
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SPLIT_FELT,
+                exec_scopes_ref!(),
+                &constants
+            ),
+            Err(HintError::SplitFeltConstantsCheckFailed(
+                "MAX_LOW < 2**128".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn run_split_felt_constants_dont_add_up_to_prime() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), 7)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["value", "low", "high"];
+        let mut constants = split_felt_constants();
+        constants.insert(
+            "starkware.cairo.common.math.split_felt.MAX_HIGH".to_string(),
+            Felt::new(4),
+        );
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SPLIT_FELT,
+                exec_scopes_ref!(),
+                &constants
+            ),
+            Err(HintError::SplitFeltConstantsCheckFailed(
+                "PRIME - 1 == MAX_HIGH * 2**128 + MAX_LOW".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn run_split_felt_missing_constant() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), 7)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["value", "low", "high"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::SPLIT_FELT),
+            Err(HintError::MissingConstant(
+                "starkware.cairo.common.math.split_felt.MAX_HIGH"
+            ))
+        );
+    }
+
+    #[test]
+    fn run_join_split_output_after_split_felt() {
+        let mut vm = vm_with_range_check!();
+        //ids.value = 5 * 2**128 + 7, ids.halves points to (2, 0), ids.output_ptr to (3, 0)
+        vm.memory = memory![
+            ((1, 3), ("1701411834604692317316873037158841057287", 10)),
+            ((1, 4), (2, 0)),
+            ((1, 5), (3, 0))
+        ];
+        add_segments!(vm, 2);
+        vm.run_context.fp = 7;
+        let ids_data = HashMap::from([
+            ("value".to_string(), HintReference::new_simple(-4)),
+            ("low".to_string(), HintReference::new(-3, 0, true, true)),
+            ("high".to_string(), HintReference::new(-3, 1, true, true)),
+            ("output_ptr".to_string(), HintReference::new_simple(-2)),
+            ("new_output_ptr".to_string(), HintReference::new_simple(-1)),
+        ]);
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SPLIT_FELT,
+                exec_scopes_ref!(),
+                &split_felt_constants()
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((2, 0), 7), ((2, 1), 5)];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::JOIN_SPLIT_OUTPUT),
+            Ok(())
+        );
+        check_memory![
+            vm.memory,
+            ((3, 0), ("1701411834604692317316873037158841057287", 10)),
+            ((1, 6), (3, 1))
+        ];
+    }
+
+    #[test]
+    fn run_add_output_page_two_pages() {
+        let mut vm = vm!();
+        //First page: output cells (2, 0) to (2, 2), second page: (2, 3) and (2, 4)
+        vm.memory = memory![
+            ((1, 0), 1),
+            ((1, 1), (2, 0)),
+            ((1, 2), (2, 3)),
+            ((1, 3), 2),
+            ((1, 4), (2, 3)),
+            ((1, 5), (2, 5))
+        ];
+        vm.segments.num_segments = 2;
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        vm.builtin_runners = vec![("output".to_string(), output_builtin.into())];
+        let ids_data = ids_data!["page_id", "page_start", "output_ptr"];
+        vm.run_context.fp = 3;
+        assert_eq!(run_hint!(vm, ids_data, hint_code::ADD_OUTPUT_PAGE), Ok(()));
+        vm.run_context.fp = 6;
+        assert_eq!(run_hint!(vm, ids_data, hint_code::ADD_OUTPUT_PAGE), Ok(()));
+        let output_builtin = vm.get_output_builtin().unwrap();
+        assert_eq!(
+            output_builtin.get_pages(),
+            &HashMap::from([
+                (1, PageInfo { start: 0, size: 3 }),
+                (2, PageInfo { start: 3, size: 2 })
+            ])
+        );
+        //Both pages show in the public memory of the output segment
+        assert_eq!(
+            output_builtin.get_public_memory(5),
+            Ok(vec![(0, 1), (1, 1), (2, 1), (3, 2), (4, 2)])
+        );
+    }
+
+    #[test]
+    fn run_add_output_page_without_output_builtin() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 1), ((1, 1), (2, 0)), ((1, 2), (2, 3))];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["page_id", "page_start", "output_ptr"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::ADD_OUTPUT_PAGE),
+            Err(HintError::Internal(VirtualMachineError::NoOutputBuiltin))
+        );
+    }
+
+    #[test]
+    fn run_join_split_output_part_out_of_range() {
+        let mut vm = vm!();
+        //ids.low = 2**128
+        vm.memory = memory![
+            ((1, 0), ("340282366920938463463374607431768211456", 10)),
+            ((1, 1), 1),
+            ((1, 2), (2, 0))
+        ];
+        add_segments!(vm, 1);
+        vm.run_context.fp = 4;
+        let ids_data = ids_data!["low", "high", "output_ptr", "new_output_ptr"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::JOIN_SPLIT_OUTPUT),
+            Err(HintError::JoinOutputPartOutOfRange(
+                String::from("low"),
+                Felt::one().shl(128_u32)
+            ))
+        );
+    }
+
     #[test]
     fn run_split_felt_incorrect_ids() {
         let hint_code =
@@ -1680,7 +2643,13 @@ mod tests {
         let ids_data = ids_data!["low"];
         //Execute the hint
         assert_eq!(
-            run_hint!(vm, ids_data, hint_code),
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &split_felt_constants()
+            ),
             Err(HintError::FailedToGetIds)
         );
     }
@@ -1706,7 +2675,13 @@ mod tests {
 
         //Execute the hint
         assert_eq!(
-            run_hint!(vm, ids_data, hint_code),
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &split_felt_constants()
+            ),
             Err(HintError::Internal(VirtualMachineError::MemoryError(
                 MemoryError::InconsistentMemory(
                     MaybeRelocatable::from((2, 0)),
@@ -1738,7 +2713,13 @@ mod tests {
         ]);
         //Execute the hint
         assert_eq!(
-            run_hint!(vm, ids_data, hint_code),
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &split_felt_constants()
+            ),
             Err(HintError::Internal(VirtualMachineError::MemoryError(
                 MemoryError::InconsistentMemory(
                     MaybeRelocatable::from((2, 1)),
@@ -1765,7 +2746,13 @@ mod tests {
         ]);
         //Execute the hint
         assert_eq!(
-            run_hint!(vm, ids_data, hint_code),
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                exec_scopes_ref!(),
+                &split_felt_constants()
+            ),
             Err(HintError::Internal(VirtualMachineError::ExpectedInteger(
                 MaybeRelocatable::from((1, 3))
             )))
@@ -1874,4 +2861,126 @@ mod tests {
             )))
         );
     }
+
+    #[test]
+    fn run_assert_lt_felt_signed_negative_operands() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.memory = memory![((1, 0), (-1)), ((1, 1), 1)];
+        let ids_data = ids_data!["a", "b"];
+        //-1 < 1 holds when comparing signed values, but not as unsigned ones
+        assert_eq!(
+            run_hint!(vm, ids_data.clone(), hint_code::ASSERT_LT_FELT_SIGNED),
+            Ok(())
+        );
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::ASSERT_LT_FELT),
+            Err(HintError::AssertLtFelt(Felt::new(-1), Felt::one()))
+        );
+    }
+
+    #[test]
+    fn run_assert_lt_felt_signed_fails() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.memory = memory![((1, 0), 1), ((1, 1), (-1))];
+        let ids_data = ids_data!["a", "b"];
+        assert_eq!(
+            run_hint!(vm, ids_data.clone(), hint_code::ASSERT_LT_FELT_SIGNED),
+            Err(HintError::AssertLtFeltSigned(
+                BigInt::one(),
+                BigInt::from(-1)
+            ))
+        );
+        //1 < PRIME - 1 holds when unsigned
+        assert_eq!(run_hint!(vm, ids_data, hint_code::ASSERT_LT_FELT), Ok(()));
+    }
+
+    #[test]
+    fn run_recover_y_both_parities() {
+        let hint_code = "from starkware.crypto.signature.signature import ALPHA, BETA, FIELD_PRIME\nfrom starkware.python.math_utils import recover_y\ny = recover_y(ids.x, ALPHA, BETA, FIELD_PRIME)\nids.y = y if y % 2 == ids.parity else FIELD_PRIME - y";
+        // x coordinate of the STARK curve generator
+        let x = felt_str!(
+            "1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
+            16
+        );
+        let odd_y = felt_str!(
+            "5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f",
+            16
+        );
+        for (parity, expected_y) in [(1, odd_y.clone()), (0, Felt::zero() - odd_y)] {
+            let mut vm = vm!();
+            //Initialize fp
+            vm.run_context.fp = 3;
+            //Insert ids into memory
+            vm.memory = memory![((1, 1), parity)];
+            vm.insert_value(&relocatable!(1, 0), x.clone()).unwrap();
+            let ids_data = ids_data!["x", "parity", "y"];
+            //Execute the hint
+            assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+            //Check hint memory inserts
+            assert_eq!(
+                vm.get_integer(&relocatable!(1, 2)).unwrap().as_ref(),
+                &expected_y
+            );
+        }
+    }
+
+    #[test]
+    fn run_recover_y_not_on_curve() {
+        let hint_code = "from starkware.crypto.signature.signature import ALPHA, BETA, FIELD_PRIME\nfrom starkware.python.math_utils import recover_y\ny = recover_y(ids.x, ALPHA, BETA, FIELD_PRIME)\nids.y = y if y % 2 == ids.parity else FIELD_PRIME - y";
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 3;
+        //Insert ids into memory, beta is not a quadratic residue so x = 0 has no y
+        vm.memory = memory![((1, 0), 0), ((1, 1), 0)];
+        let ids_data = ids_data!["x", "parity", "y"];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::RecoverYPointNotOnCurve(Felt::zero()))
+        );
+    }
+
+    #[test]
+    fn run_random_ec_point_same_seed_same_point() {
+        let run = |seed: u64| {
+            let mut vm = vm!();
+            add_segments!(vm, 2);
+            //Initialize fp
+            vm.run_context.fp = 2;
+            let ids_data = HashMap::from([("s".to_string(), HintReference::new_simple(-2))]);
+            let mut exec_scopes = ExecutionScopes::new();
+            exec_scopes.insert_value(HINT_RNG, HintRng::seed_from_u64(seed));
+            //Execute the hint
+            assert_eq!(
+                run_hint!(vm, ids_data, hint_code::RANDOM_EC_POINT, &mut exec_scopes),
+                Ok(())
+            );
+            (
+                vm.get_integer(&relocatable!(1, 0)).unwrap().into_owned(),
+                vm.get_integer(&relocatable!(1, 1)).unwrap().into_owned(),
+            )
+        };
+        let (x, y) = run(7);
+        //s is on the STARK curve
+        assert_eq!(&y * &y, &x * &x * &x + &x + stark_curve_beta());
+        assert_eq!(run(7), (x.clone(), y));
+        assert_ne!(run(8).0, x);
+    }
+
+    #[test]
+    fn run_recover_y_parity_not_a_boolean() {
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 3;
+        //Insert ids into memory
+        vm.memory = memory![((1, 0), 0), ((1, 1), 2)];
+        let ids_data = ids_data!["x", "parity", "y"];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::RECOVER_Y),
+            Err(HintError::Math(MathError::NotABoolean(Felt::new(2))))
+        );
+    }
 }
@@ -1,17 +1,21 @@
 pub mod blake2s_hash;
 pub mod blake2s_utils;
 pub mod builtin_hint_processor_definition;
+pub mod cairo1_hints;
 pub mod cairo_keccak;
 pub mod dict_hint_utils;
 pub mod dict_manager;
 pub mod find_element_hint;
 pub mod hint_code;
+pub mod hint_rng;
 pub mod hint_utils;
+pub mod jump_table;
 pub mod keccak_utils;
 pub mod math_utils;
 pub mod memcpy_hint_utils;
 pub mod memset_utils;
 pub mod pow_utils;
+pub mod print;
 pub mod secp;
 pub mod segments;
 pub mod set;
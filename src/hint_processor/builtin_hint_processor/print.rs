@@ -0,0 +1,143 @@
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::get_maybe_relocatable_from_var_name,
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use std::{collections::HashMap, io::Write};
+
+/*
+Debug print hints are not part of the Cairo common library, but compiled programs often carry
+them. A hint is recognized as a print hint when every non-empty line has the form
+`print(ids.<name>)`, and the names are returned in order. Any other code returns None.
+*/
+pub fn parse_print_hint(code: &str) -> Option<Vec<&str>> {
+    let mut names = Vec::new();
+    for line in code.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let name = line
+            .strip_prefix("print(ids.")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .map(str::trim)?;
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        names.push(name);
+    }
+    (!names.is_empty()).then_some(names)
+}
+
+/*
+Implements hint:
+%{ print(ids.<name>) %}
+Felts are written in decimal and relocatables as `segment:offset`, one value per line.
+Failing to write to the sink doesn't affect the execution.
+*/
+pub fn print_vars(
+    vm: &VirtualMachine,
+    names: &[&str],
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    sink: &mut dyn Write,
+) -> Result<(), HintError> {
+    for name in names {
+        let value = get_maybe_relocatable_from_var_name(name, vm, ids_data, ap_tracking)?;
+        writeln!(sink, "{}", value).ok();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::builtin_hint_processor_definition::{
+                BuiltinHintProcessor, HintProcessorData,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::vm_memory::memory::Memory,
+    };
+    use std::{any::Any, cell::RefCell, rc::Rc};
+
+    #[derive(Clone, Default)]
+    struct CapturedSink(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for CapturedSink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parse_print_hint_single_and_multiple_lines() {
+        assert_eq!(parse_print_hint("print(ids.x)"), Some(vec!["x"]));
+        assert_eq!(
+            parse_print_hint("print(ids.a)\n  print(ids.b_ptr)\n"),
+            Some(vec!["a", "b_ptr"])
+        );
+    }
+
+    #[test]
+    fn parse_print_hint_rejects_other_code() {
+        assert_eq!(parse_print_hint(""), None);
+        assert_eq!(parse_print_hint("print(ids.x.y)"), None);
+        assert_eq!(parse_print_hint("print(ids.x)\nids.y = 1"), None);
+        assert_eq!(parse_print_hint("memory[ap] = segments.add()"), None);
+    }
+
+    #[test]
+    fn run_print_hint_with_captured_sink() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.memory = memory![((1, 0), 17), ((1, 1), (2, 3))];
+        let ids_data = ids_data!["x", "ptr"];
+        let sink = CapturedSink::default();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        hint_processor.set_print_sink(Box::new(sink.clone()));
+        let hint_data =
+            HintProcessorData::new_default(String::from("print(ids.x)\nprint(ids.ptr)"), ids_data);
+        assert_eq!(
+            hint_processor.execute_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &any_box!(hint_data),
+                &HashMap::new()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            String::from_utf8(sink.0.borrow().clone()).unwrap(),
+            "17\n2:3\n"
+        );
+        //Execution state is left untouched
+        assert_eq!(vm.run_context.ap, 0);
+    }
+
+    #[test]
+    fn run_print_hint_unknown_variable() {
+        let vm = vm!();
+        let mut sink = CapturedSink::default();
+        assert_eq!(
+            print_vars(
+                &vm,
+                &["x"],
+                &HashMap::new(),
+                &ApTracking::default(),
+                &mut sink
+            ),
+            Err(HintError::FailedToGetIds)
+        );
+        assert!(sink.0.borrow().is_empty());
+    }
+}
@@ -2,10 +2,11 @@ use crate::{
     hint_processor::{
         builtin_hint_processor::{
             hint_utils::{get_relocatable_from_var_name, insert_value_from_var_name},
-            secp::secp_utils::{split, BASE_86},
+            secp::secp_utils::{pack_from_var_name, split, BASE_86},
         },
         hint_processor_definition::HintReference,
     },
+    math_utils::{div_mod, safe_div_bigint},
     serde::deserialize_program::ApTracking,
     types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
     vm::{
@@ -14,7 +15,10 @@ use crate::{
     },
 };
 use felt::Felt;
-use std::collections::HashMap;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, Zero};
+use std::{collections::HashMap, ops::Shl};
 /*
 Implements hint:
 %{
@@ -64,6 +68,73 @@ pub fn bigint_to_uint256(
     insert_value_from_var_name("low", low, vm, ids_data, ap_tracking)
 }
 
+/*
+Implements hint:
+%{
+    from starkware.cairo.common.cairo_secp.secp_utils import pack
+    from starkware.cairo.common.math_utils import as_int
+    from starkware.python.math_utils import div_mod, safe_div
+
+    p = pack(ids.P, PRIME)
+    x = pack(ids.x, PRIME) + as_int(ids.x.d3, PRIME) * ids.BASE ** 3 + as_int(ids.x.d4, PRIME) * ids.BASE ** 4
+    y = pack(ids.y, PRIME)
+
+    value = res = div_mod(x, y, p)
+%}
+*/
+pub fn bigint_pack_div_mod(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let p = pack_from_var_name("P", vm, ids_data, ap_tracking)?;
+    //ids.x is a BigInt5, its limbs are packed with the same 86-bit base as the BigInt3 ones
+    let x_addr = get_relocatable_from_var_name("x", vm, ids_data, ap_tracking)?;
+    let mut x = BigInt::zero();
+    for i in 0..5_usize {
+        x += vm.get_integer(&(x_addr + i))?.to_bigint().shl(86 * i);
+    }
+    let y = pack_from_var_name("y", vm, ids_data, ap_tracking)?;
+    if y.mod_floor(&p).is_zero() {
+        return Err(HintError::PackedDivisionByZero(y, p));
+    }
+
+    let res = div_mod(&x, &y, &p);
+    exec_scopes.insert_value("p", p);
+    exec_scopes.insert_value("x", x);
+    exec_scopes.insert_value("y", y);
+    exec_scopes.insert_value("value", res.clone());
+    exec_scopes.insert_value("res", res);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    k = safe_div(res * y - x, p)
+    value = k if k > 0 else 0 - k
+    ids.flag = 1 if k > 0 else 0
+%}
+*/
+pub fn bigint_safe_div(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let res = exec_scopes.get_ref::<BigInt>("res")?;
+    let x = exec_scopes.get_ref::<BigInt>("x")?;
+    let y = exec_scopes.get_ref::<BigInt>("y")?;
+    let p = exec_scopes.get_ref::<BigInt>("p")?;
+
+    let k = safe_div_bigint(&(res * y - x), p)?;
+    let flag = Felt::new(k.is_positive() as u8);
+    exec_scopes.insert_value("value", k.abs());
+    exec_scopes.insert_value("k", k);
+    insert_value_from_var_name("flag", flag, vm, ids_data, ap_tracking)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,6 +142,7 @@ mod tests {
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::{
         BuiltinHintProcessor, HintProcessorData,
     };
+    use crate::hint_processor::builtin_hint_processor::hint_code;
     use crate::hint_processor::hint_processor_definition::HintProcessor;
     use crate::types::exec_scope::ExecutionScopes;
     use crate::types::relocatable::MaybeRelocatable;
@@ -147,4 +219,108 @@ mod tests {
             Err(HintError::BigIntToBigUintFail)
         );
     }
+
+    #[test]
+    fn run_bigint_pack_div_mod_and_safe_div() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 11;
+        let ids_data = non_continuous_ids_data![("x", -11), ("y", -6), ("P", -3), ("flag", 0)];
+        //x = 2**86 + 20, y = 3, P = 1000003
+        vm.memory = memory![
+            ((1, 0), 20),
+            ((1, 1), 1),
+            ((1, 2), 0),
+            ((1, 3), 0),
+            ((1, 4), 0),
+            ((1, 5), 3),
+            ((1, 6), 0),
+            ((1, 7), 0),
+            ((1, 8), 1000003),
+            ((1, 9), 0),
+            ((1, 10), 0)
+        ];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data.clone(),
+                hint_code::BIGINT_PACK_DIV_MOD,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        let x = BigInt::one().shl(86_u32) + 20;
+        let y = BigInt::from(3);
+        let p = BigInt::from(1000003);
+        let res = exec_scopes.get::<BigInt>("res").unwrap();
+        assert_eq!(exec_scopes.get::<BigInt>("value"), Ok(res.clone()));
+        assert_eq!(exec_scopes.get::<BigInt>("x"), Ok(x.clone()));
+        assert!(!res.is_negative() && res < p);
+
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::BIGINT_SAFE_DIV, &mut exec_scopes),
+            Ok(())
+        );
+        //res * y - x = k * p, where k is negative as x is bigger than p
+        let k = exec_scopes.get::<BigInt>("k").unwrap();
+        assert_eq!(&res * &y - &x, &k * &p);
+        assert!(k.is_negative());
+        assert_eq!(exec_scopes.get::<BigInt>("value"), Ok(-k));
+        check_memory![vm.memory, ((1, 11), 0)];
+    }
+
+    #[test]
+    fn run_bigint_safe_div_positive_quotient() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 1;
+        add_segments!(vm, 2);
+        let ids_data = ids_data!["flag"];
+        //res * y - x = 333341 * 3 - 20 = p
+        let mut exec_scopes = scope![
+            ("res", BigInt::from(333341)),
+            ("x", BigInt::from(20)),
+            ("y", BigInt::from(3)),
+            ("p", BigInt::from(1000003))
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::BIGINT_SAFE_DIV, &mut exec_scopes),
+            Ok(())
+        );
+        assert_eq!(exec_scopes.get::<BigInt>("k"), Ok(BigInt::one()));
+        assert_eq!(exec_scopes.get::<BigInt>("value"), Ok(BigInt::one()));
+        check_memory![vm.memory, ((1, 0), 1)];
+    }
+
+    #[test]
+    fn run_bigint_pack_div_mod_division_by_zero() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 11;
+        let ids_data = non_continuous_ids_data![("x", -11), ("y", -6), ("P", -3)];
+        //y = P = 7
+        vm.memory = memory![
+            ((1, 0), 1),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 0),
+            ((1, 4), 0),
+            ((1, 5), 7),
+            ((1, 6), 0),
+            ((1, 7), 0),
+            ((1, 8), 7),
+            ((1, 9), 0),
+            ((1, 10), 0)
+        ];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::BIGINT_PACK_DIV_MOD,
+                exec_scopes_ref!()
+            ),
+            Err(HintError::PackedDivisionByZero(
+                BigInt::from(7),
+                BigInt::from(7)
+            ))
+        );
+    }
 }
@@ -1,16 +1,21 @@
-use super::secp_utils::pack_from_var_name;
+use super::secp_utils::{pack_from_var_name, split};
 use crate::{
     hint_processor::{
         builtin_hint_processor::{
-            hint_utils::{insert_value_from_var_name, insert_value_into_ap},
+            hint_utils::{
+                get_relocatable_from_var_name, insert_value_from_var_name, insert_value_into_ap,
+            },
             secp::secp_utils::SECP_REM,
         },
         hint_processor_definition::HintReference,
     },
-    math_utils::div_mod,
+    math_utils::{div_mod, sqrt_mod_prime},
     serde::deserialize_program::ApTracking,
-    types::exec_scope::ExecutionScopes,
-    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
 };
 use felt::Felt;
 use num_bigint::BigInt;
@@ -49,6 +54,33 @@ pub fn verify_zero(
     insert_value_from_var_name("q", Felt::new(q), vm, ids_data, ap_tracking)
 }
 
+/*
+Implements hint:
+%{
+    from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+    q, r = divmod(pack(ids.val, PRIME), SECP_P)
+    assert r == 0, f"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}."
+    ids.q = q % PRIME
+%}
+*/
+pub fn verify_zero_with_external_const(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let secp_p = exec_scopes.get_ref::<BigInt>("SECP_P")?;
+
+    let val = pack_from_var_name("val", vm, ids_data, ap_tracking)?;
+    let (q, r) = val.div_rem(secp_p);
+    if !r.is_zero() {
+        return Err(HintError::SecpVerifyZero(val));
+    }
+
+    insert_value_from_var_name("q", Felt::new(q), vm, ids_data, ap_tracking)
+}
+
 /*
 Implements hint:
 %{
@@ -153,14 +185,94 @@ pub fn is_zero_assign_scope_variables(
     Ok(())
 }
 
+/*
+Implements hint:
+%{
+    from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack
+    from starkware.python.math_utils import div_mod
+
+    x = pack(ids.x, PRIME) % SECP_P
+    assert x != 0, f"verify_nonzero: {x} = 0 mod SECP_P."
+    value = x_inv = div_mod(1, x, SECP_P)
+%}
+*/
+pub fn verify_nonzero(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
+) -> Result<(), HintError> {
+    let secp_p = BigInt::one().shl(256_u32)
+        - constants
+            .get(SECP_REM)
+            .ok_or(HintError::MissingConstant(SECP_REM))?
+            .to_bigint();
+
+    let x_packed = pack_from_var_name("x", vm, ids_data, ap_tracking)?;
+    let x = x_packed.mod_floor(&secp_p);
+    if x.is_zero() {
+        return Err(HintError::SecpVerifyNonZero(x_packed));
+    }
+
+    let value = div_mod(&BigInt::one(), &x, &secp_p);
+    exec_scopes.insert_value("x", x);
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("x_inv", value);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.common.cairo_secp.secp_utils import pack, split
+    from starkware.python.math_utils import is_quad_residue, sqrt
+
+    x = pack(ids.x, PRIME) % SECP_P
+    assert is_quad_residue(x, SECP_P), f"sqrt_mod: {x} is not a quadratic residue modulo {SECP_P}."
+    value = y = sqrt(x, SECP_P)
+    segments.write_arg(ids.y.address_, split(y))
+%}
+The prime is taken from the `SECP_P` variable in the current execution scope.
+*/
+pub fn sqrt_mod(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, Felt>,
+) -> Result<(), HintError> {
+    let secp_p = exec_scopes.get::<BigInt>("SECP_P")?;
+
+    let x = pack_from_var_name("x", vm, ids_data, ap_tracking)?.mod_floor(&secp_p);
+    let y = sqrt_mod_prime(&x, &secp_p).ok_or(HintError::QuadraticNonResidue(x, secp_p))?;
+
+    let y_reloc = get_relocatable_from_var_name("y", vm, ids_data, ap_tracking)?;
+    let y_limbs: Vec<MaybeRelocatable> = split(
+        &y.to_biguint().ok_or(HintError::BigIntToBigUintFail)?,
+        constants,
+    )?
+    .into_iter()
+    .map(|n| MaybeRelocatable::from(Felt::new(n)))
+    .collect();
+    vm.write_arg(&y_reloc, &y_limbs)
+        .map_err(VirtualMachineError::MemoryError)?;
+
+    exec_scopes.insert_value("value", y.clone());
+    exec_scopes.insert_value("y", y);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         any_box,
         hint_processor::{
-            builtin_hint_processor::builtin_hint_processor_definition::{
-                BuiltinHintProcessor, HintProcessorData,
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+                secp::secp_utils::BASE_86,
             },
             hint_processor_definition::HintProcessor,
         },
@@ -579,6 +691,67 @@ mod tests {
         );
     }
 
+    fn secp_constants() -> HashMap<String, Felt> {
+        [(
+            SECP_REM,
+            Felt::one().shl(32_u32)
+                + Felt::one().shl(9_u32)
+                + Felt::one().shl(8_u32)
+                + Felt::one().shl(7_u32)
+                + Felt::one().shl(6_u32)
+                + Felt::one().shl(4_u32)
+                + Felt::one(),
+        )]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect()
+    }
+
+    #[test]
+    fn verify_nonzero_ok() {
+        let mut vm = vm_with_range_check!();
+        run_context!(vm, 0, 9, 9);
+        let ids_data = non_continuous_ids_data![("x", -5)];
+        //ids.x = 5 + 3 * 2**86
+        vm.memory = memory![((1, 4), 5), ((1, 5), 3), ((1, 6), 0)];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::VERIFY_NONZERO,
+                &mut exec_scopes,
+                &secp_constants()
+            ),
+            Ok(())
+        );
+        let secp_p = bigint_str!(
+            "115792089237316195423570985008687907853269984665640564039457584007908834671663"
+        );
+        let x = BigInt::from(5) + BigInt::from(3) * BigInt::one().shl(86_u32);
+        let x_inv = exec_scopes.get::<BigInt>("x_inv").unwrap();
+        assert_eq!((x * &x_inv).mod_floor(&secp_p), BigInt::one());
+        assert_eq!(exec_scopes.get::<BigInt>("value"), Ok(x_inv));
+    }
+
+    #[test]
+    fn verify_nonzero_zero_value() {
+        let mut vm = vm_with_range_check!();
+        run_context!(vm, 0, 9, 9);
+        let ids_data = non_continuous_ids_data![("x", -5)];
+        vm.memory = memory![((1, 4), 0), ((1, 5), 0), ((1, 6), 0)];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::VERIFY_NONZERO,
+                exec_scopes_ref!(),
+                &secp_constants()
+            ),
+            Err(HintError::SecpVerifyNonZero(BigInt::zero()))
+        );
+    }
+
     #[test]
     fn is_zero_assign_scope_variables_ok() {
         let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import SECP_P\nfrom starkware.python.math_utils import div_mod\n\nvalue = x_inv = div_mod(1, x, SECP_P)";
@@ -633,6 +806,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_sqrt_mod_ok() {
+        let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import pack, split\nfrom starkware.python.math_utils import is_quad_residue, sqrt\n\nx = pack(ids.x, PRIME) % SECP_P\nassert is_quad_residue(x, SECP_P), f\"sqrt_mod: {x} is not a quadratic residue modulo {SECP_P}.\"\nvalue = y = sqrt(x, SECP_P)\nsegments.write_arg(ids.y.address_, split(y))";
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint data
+        let ids_data = non_continuous_ids_data![("x", -10), ("y", -3)];
+        //x is the square of the x coordinate of the secp256k1 generator
+        vm.memory = memory![
+            ((1, 0), 54050938032766687529729097_u128),
+            ((1, 1), 66775421090101485361320769_u128),
+            ((1, 2), 10073074979586984135539354_u128)
+        ];
+        //Initialize vm scope with the secp256k1 prime
+        let mut exec_scopes = scope![(
+            "SECP_P",
+            bigint_str!(
+                "115792089237316195423570985008687907853269984665640564039457584007908834671663"
+            )
+        )];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                &mut exec_scopes,
+                &[(BASE_86, Felt::one().shl(86_u32))]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v))
+                    .collect()
+            ),
+            Ok(())
+        );
+        //Check that the smallest root was chosen
+        let y = bigint_str!(
+            "55066263022277343669578718895168534326250603453777594175500187360389116729240"
+        );
+        assert_eq!(exec_scopes.get::<BigInt>("value"), Ok(y.clone()));
+        assert_eq!(exec_scopes.get::<BigInt>("y"), Ok(y));
+        //Check the limbs of ids.y
+        check_memory![
+            vm.memory,
+            ((1, 7), 17117865558768631194064792_u128),
+            ((1, 8), 12501176021340589225372855_u128),
+            ((1, 9), 9198697782662356105779718_u128)
+        ];
+    }
+
+    #[test]
+    fn run_sqrt_mod_non_residue() {
+        let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import pack, split\nfrom starkware.python.math_utils import is_quad_residue, sqrt\n\nx = pack(ids.x, PRIME) % SECP_P\nassert is_quad_residue(x, SECP_P), f\"sqrt_mod: {x} is not a quadratic residue modulo {SECP_P}.\"\nvalue = y = sqrt(x, SECP_P)\nsegments.write_arg(ids.y.address_, split(y))";
+        let mut vm = vm!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint data
+        let ids_data = non_continuous_ids_data![("x", -10), ("y", -3)];
+        //-1 is not a quadratic residue modulo the secp256k1 prime, as p % 4 == 3
+        let secp_p = bigint_str!(
+            "115792089237316195423570985008687907853269984665640564039457584007908834671663"
+        );
+        let minus_one = &secp_p - 1_u32;
+        vm.memory = memory![
+            ((1, 0), 77371252455336262886226990_u128),
+            ((1, 1), 77371252455336267181195263_u128),
+            ((1, 2), 19342813113834066795298815_u128)
+        ];
+        let mut exec_scopes = scope![("SECP_P", secp_p.clone())];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code,
+                &mut exec_scopes,
+                &[(BASE_86, Felt::one().shl(86_u32))]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v))
+                    .collect()
+            ),
+            Err(HintError::QuadraticNonResidue(minus_one, secp_p))
+        );
+    }
+
     #[test]
     fn is_zero_assign_scope_variables_scope_error() {
         let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import SECP_P\nfrom starkware.python.math_utils import div_mod\n\nvalue = x_inv = div_mod(1, x, SECP_P)";
@@ -662,4 +920,57 @@ mod tests {
             Err(HintError::VariableNotInScopeError("x".to_string()))
         );
     }
+
+    #[test]
+    fn run_verify_zero_with_external_const_multiple_of_prime() {
+        let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import pack\n\nq, r = divmod(pack(ids.val, PRIME), SECP_P)\nassert r == 0, f\"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}.\"\nids.q = q % PRIME";
+        let mut vm = vm!();
+        //Initialize run_context
+        run_context!(vm, 0, 9, 9);
+        //Create hint data
+        let ids_data = non_continuous_ids_data![("val", -5), ("q", 0)];
+        //val is the packed secp256k1 prime
+        vm.memory = memory![
+            ((1, 4), 77371252455336262886226991_u128),
+            ((1, 5), 77371252455336267181195263_u128),
+            ((1, 6), 19342813113834066795298815_u128)
+        ];
+        //Initialize vm scope with the secp256k1 prime
+        let mut exec_scopes = scope![(
+            "SECP_P",
+            bigint_str!(
+                "115792089237316195423570985008687907853269984665640564039457584007908834671663"
+            )
+        )];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+        //Check hint memory inserts
+        //ids.q
+        check_memory![vm.memory, ((1, 9), 1)];
+    }
+
+    #[test]
+    fn run_verify_zero_with_external_const_not_multiple_of_prime() {
+        let hint_code = "from starkware.cairo.common.cairo_secp.secp_utils import pack\n\nq, r = divmod(pack(ids.val, PRIME), SECP_P)\nassert r == 0, f\"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}.\"\nids.q = q % PRIME";
+        let mut vm = vm!();
+        //Initialize run_context
+        run_context!(vm, 0, 9, 9);
+        //Create hint data
+        let ids_data = non_continuous_ids_data![("val", -5), ("q", 0)];
+        vm.memory = memory![((1, 4), 0), ((1, 5), 0), ((1, 6), 150)];
+        //Initialize vm scope with the secp256k1 prime
+        let mut exec_scopes = scope![(
+            "SECP_P",
+            bigint_str!(
+                "115792089237316195423570985008687907853269984665640564039457584007908834671663"
+            )
+        )];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code, &mut exec_scopes),
+            Err(HintError::SecpVerifyZero(bigint_str!(
+                "897946605976106752944343961220884287276604954404454400"
+            ),))
+        );
+    }
 }
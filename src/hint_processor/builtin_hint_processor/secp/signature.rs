@@ -15,7 +15,7 @@ use crate::{
 use felt::Felt;
 use num_bigint::BigInt;
 use num_integer::Integer;
-use num_traits::One;
+use num_traits::{One, Zero};
 use std::{
     collections::HashMap,
     ops::{Shl, Shr},
@@ -36,9 +36,6 @@ pub fn div_mod_n_packed_divmod(
     ap_tracking: &ApTracking,
     constants: &HashMap<String, Felt>,
 ) -> Result<(), HintError> {
-    let a = pack_from_var_name("a", vm, ids_data, ap_tracking)?;
-    let b = pack_from_var_name("b", vm, ids_data, ap_tracking)?;
-
     let n = {
         let base = constants
             .get(BASE_86)
@@ -60,7 +57,45 @@ pub fn div_mod_n_packed_divmod(
         (n2 * &base * &base) | (n1 * base) | n0
     };
 
-    let value = div_mod(&a, &b, &n);
+    div_mod_packed(vm, exec_scopes, ids_data, ap_tracking, &n)
+}
+
+/* Implements hint:
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+value = res = div_mod(a, b, N)
+
+Unlike div_mod_n_packed_divmod, the modulus N is taken from the scope, so it can be any prime
+*/
+pub fn div_mod_n_packed_external_n(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let n = exec_scopes.get::<BigInt>("N")?;
+    div_mod_packed(vm, exec_scopes, ids_data, ap_tracking, &n)
+}
+
+// Computes a * b^-1 mod n for the packed values ids.a and ids.b, storing the inputs and the
+// result in scope
+fn div_mod_packed(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    n: &BigInt,
+) -> Result<(), HintError> {
+    let a = pack_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b = pack_from_var_name("b", vm, ids_data, ap_tracking)?;
+    if b.mod_floor(n).is_zero() {
+        return Err(HintError::PackedDivisionByZero(b, n.clone()));
+    }
+
+    let value = div_mod(&a, &b, n);
     exec_scopes.insert_value("a", a);
     exec_scopes.insert_value("b", b);
     exec_scopes.insert_value("value", value.clone());
@@ -156,7 +191,6 @@ mod tests {
             vm_memory::memory::Memory,
         },
     };
-    use num_traits::Zero;
     use std::{any::Any, ops::Shl};
 
     #[test]
@@ -191,6 +225,64 @@ mod tests {
         assert_eq!(div_mod_n_safe_div(&mut exec_scopes, &constants), Ok(()));
     }
 
+    #[test]
+    fn div_mod_n_packed_external_n_custom_prime() {
+        let mut vm = vm!();
+        //ids.a = 15 + 3 * 2**86 + 40 * 2**172, ids.b = 10 + 2**172
+        vm.memory = memory![
+            ((1, 0), 15),
+            ((1, 1), 3),
+            ((1, 2), 40),
+            ((1, 3), 10),
+            ((1, 4), 0),
+            ((1, 5), 1)
+        ];
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("a", -3), ("b", 0)];
+        let mut exec_scopes = scope![("N", BigInt::from(97))];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::DIV_MOD_N_PACKED_DIVMOD_EXTERNAL_N,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        //a = 81 (mod 97), b = 91 (mod 97)
+        assert_eq!(exec_scopes.get::<BigInt>("res"), Ok(BigInt::from(35)));
+        assert_eq!(exec_scopes.get::<BigInt>("value"), Ok(BigInt::from(35)));
+    }
+
+    #[test]
+    fn div_mod_n_packed_external_n_zero_divisor() {
+        let mut vm = vm!();
+        //ids.b = 97
+        vm.memory = memory![
+            ((1, 0), 15),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 97),
+            ((1, 4), 0),
+            ((1, 5), 0)
+        ];
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("a", -3), ("b", 0)];
+        let mut exec_scopes = scope![("N", BigInt::from(97))];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::DIV_MOD_N_PACKED_DIVMOD_EXTERNAL_N,
+                &mut exec_scopes
+            ),
+            Err(HintError::PackedDivisionByZero(
+                BigInt::from(97),
+                BigInt::from(97)
+            ))
+        );
+    }
+
     #[test]
     fn safe_div_fail() {
         let mut exec_scopes = scope![
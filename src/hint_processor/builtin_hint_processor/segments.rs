@@ -1,12 +1,17 @@
-use crate::hint_processor::builtin_hint_processor::hint_utils::get_ptr_from_var_name;
+use crate::hint_processor::builtin_hint_processor::hint_utils::{
+    get_integer_from_var_name, get_ptr_from_var_name,
+};
 use crate::hint_processor::{
     builtin_hint_processor::hint_utils::insert_value_from_var_name,
     hint_processor_definition::HintReference,
 };
 use crate::serde::deserialize_program::ApTracking;
+use crate::types::relocatable::Relocatable;
 use crate::vm::errors::hint_errors::HintError;
 use crate::vm::errors::vm_errors::VirtualMachineError;
 use crate::vm::vm_core::VirtualMachine;
+use felt::Felt;
+use num_traits::ToPrimitive;
 use std::collections::HashMap;
 
 /*
@@ -44,6 +49,53 @@ pub fn temporary_array(
     Ok(())
 }
 
+//Returns the number of elements of size elm_size between the pointers start and end.
+//Both pointers must belong to the same segment and be a whole number of elements apart.
+pub fn get_array_length(
+    start: &Relocatable,
+    end: &Relocatable,
+    elm_size: usize,
+) -> Result<usize, HintError> {
+    if start.segment_index != end.segment_index {
+        return Err(HintError::ArrayPointersInDifferentSegments(*start, *end));
+    }
+    if elm_size == 0 {
+        return Err(HintError::InvalidArrayElmSize(Felt::new(elm_size)));
+    }
+    let distance = end.sub(start)?;
+    if distance % elm_size != 0 {
+        return Err(HintError::ArrayLengthNotMultipleOfElmSize(
+            distance, elm_size,
+        ));
+    }
+    Ok(distance / elm_size)
+}
+
+/*
+This hint doesn't belong to the Cairo common library
+
+Implements hint:
+%{
+    assert (ids.arr_end - ids.arr_start) % ids.elm_size == 0
+    ids.len = (ids.arr_end - ids.arr_start) // ids.elm_size
+%}
+*/
+pub fn array_length(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let arr_start = get_ptr_from_var_name("arr_start", vm, ids_data, ap_tracking)?;
+    let arr_end = get_ptr_from_var_name("arr_end", vm, ids_data, ap_tracking)?;
+    let elm_size = get_integer_from_var_name("elm_size", vm, ids_data, ap_tracking)?;
+    let elm_size = elm_size
+        .to_usize()
+        .ok_or_else(|| HintError::InvalidArrayElmSize(elm_size.clone().into_owned()))?;
+
+    let len = get_array_length(&arr_start, &arr_end, elm_size)?;
+    insert_value_from_var_name("len", Felt::new(len), vm, ids_data, ap_tracking)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +154,46 @@ mod tests {
         assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
         check_memory!(vm.memory, ((1, 0), (-1, 0)));
     }
+
+    #[test]
+    fn get_array_length_valid() {
+        assert_eq!(get_array_length(&(2, 3).into(), &(2, 9).into(), 3), Ok(2));
+        assert_eq!(get_array_length(&(2, 3).into(), &(2, 3).into(), 3), Ok(0));
+    }
+
+    #[test]
+    fn get_array_length_not_a_multiple_of_elm_size() {
+        assert_eq!(
+            get_array_length(&(2, 3).into(), &(2, 8).into(), 3),
+            Err(HintError::ArrayLengthNotMultipleOfElmSize(5, 3))
+        );
+    }
+
+    #[test]
+    fn run_array_length() {
+        let hint_code = hint_code::ARRAY_LENGTH;
+        let mut vm = vm!();
+        vm.run_context.fp = 4;
+        //ids.arr_start, ids.arr_end, ids.elm_size
+        vm.memory = memory![((1, 0), (2, 1)), ((1, 1), (2, 9)), ((1, 2), 2)];
+        let ids_data = ids_data!["arr_start", "arr_end", "elm_size", "len"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        check_memory![vm.memory, ((1, 3), 4)];
+    }
+
+    #[test]
+    fn run_array_length_different_segments() {
+        let hint_code = hint_code::ARRAY_LENGTH;
+        let mut vm = vm!();
+        vm.run_context.fp = 4;
+        vm.memory = memory![((1, 0), (2, 1)), ((1, 1), (3, 9)), ((1, 2), 2)];
+        let ids_data = ids_data!["arr_start", "arr_end", "elm_size", "len"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::ArrayPointersInDifferentSegments(
+                (2, 1).into(),
+                (3, 9).into()
+            ))
+        );
+    }
 }
@@ -274,6 +274,8 @@ pub fn squash_dict(
             .or_default()
             .push(Felt::new(i));
     }
+    //The squashed accesses are part of the accessed memory
+    vm.mark_range_as_accessed(address, n_accesses_usize * DICT_ACCESS_SIZE);
     //Descending list of keys.
     let mut keys: Vec<Felt> = access_indices.keys().cloned().collect();
     keys.sort();
@@ -305,7 +307,7 @@ mod tests {
             },
             hint_processor_definition::HintProcessor,
         },
-        types::exec_scope::ExecutionScopes,
+        types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
         utils::test_utils::*,
         vm::{
             errors::memory_errors::MemoryError, runners::builtin_runner::RangeCheckBuiltinRunner,
@@ -778,6 +780,41 @@ mod tests {
         check_memory![vm.memory, ((1, 1), 0), ((1, 2), 1)];
     }
 
+    #[test]
+    fn squash_dict_marks_dict_accesses_as_accessed() {
+        //Dict = {1: (1,1), 1: (1,2)}
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 5;
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((1, 3), 6),
+            ((1, 4), 2),
+            ((2, 0), 1),
+            ((2, 1), 1),
+            ((2, 2), 1),
+            ((2, 3), 1),
+            ((2, 4), 1),
+            ((2, 5), 2)
+        ];
+        let ids_data = ids_data![
+            "dict_accesses",
+            "big_keys",
+            "first_key",
+            "ptr_diff",
+            "n_accesses"
+        ];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_eq!(
+            run_hint!(vm, ids_data, SQUASH_DICT, &mut exec_scopes),
+            Ok(())
+        );
+        let accessed_addresses = vm.accessed_addresses.as_ref().unwrap();
+        for offset in 0..6 {
+            assert!(accessed_addresses.contains(&Relocatable::from((2, offset))));
+        }
+        assert!(!accessed_addresses.contains(&Relocatable::from((2, 6))));
+    }
+
     #[test]
     fn squash_dict_valid_two_key_dict_no_max_size() {
         //Dict = {1: (1,1), 1: (1,2), 2: (10,10), 2: (10,20)}
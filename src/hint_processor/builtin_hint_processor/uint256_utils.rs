@@ -9,8 +9,9 @@ use crate::{
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
 };
 use felt::Felt;
+use num_bigint::BigUint;
 use num_integer::div_rem;
-use num_traits::{One, Signed, Zero};
+use num_traits::{One, Zero};
 use std::{
     collections::HashMap,
     ops::{Shl, Shr},
@@ -75,13 +76,9 @@ pub fn split_64(
     ap_tracking: &ApTracking,
 ) -> Result<(), HintError> {
     let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
-    let mut digits = a.iter_u64_digits();
-    let low = Felt::new(digits.next().unwrap_or(0u64));
-    let high = if digits.len() <= 1 {
-        Felt::new(digits.next().unwrap_or(0u64))
-    } else {
-        a.as_ref().shr(64_u32)
-    };
+    let low = a.as_ref() & &Felt::new(u64::MAX);
+    //Every bit above the lowest 64 goes to high, even if a doesn't fit in 128 bits
+    let high = a.as_ref().shr(64_u32);
     insert_value_from_var_name("high", high, vm, ids_data, ap_tracking)?;
     insert_value_from_var_name("low", low, vm, ids_data, ap_tracking)
 }
@@ -119,7 +116,7 @@ pub fn uint256_sqrt(
 
     let root = isqrt(&(&n_high.to_biguint().shl(128_u32) + n_low.to_biguint()))?;
 
-    if root >= num_bigint::BigUint::one().shl(128_u32) {
+    if root >= BigUint::one().shl(128_u32) {
         return Err(HintError::AssertionFailed(format!(
             "assert 0 <= {} < 2 ** 128",
             &root
@@ -143,7 +140,8 @@ pub fn uint256_signed_nn(
     let a_high = vm.get_integer(&(a_addr + 1_usize))?;
     //Main logic
     //memory[ap] = 1 if 0 <= (ids.a.high % PRIME) < 2 ** 127 else 0
-    let result: Felt = if !a_high.is_negative() && a_high.as_ref() <= &Felt::new(i128::MAX) {
+    //The high limb holds the sign in its top bit (bit 127)
+    let result: Felt = if a_high.bits() <= 127 {
         Felt::one()
     } else {
         Felt::zero()
@@ -151,6 +149,37 @@ pub fn uint256_signed_nn(
     insert_value_into_ap(vm, result)
 }
 
+/*
+Implements hint:
+%{
+    a = (ids.a.high << 128) + ids.a.low
+    b = (ids.b.high << 128) + ids.b.low
+    assert a <= b, f'a = {a} is not less than or equal to b = {b}.'
+%}
+*/
+pub fn assert_uint256_le(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b_addr = get_relocatable_from_var_name("b", vm, ids_data, ap_tracking)?;
+    let a_low = vm.get_integer(&a_addr)?.to_biguint();
+    let a_high = vm.get_integer(&(a_addr + 1_usize))?.to_biguint();
+    let b_low = vm.get_integer(&b_addr)?.to_biguint();
+    let b_high = vm.get_integer(&(b_addr + 1_usize))?.to_biguint();
+
+    //Main logic
+    //Compare the high limbs first, falling back to the low limbs when they are equal
+    if (&a_high, &a_low) > (&b_high, &b_low) {
+        return Err(HintError::NonLeUint256(
+            a_high.shl(128_u32) + a_low,
+            b_high.shl(128_u32) + b_low,
+        ));
+    }
+    Ok(())
+}
+
 /*
 Implements hint:
 %{
@@ -174,14 +203,10 @@ pub fn uint256_unsigned_div_rem(
     let quotient_addr = get_relocatable_from_var_name("quotient", vm, ids_data, ap_tracking)?;
     let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
 
-    let a_low = vm.get_integer(&a_addr)?;
-    let a_high = vm.get_integer(&(a_addr + 1_usize))?;
-    let div_low = vm.get_integer(&div_addr)?;
-    let div_high = vm.get_integer(&(div_addr + 1_usize))?;
-    let a_low = a_low.as_ref();
-    let a_high = a_high.as_ref();
-    let div_low = div_low.as_ref();
-    let div_high = div_high.as_ref();
+    let a_low = vm.get_integer(&a_addr)?.to_biguint();
+    let a_high = vm.get_integer(&(a_addr + 1_usize))?.to_biguint();
+    let div_low = vm.get_integer(&div_addr)?.to_biguint();
+    let div_high = vm.get_integer(&(div_addr + 1_usize))?.to_biguint();
 
     //Main logic
     //a = (ids.a.high << 128) + ids.a.low
@@ -193,16 +218,19 @@ pub fn uint256_unsigned_div_rem(
     //ids.remainder.low = remainder & ((1 << 128) - 1)
     //ids.remainder.high = remainder >> 128
 
-    let a = &a_high.shl(128_usize) + a_low;
-    let div = &div_high.shl(128_usize) + div_low;
-    //a and div will always be positive numbers
-    //Then, Rust div_rem equals Python divmod
+    //The limbs are combined as BigUints, as 256-bit values don't fit in a felt
+    let a = a_high.shl(128_u32) + a_low;
+    let div = div_high.shl(128_u32) + div_low;
+    if div.is_zero() {
+        return Err(HintError::Uint256DivisionByZero);
+    }
     let (quotient, remainder) = div_rem(a, div);
-    let quotient_low = &quotient & &Felt::new(u128::MAX);
-    let quotient_high = quotient.shr(128);
+    let low_mask = BigUint::from(u128::MAX);
+    let quotient_low = Felt::new(&quotient & &low_mask);
+    let quotient_high = Felt::new(quotient.shr(128_u32));
 
-    let remainder_low = &remainder & &Felt::new(u128::MAX);
-    let remainder_high = remainder.shr(128);
+    let remainder_low = Felt::new(&remainder & &low_mask);
+    let remainder_high = Felt::new(remainder.shr(128_u32));
 
     //Insert ids.quotient.low
     vm.insert_value(&quotient_addr, quotient_low)?;
@@ -215,14 +243,193 @@ pub fn uint256_unsigned_div_rem(
     Ok(())
 }
 
+/*
+Implements hint:
+%{
+    a = (ids.a.high << 128) + ids.a.low
+    b = (ids.b.high << 128) + ids.b.low
+    div = (ids.div.high << 128) + ids.div.low
+    quotient, remainder = divmod(a * b, div)
+
+    ids.quotient_low.low = quotient & ((1 << 128) - 1)
+    ids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)
+    ids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)
+    ids.quotient_high.high = quotient >> 384
+    ids.remainder.low = remainder & ((1 << 128) - 1)
+    ids.remainder.high = remainder >> 128
+%}
+*/
+pub fn uint256_mul_div_mod(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b_addr = get_relocatable_from_var_name("b", vm, ids_data, ap_tracking)?;
+    let div_addr = get_relocatable_from_var_name("div", vm, ids_data, ap_tracking)?;
+    let quotient_low_addr =
+        get_relocatable_from_var_name("quotient_low", vm, ids_data, ap_tracking)?;
+    let quotient_high_addr =
+        get_relocatable_from_var_name("quotient_high", vm, ids_data, ap_tracking)?;
+    let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
+
+    let a_low = vm.get_integer(&a_addr)?.to_biguint();
+    let a_high = vm.get_integer(&(a_addr + 1_usize))?.to_biguint();
+    let b_low = vm.get_integer(&b_addr)?.to_biguint();
+    let b_high = vm.get_integer(&(b_addr + 1_usize))?.to_biguint();
+    let div_low = vm.get_integer(&div_addr)?.to_biguint();
+    let div_high = vm.get_integer(&(div_addr + 1_usize))?.to_biguint();
+
+    let a = a_high.shl(128_u32) + a_low;
+    let b = b_high.shl(128_u32) + b_low;
+    let div = div_high.shl(128_u32) + div_low;
+    if div.is_zero() {
+        return Err(HintError::Uint256DivisionByZero);
+    }
+    //The product can take up to 512 bits, so the quotient is written as two uint256
+    let (quotient, remainder) = div_rem(a * b, div);
+    let low_mask = BigUint::from(u128::MAX);
+    let limb = |value: &BigUint, shift: u32| Felt::new(value.shr(shift) & &low_mask);
+
+    vm.insert_value(&quotient_low_addr, limb(&quotient, 0))?;
+    vm.insert_value(&(quotient_low_addr + 1_i32), limb(&quotient, 128))?;
+    vm.insert_value(&quotient_high_addr, limb(&quotient, 256))?;
+    vm.insert_value(&(quotient_high_addr + 1_i32), limb(&quotient, 384))?;
+    vm.insert_value(&remainder_addr, limb(&remainder, 0))?;
+    vm.insert_value(&(remainder_addr + 1_i32), limb(&remainder, 128))?;
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    a = (ids.a.high << 128) + ids.a.low
+    b = (ids.b.high << 128) + ids.b.low
+    res = a * b
+    assert res < 2 ** 256, f'a * b = {res} overflows a uint256.'
+    ids.res.low = res & ((1 << 128) - 1)
+    ids.res.high = res >> 128
+%}
+*/
+pub fn assert_uint256_mul_no_overflow(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b_addr = get_relocatable_from_var_name("b", vm, ids_data, ap_tracking)?;
+    let res_addr = get_relocatable_from_var_name("res", vm, ids_data, ap_tracking)?;
+
+    let a_low = vm.get_integer(&a_addr)?.to_biguint();
+    let a_high = vm.get_integer(&(a_addr + 1_usize))?.to_biguint();
+    let b_low = vm.get_integer(&b_addr)?.to_biguint();
+    let b_high = vm.get_integer(&(b_addr + 1_usize))?.to_biguint();
+
+    let a = a_high.shl(128_u32) + a_low;
+    let b = b_high.shl(128_u32) + b_low;
+    //The high 256 bits of the 512-bit product have to be zero
+    let res = a * b;
+    if res.bits() > 256 {
+        return Err(HintError::Uint256MulOverflow(res));
+    }
+    let low_mask = BigUint::from(u128::MAX);
+    vm.insert_value(&res_addr, Felt::new(&res & &low_mask))?;
+    vm.insert_value(&(res_addr + 1_i32), Felt::new(res.shr(128_u32)))?;
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    assert ids.a.low < 2**128 and ids.a.high < 2**128
+    assert ids.b.low < 2**128 and ids.b.high < 2**128
+    ids.res.low = ids.a.low & ids.b.low
+    ids.res.high = ids.a.high & ids.b.high
+%}
+*/
+pub fn uint256_and(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    uint256_bitwise(vm, ids_data, ap_tracking, |a, b| a & b)
+}
+
+/*
+Implements hint:
+%{
+    assert ids.a.low < 2**128 and ids.a.high < 2**128
+    assert ids.b.low < 2**128 and ids.b.high < 2**128
+    ids.res.low = ids.a.low | ids.b.low
+    ids.res.high = ids.a.high | ids.b.high
+%}
+*/
+pub fn uint256_or(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    uint256_bitwise(vm, ids_data, ap_tracking, |a, b| a | b)
+}
+
+/*
+Implements hint:
+%{
+    assert ids.a.low < 2**128 and ids.a.high < 2**128
+    assert ids.b.low < 2**128 and ids.b.high < 2**128
+    ids.res.low = ids.a.low ^ ids.b.low
+    ids.res.high = ids.a.high ^ ids.b.high
+%}
+*/
+pub fn uint256_xor(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    uint256_bitwise(vm, ids_data, ap_tracking, |a, b| a ^ b)
+}
+
+//Applies op limb by limb to ids.a and ids.b and writes the result limbs into ids.res, after
+//checking that every input limb fits in 128 bits
+fn uint256_bitwise(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    op: fn(&Felt, &Felt) -> Felt,
+) -> Result<(), HintError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b_addr = get_relocatable_from_var_name("b", vm, ids_data, ap_tracking)?;
+    let res_addr = get_relocatable_from_var_name("res", vm, ids_data, ap_tracking)?;
+
+    let mut res = Vec::with_capacity(2);
+    for (i, limb) in ["low", "high"].into_iter().enumerate() {
+        let a_limb = vm.get_integer(&(a_addr + i))?;
+        let b_limb = vm.get_integer(&(b_addr + i))?;
+        for (name, value) in [("a", &a_limb), ("b", &b_limb)] {
+            if value.bits() > 128 {
+                return Err(HintError::Uint256LimbOutOfRange(
+                    format!("{name}.{limb}"),
+                    value.as_ref().clone(),
+                ));
+            }
+        }
+        res.push(op(&a_limb, &b_limb));
+    }
+    for (i, limb) in res.into_iter().enumerate() {
+        vm.insert_value(&(res_addr + i), limb)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         any_box,
         hint_processor::{
-            builtin_hint_processor::builtin_hint_processor_definition::{
-                BuiltinHintProcessor, HintProcessorData,
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
             },
             hint_processor_definition::HintProcessor,
         },
@@ -335,6 +542,40 @@ mod tests {
         ];
     }
 
+    #[test]
+    fn run_split_64_fits_in_low_half() {
+        let hint_code = hint_code::SPLIT_64;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = non_continuous_ids_data![("a", -3), ("high", 1), ("low", 0)];
+        vm.memory = memory![((1, 7), 7249717543555297151_u64)];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        check_memory![&vm.memory, ((1, 10), 7249717543555297151_u64), ((1, 11), 0)];
+    }
+
+    #[test]
+    fn run_split_64_above_128_bits() {
+        let hint_code = hint_code::SPLIT_64;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = non_continuous_ids_data![("a", -3), ("high", 1), ("low", 0)];
+        //a = 2**200 + 5
+        vm.memory = memory![(
+            (1, 7),
+            (
+                "1606938044258990275541962092341162602522202993782792835301381",
+                10
+            )
+        )];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //high = 2**136
+        check_memory![
+            &vm.memory,
+            ((1, 10), 5),
+            ((1, 11), ("87112285931760246646623899502532662132736", 10))
+        ];
+    }
+
     #[test]
     fn run_split_64_memory_error() {
         let hint_code = "ids.low = ids.a & ((1<<64) - 1)\nids.high = ids.a >> 64";
@@ -467,6 +708,40 @@ mod tests {
         check_memory![&vm.memory, ((1, 5), 0)];
     }
 
+    #[test]
+    fn run_signed_nn_small_positive() {
+        let mut vm = vm_with_range_check!();
+        //Initialize run_context
+        run_context!(vm, 0, 5, 4);
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("a", -4)];
+        //Insert ids.a.low and ids.a.high into memory
+        vm.memory = memory![((1, 0), 0), ((1, 1), 5)];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT256_SIGNED_NN),
+            Ok(())
+        );
+        check_memory![&vm.memory, ((1, 5), 1)];
+    }
+
+    #[test]
+    fn run_signed_nn_sign_bit_set() {
+        let mut vm = vm_with_range_check!();
+        //Initialize run_context
+        run_context!(vm, 0, 5, 4);
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("a", -4)];
+        //Insert ids.a.high = 2**128 - 1 into memory
+        vm.memory = memory![((1, 1), ("340282366920938463463374607431768211455", 10))];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT256_SIGNED_NN),
+            Ok(())
+        );
+        check_memory![&vm.memory, ((1, 5), 0)];
+    }
+
     #[test]
     fn run_signed_nn_ok_invalid_memory_insert() {
         let hint_code = "memory[ap] = 1 if 0 <= (ids.a.high % PRIME) < 2 ** 127 else 0";
@@ -513,6 +788,124 @@ mod tests {
         ];
     }
 
+    #[test]
+    fn run_unsigned_div_rem_exact_division() {
+        let hint_code = hint_code::UINT256_UNSIGNED_DIV_REM;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data =
+            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)];
+        //a = 21 * 2**128 + 42, div = 7 * 2**128 + 14
+        vm.memory = memory![((1, 4), 42), ((1, 5), 21), ((1, 6), 14), ((1, 7), 7)];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        check_memory![
+            &vm.memory,
+            ((1, 10), 3),
+            ((1, 11), 0),
+            ((1, 12), 0),
+            ((1, 13), 0)
+        ];
+    }
+
+    #[test]
+    fn run_unsigned_div_rem_above_field_prime() {
+        let hint_code = hint_code::UINT256_UNSIGNED_DIV_REM;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data =
+            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)];
+        //a = 2**256 - 1, div = 2**128
+        vm.memory = memory![
+            ((1, 4), ("340282366920938463463374607431768211455", 10)),
+            ((1, 5), ("340282366920938463463374607431768211455", 10)),
+            ((1, 6), 0),
+            ((1, 7), 1)
+        ];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        check_memory![
+            &vm.memory,
+            ((1, 10), ("340282366920938463463374607431768211455", 10)),
+            ((1, 11), 0),
+            ((1, 12), ("340282366920938463463374607431768211455", 10)),
+            ((1, 13), 0)
+        ];
+    }
+
+    #[test]
+    fn run_unsigned_div_rem_division_by_zero() {
+        let hint_code = hint_code::UINT256_UNSIGNED_DIV_REM;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data =
+            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)];
+        vm.memory = memory![((1, 4), 89), ((1, 5), 72), ((1, 6), 0), ((1, 7), 0)];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::Uint256DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn run_mul_div_mod_512_bit_intermediate() {
+        let hint_code = hint_code::UINT256_MUL_DIV_MOD;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = non_continuous_ids_data![
+            ("a", -8),
+            ("b", -6),
+            ("div", -4),
+            ("quotient_low", 0),
+            ("quotient_high", 2),
+            ("remainder", 4)
+        ];
+        //a = 2**255 + 12345, b = 2**200 + 3, div = 2**130 + 17
+        vm.memory = memory![
+            ((1, 2), 12345),
+            ((1, 3), ("170141183460469231731687303715884105728", 10)),
+            ((1, 4), 3),
+            ((1, 5), ("4722366482869645213696", 10)),
+            ((1, 6), 17),
+            ((1, 7), 4)
+        ];
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        check_memory![
+            &vm.memory,
+            ((1, 10), ("127605887595366508864541308833574682622", 10)),
+            ((1, 11), ("340282366920938460954617413407269191680", 10)),
+            ((1, 12), ("590295810358705651711", 10)),
+            ((1, 13), 0),
+            ((1, 14), ("212676479325338593546420001851607912653", 10)),
+            ((1, 15), 1)
+        ];
+    }
+
+    #[test]
+    fn run_mul_div_mod_division_by_zero() {
+        let hint_code = hint_code::UINT256_MUL_DIV_MOD;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = non_continuous_ids_data![
+            ("a", -8),
+            ("b", -6),
+            ("div", -4),
+            ("quotient_low", 0),
+            ("quotient_high", 2),
+            ("remainder", 4)
+        ];
+        vm.memory = memory![
+            ((1, 2), 2),
+            ((1, 3), 0),
+            ((1, 4), 3),
+            ((1, 5), 0),
+            ((1, 6), 0),
+            ((1, 7), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::Uint256DivisionByZero)
+        );
+    }
+
     #[test]
     fn run_unsigned_div_rem_invalid_memory_insert() {
         let hint_code = "a = (ids.a.high << 128) + ids.a.low\ndiv = (ids.div.high << 128) + ids.div.low\nquotient, remainder = divmod(a, div)\n\nids.quotient.low = quotient & ((1 << 128) - 1)\nids.quotient.high = quotient >> 128\nids.remainder.low = remainder & ((1 << 128) - 1)\nids.remainder.high = remainder >> 128";
@@ -542,4 +935,141 @@ mod tests {
             )))
         );
     }
+
+    #[test]
+    fn run_assert_uint256_le_lower() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\nb = (ids.b.high << 128) + ids.b.low\nassert a <= b, f'a = {a} is not less than or equal to b = {b}.'";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 4;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("a", -4), ("b", -2)];
+        //a.high < b.high, even though a.low > b.low
+        vm.memory = memory![((1, 0), 10), ((1, 1), 1), ((1, 2), 5), ((1, 3), 2)];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+    }
+
+    #[test]
+    fn run_assert_uint256_le_equal() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\nb = (ids.b.high << 128) + ids.b.low\nassert a <= b, f'a = {a} is not less than or equal to b = {b}.'";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 4;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("a", -4), ("b", -2)];
+        vm.memory = memory![((1, 0), 7), ((1, 1), 3), ((1, 2), 7), ((1, 3), 3)];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+    }
+
+    #[test]
+    fn run_assert_uint256_le_greater() {
+        let hint_code = "a = (ids.a.high << 128) + ids.a.low\nb = (ids.b.high << 128) + ids.b.low\nassert a <= b, f'a = {a} is not less than or equal to b = {b}.'";
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 4;
+        //Create hint_data
+        let ids_data = non_continuous_ids_data![("a", -4), ("b", -2)];
+        //High limbs are equal, a.low > b.low
+        vm.memory = memory![((1, 0), 8), ((1, 1), 1), ((1, 2), 7), ((1, 3), 1)];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(HintError::NonLeUint256(
+                BigUint::from(8_u32) + BigUint::one().shl(128_u32),
+                BigUint::from(7_u32) + BigUint::one().shl(128_u32),
+            ))
+        );
+    }
+
+    #[test]
+    fn run_assert_uint256_mul_no_overflow_ok() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 4;
+        let ids_data = non_continuous_ids_data![("a", -4), ("b", -2), ("res", 0)];
+        //a = 2**127 + 5, b = 2**100 + 3
+        vm.memory = memory![
+            ((1, 0), ("170141183460469231731687303715884105733", 10)),
+            ((1, 1), 0),
+            ((1, 2), ("1267650600228229401496703205379", 10)),
+            ((1, 3), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::ASSERT_UINT256_MUL_NO_OVERFLOW),
+            Ok(())
+        );
+        check_memory![
+            &vm.memory,
+            ((1, 4), ("170141189798722232872834311199400132623", 10)),
+            ((1, 5), ("633825300114114700748351602689", 10))
+        ];
+    }
+
+    #[test]
+    fn run_assert_uint256_mul_no_overflow_overflows() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 4;
+        let ids_data = non_continuous_ids_data![("a", -4), ("b", -2), ("res", 0)];
+        //a = b = 2**128, so a * b = 2**256 doesn't fit in a uint256
+        vm.memory = memory![((1, 0), 0), ((1, 1), 1), ((1, 2), 0), ((1, 3), 1)];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::ASSERT_UINT256_MUL_NO_OVERFLOW),
+            Err(HintError::Uint256MulOverflow(BigUint::one().shl(256_u32)))
+        );
+        //The result wasn't written
+        assert_eq!(vm.memory.get(&MaybeRelocatable::from((1, 4))), Ok(None));
+    }
+
+    #[test]
+    fn run_uint256_bitwise_ops() {
+        //a.low = 2**128 - 1, a.high = 0b1100, b.low = 0xff00, b.high = 0b1010
+        for (hint_code, res_low, res_high) in [
+            (hint_code::UINT256_AND, 0xff00, 0b1000),
+            (hint_code::UINT256_OR, u128::MAX, 0b1110),
+            (hint_code::UINT256_XOR, u128::MAX - 0xff00, 0b0110),
+        ] {
+            let mut vm = vm_with_range_check!();
+            vm.run_context.fp = 4;
+            let ids_data = non_continuous_ids_data![("a", -4), ("b", -2), ("res", 0)];
+            vm.memory = memory![
+                ((1, 0), ("340282366920938463463374607431768211455", 10)),
+                ((1, 1), 0b1100),
+                ((1, 2), 0xff00),
+                ((1, 3), 0b1010)
+            ];
+            assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+            assert_eq!(
+                vm.get_integer(&(1, 4).into()).unwrap().as_ref(),
+                &Felt::new(res_low)
+            );
+            assert_eq!(
+                vm.get_integer(&(1, 5).into()).unwrap().as_ref(),
+                &Felt::new(res_high)
+            );
+        }
+    }
+
+    #[test]
+    fn run_uint256_and_limb_out_of_range() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 4;
+        let ids_data = non_continuous_ids_data![("a", -4), ("b", -2), ("res", 0)];
+        //b.high = 2**128
+        vm.memory = memory![
+            ((1, 0), 1),
+            ((1, 1), 1),
+            ((1, 2), 1),
+            ((1, 3), ("340282366920938463463374607431768211456", 10))
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT256_AND),
+            Err(HintError::Uint256LimbOutOfRange(
+                String::from("b.high"),
+                Felt::one().shl(128_u32)
+            ))
+        );
+        //The low limb of the result isn't written either
+        assert_eq!(vm.memory.get(&MaybeRelocatable::from((1, 4))), Ok(None));
+    }
 }
@@ -118,7 +118,7 @@ pub fn compute_addr_from_reference(
                     .to_usize()
                     .ok_or(VirtualMachineError::BigintToUsizeFail)?)
         }
-        OffsetValue::Value(value) => Ok(offset1 + *value),
+        OffsetValue::Value(value) => Ok(offset1.add_int(*value)?),
         _ => Err(HintError::NoRegisterInReference),
     }
 }
@@ -177,17 +177,17 @@ fn get_offset_value_reference(
         apply_ap_tracking_correction(&vm.get_ap(), var_ap_trackig, hint_ap_tracking)?
     };
 
-    if offset.is_negative() && base_addr.offset < offset.unsigned_abs() as usize {
-        return Err(HintError::FailedToGetIds);
-    }
+    let addr = base_addr
+        .add_int(*offset)
+        .map_err(|_| HintError::FailedToGetIds)?;
 
     if *deref {
         Ok(vm
-            .get_maybe(&(base_addr + *offset))
+            .get_maybe(&addr)
             .map_err(|_| HintError::FailedToGetIds)?
             .ok_or(HintError::FailedToGetIds)?)
     } else {
-        Ok((base_addr + *offset).into())
+        Ok(addr.into())
     }
 }
 
@@ -4,7 +4,10 @@ use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_def
 use cairo_vm::vm::errors::cairo_run_errors::CairoRunError;
 use cairo_vm::vm::errors::runner_errors::RunnerError;
 use cairo_vm::vm::errors::trace_errors::TraceError;
+use cairo_vm::vm::runners::cairo_runner::CairoArg;
 use clap::{Parser, ValueHint};
+use std::fs::File;
+use std::io::BufWriter;
 use std::path::PathBuf;
 
 #[cfg(feature = "with_mimalloc")]
@@ -32,6 +35,19 @@ struct Args {
     layout: String,
     #[structopt(long = "--proof_mode")]
     proof_mode: bool,
+    #[clap(long = "--args", value_parser = parse_args, conflicts_with = "proof_mode")]
+    args: Option<ProgramArgs>,
+    #[clap(long = "--hint_metrics_file", value_parser)]
+    hint_metrics_file: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug)]
+struct ProgramArgs(Vec<CairoArg>);
+
+fn parse_args(value: &str) -> Result<ProgramArgs, String> {
+    cairo_run::parse_cairo_args(value)
+        .map(ProgramArgs)
+        .map_err(|_| format!("{} is not a valid list of arguments", value))
 }
 
 fn validate_layout(value: &str) -> Result<(), String> {
@@ -47,15 +63,33 @@ fn main() -> Result<(), CairoRunError> {
     let args = Args::parse();
     let trace_enabled = args.trace_file.is_some();
     let mut hint_executor = BuiltinHintProcessor::new_empty();
-    let cairo_runner = match cairo_run::cairo_run(
-        &args.filename,
-        &args.entrypoint,
-        trace_enabled,
-        args.print_output,
-        &args.layout,
-        args.proof_mode,
-        &mut hint_executor,
-    ) {
+    if let Some(ref metrics_path) = args.hint_metrics_file {
+        let metrics_file = File::create(metrics_path)
+            .map_err(|_| CairoRunError::Runner(RunnerError::WriteFail))?;
+        hint_executor.set_metrics_sink(Box::new(BufWriter::new(metrics_file)));
+    }
+    let result = match args.args {
+        Some(ProgramArgs(program_args)) => cairo_run::cairo_run_with_args(
+            &args.filename,
+            &args.entrypoint,
+            &program_args,
+            trace_enabled,
+            args.print_output,
+            &args.layout,
+            &mut hint_executor,
+        )
+        .map(|(cairo_runner, _vm)| cairo_runner),
+        None => cairo_run::cairo_run(
+            &args.filename,
+            &args.entrypoint,
+            trace_enabled,
+            args.print_output,
+            &args.layout,
+            args.proof_mode,
+            &mut hint_executor,
+        ),
+    };
+    let cairo_runner = match result {
         Ok(runner) => runner,
         Err(error) => {
             println!("{}", error);
@@ -105,6 +139,12 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_args() {
+        assert!(parse_args("1,[2,3],2").is_ok());
+        assert!(parse_args("1,[2,3").is_err());
+    }
+
     #[test]
     fn test_invalid_layout() {
         let invalid_layout = "invalid layout name";
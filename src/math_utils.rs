@@ -1,9 +1,12 @@
-use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::{
+    errors::{hint_errors::HintError, runner_errors::RunnerError, vm_errors::VirtualMachineError},
+    runners::builtin_runner::EcOpBuiltinRunner,
+};
 use felt::Felt;
 use num_bigint::{BigInt, BigUint};
 use num_integer::Integer;
 use num_traits::{One, Signed, Zero};
-use std::ops::Shr;
+use std::{cmp::Ordering, ops::Shr};
 
 ///Returns the integer square root of the nonnegative integer n.
 ///This is the floor of the exact square root of n.
@@ -97,6 +100,11 @@ pub fn safe_div_usize(x: usize, y: usize) -> Result<usize, VirtualMachineError>
     Ok(q)
 }
 
+///Compares the signed interpretations of two felts, where values above PRIME / 2 are negative.
+pub fn cmp_signed(a: &Felt, b: &Felt) -> Ordering {
+    a.to_bigint().cmp(&b.to_bigint())
+}
+
 ///Returns x, y, g such that g = x*a + y*b = gcd(a, b).
 fn igcdex(num_a: &BigInt, num_b: &BigInt) -> (BigInt, BigInt, BigInt) {
     match (num_a, num_b) {
@@ -129,6 +137,57 @@ pub fn div_mod(n: &BigInt, m: &BigInt, p: &BigInt) -> BigInt {
     (n * a).mod_floor(p)
 }
 
+///Returns true if n is a quadratic residue modulo the prime p (Euler's criterion).
+pub fn is_quad_residue(n: &BigInt, p: &BigInt) -> bool {
+    let n = n.mod_floor(p);
+    n.is_zero() || n.modpow(&((p - 1_u32) >> 1_u32), p).is_one()
+}
+
+///Finds the minimum nonnegative integer m such that (m * m) % p == n, using the
+///Tonelli-Shanks algorithm. Returns None if n is not a quadratic residue modulo the prime p.
+pub fn sqrt_mod_prime(n: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let n = n.mod_floor(p);
+    if n.is_zero() {
+        return Some(BigInt::zero());
+    }
+    if !is_quad_residue(&n, p) {
+        return None;
+    }
+    if p == &BigInt::from(2_u32) {
+        return Some(n);
+    }
+    // Write p - 1 as q * 2**s, with q odd
+    let p_minus_one: BigInt = p - 1_u32;
+    let s = p_minus_one.trailing_zeros()?;
+    let q = &p_minus_one >> s;
+    // Find a quadratic non-residue z
+    let mut z = BigInt::from(2_u32);
+    while is_quad_residue(&z, p) {
+        z += 1_u32;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = n.modpow(&q, p);
+    let mut r = n.modpow(&((&q + 1_u32) >> 1_u32), p);
+    while !t.is_one() {
+        // Find the least i such that t**(2**i) == 1
+        let mut i = 0_u64;
+        let mut t_pow = t.clone();
+        while !t_pow.is_one() {
+            t_pow = (&t_pow * &t_pow).mod_floor(p);
+            i += 1;
+        }
+        let b = c.modpow(&(BigInt::one() << (m - i - 1)), p);
+        m = i;
+        c = (&b * &b).mod_floor(p);
+        t = (t * &c).mod_floor(p);
+        r = (r * b).mod_floor(p);
+    }
+    let other_root = p - &r;
+    Some(r.min(other_root))
+}
+
 pub fn ec_add(
     point_a: (BigInt, BigInt),
     point_b: (BigInt, BigInt),
@@ -175,10 +234,65 @@ pub fn ec_double_slope(point: &(BigInt, BigInt), alpha: &BigInt, prime: &BigInt)
     )
 }
 
+/// Computes partial_sum + scalar * point by double-and-add over the `height` lowest bits of the
+/// scalar, on the curve y^2 = x^3 + alpha * x + beta (mod prime). A partial sum of None stands
+/// for the point at infinity, which can't be represented in affine form.
+/// Like the ec_op builtin AIR, fails whenever a step reaches a partial sum and a doubled point
+/// with the same x coordinate, even if they wouldn't be added together. The error describes the
+/// computation left at that step.
+pub(crate) fn ec_double_and_add(
+    mut partial_sum: Option<(BigInt, BigInt)>,
+    mut doubled_point: (BigInt, BigInt),
+    scalar: &BigInt,
+    alpha: &BigInt,
+    prime: &BigInt,
+    height: u32,
+) -> Result<Option<(BigInt, BigInt)>, RunnerError> {
+    let mut scalar = scalar.clone();
+    for _ in 0..height {
+        partial_sum = match partial_sum {
+            Some(partial_sum) if partial_sum.0 == doubled_point.0 => {
+                return Err(RunnerError::EcOpSameXCoordinate(
+                    EcOpBuiltinRunner::format_ec_op_error(partial_sum, scalar, doubled_point),
+                ))
+            }
+            Some(partial_sum) if scalar.is_odd() => {
+                Some(ec_add(partial_sum, doubled_point.clone(), prime))
+            }
+            None if scalar.is_odd() => Some(doubled_point.clone()),
+            partial_sum => partial_sum,
+        };
+        doubled_point = ec_double(doubled_point, alpha, prime);
+        scalar >>= 1_u32;
+    }
+    Ok(partial_sum)
+}
+
+/// Computes scalar * point on the STARK curve (alpha = 1) using double-and-add.
+/// Fails if the result is the point at infinity, which can't be represented in affine form
+/// (e.g. when the scalar is zero or a multiple of the curve order). Being a point of prime order,
+/// the partial sum only meets a doubled point with the same x coordinate on its way there.
+pub fn ec_mul(point: (Felt, Felt), scalar: &Felt) -> Result<(Felt, Felt), HintError> {
+    let scalar: BigInt = scalar.to_biguint().into();
+    ec_double_and_add(
+        None,
+        (point.0.to_biguint().into(), point.1.to_biguint().into()),
+        &scalar,
+        &BigInt::one(),
+        &Felt::prime().into(),
+        scalar.bits() as u32,
+    )
+    .ok()
+    .flatten()
+    .map(|(x, y)| (Felt::new(x), Felt::new(y)))
+    .ok_or(HintError::EcMulPointAtInfinity)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::utils::test_utils::*;
+    use felt::felt_str;
     use num_traits::Num;
 
     #[test]
@@ -241,6 +355,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sqrt_mod_prime_secp_p() {
+        // secp256k1 prime, p % 4 == 3
+        let p = (BigInt::one() << 256_u32) - (BigInt::one() << 32_u32) - 977_u32;
+        let n = bigint_str!(
+            "60300556597753154781239923047219078515410877540607532238537983597388018023497"
+        );
+        assert_eq!(
+            sqrt_mod_prime(&n, &p),
+            Some(bigint_str!(
+                "55066263022277343669578718895168534326250603453777594175500187360389116729240"
+            ))
+        );
+    }
+
+    #[test]
+    fn sqrt_mod_prime_felt_prime() {
+        // The felt prime has p - 1 divisible by 2**192, which exercises the Tonelli-Shanks loop
+        let p = BigInt::from_str_radix(&felt::PRIME_STR[2..], 16).expect("Couldn't parse prime");
+        let n = bigint_str!("1524157875323883675019051998750190521");
+        assert_eq!(
+            sqrt_mod_prime(&n, &p),
+            Some(bigint_str!("1234567890123456789"))
+        );
+        assert_eq!(sqrt_mod_prime(&BigInt::zero(), &p), Some(BigInt::zero()));
+    }
+
+    #[test]
+    fn sqrt_mod_prime_non_residue() {
+        let p = BigInt::from_str_radix(&felt::PRIME_STR[2..], 16).expect("Couldn't parse prime");
+        assert!(!is_quad_residue(&bigint!(3), &p));
+        assert_eq!(sqrt_mod_prime(&bigint!(3), &p), None);
+    }
+
     #[test]
     fn compute_safe_div() {
         let x = Felt::new(26);
@@ -570,4 +718,89 @@ mod tests {
         let n = BigUint::zero();
         assert_eq!(isqrt(&n), Ok(BigUint::zero()));
     }
+
+    #[test]
+    fn cmp_signed_negative_operands() {
+        let minus_one = Felt::new(-1);
+        assert_eq!(cmp_signed(&minus_one, &Felt::one()), Ordering::Less);
+        //The unsigned order is the opposite, as -1 is PRIME - 1
+        assert_eq!(minus_one.cmp(&Felt::one()), Ordering::Greater);
+        assert_eq!(cmp_signed(&Felt::new(-5), &Felt::new(-3)), Ordering::Less);
+        assert_eq!(cmp_signed(&Felt::new(-3), &Felt::new(-3)), Ordering::Equal);
+    }
+
+    fn stark_generator() -> (Felt, Felt) {
+        (
+            Felt::from_str_radix(
+                "1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
+                16,
+            )
+            .unwrap(),
+            Felt::from_str_radix(
+                "5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f",
+                16,
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn ec_mul_generator_by_known_scalar() {
+        assert_eq!(
+            ec_mul(stark_generator(), &Felt::new(12345)),
+            Ok((
+                felt_str!(
+                    "1628448741648245036800002906075225705100596136133912895015035902954123957052"
+                ),
+                felt_str!(
+                    "3413538614367785020797936229826229286217265324567603480673611855761022730212"
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn ec_mul_small_scalars() {
+        let generator = stark_generator();
+        assert_eq!(
+            ec_mul(generator.clone(), &Felt::one()),
+            Ok(generator.clone())
+        );
+        let prime: BigInt = Felt::prime().into();
+        let (x, y) = ec_double(
+            (
+                generator.0.to_biguint().into(),
+                generator.1.to_biguint().into(),
+            ),
+            &BigInt::one(),
+            &prime,
+        );
+        assert_eq!(
+            ec_mul(generator, &Felt::new(2)),
+            Ok((Felt::new(x), Felt::new(y)))
+        );
+    }
+
+    #[test]
+    fn ec_mul_point_at_infinity() {
+        let ec_order = Felt::from_str_radix(
+            "800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f",
+            16,
+        )
+        .unwrap();
+        assert_eq!(
+            ec_mul(stark_generator(), &Felt::zero()),
+            Err(HintError::EcMulPointAtInfinity)
+        );
+        assert_eq!(
+            ec_mul(stark_generator(), &ec_order),
+            Err(HintError::EcMulPointAtInfinity)
+        );
+        //(order - 1) * G = -G
+        let (x, y) = stark_generator();
+        assert_eq!(
+            ec_mul((x.clone(), y.clone()), &(ec_order - 1_usize)),
+            Ok((x, -y))
+        );
+    }
 }
@@ -6,6 +6,7 @@ use crate::{
     },
 };
 use felt::{Felt, PRIME_STR};
+use num_bigint::BigUint;
 use num_traits::Num;
 use serde::{de, de::MapAccess, de::SeqAccess, Deserialize, Deserializer, Serialize};
 use serde_json::Number;
@@ -302,13 +303,23 @@ pub fn deserialize_program_json(reader: impl Read) -> Result<ProgramJson, Progra
     Ok(program_json)
 }
 
+// Programs compiled for a different field can't be executed by this VM. The prime is compared
+// by value, so differences in the hex formatting (leading zeros, letter case) are accepted.
+fn is_field_prime(prime: &str) -> bool {
+    prime == PRIME_STR
+        || prime
+            .strip_prefix("0x")
+            .and_then(|hex| BigUint::from_str_radix(hex, 16).ok())
+            .map_or(false, |prime| prime == Felt::prime())
+}
+
 pub fn deserialize_program(
     reader: impl Read,
     entrypoint: Option<&str>,
 ) -> Result<Program, ProgramError> {
     let program_json: ProgramJson = deserialize_program_json(reader)?;
 
-    if PRIME_STR != program_json.prime {
+    if !is_field_prime(&program_json.prime) {
         return Err(ProgramError::PrimeDiffers(program_json.prime));
     }
 
@@ -710,6 +721,41 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn deserialize_program_with_mismatched_prime_gives_error() {
+        let program_json = r#"{
+            "prime": "0x11",
+            "builtins": [],
+            "data": [],
+            "identifiers": {},
+            "hints": {},
+            "reference_manager": {"references": []},
+            "attributes": [],
+            "debug_info": null
+        }"#;
+
+        let deserialization_result = deserialize_program(program_json.as_bytes(), None);
+        assert!(matches!(
+            deserialization_result,
+            Err(ProgramError::PrimeDiffers(prime)) if prime == "0x11"
+        ));
+    }
+
+    #[test]
+    fn is_field_prime_ignores_hex_formatting() {
+        assert!(is_field_prime(PRIME_STR));
+        assert!(is_field_prime(
+            "0x0800000000000011000000000000000000000000000000000000000000000001"
+        ));
+        assert!(!is_field_prime(
+            "0X800000000000011000000000000000000000000000000000000000000000001"
+        ));
+        assert!(!is_field_prime("0x11"));
+        assert!(!is_field_prime(
+            "3618502788666131213697322783095070105623107215331596699973092056135872020481"
+        ));
+    }
+
     #[test]
     fn deserialize_program_test() {
         let even_length_file =
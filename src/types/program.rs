@@ -3,9 +3,14 @@ use crate::{
         deserialize_program, Attribute, HintParams, Identifier, InstructionLocation,
         ReferenceManager,
     },
-    types::{errors::program_errors::ProgramError, relocatable::MaybeRelocatable},
+    types::{
+        errors::program_errors::ProgramError, instruction::Instruction,
+        relocatable::MaybeRelocatable,
+    },
+    vm::{decoding::decoder::decode_instruction, errors::vm_errors::VirtualMachineError},
 };
 use felt::{Felt, PRIME_STR};
+use num_traits::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use std::{
     fs::File,
@@ -85,6 +90,62 @@ impl Program {
     ) -> Result<Program, ProgramError> {
         deserialize_program(reader, entrypoint)
     }
+
+    /// Returns the program's bytecode cells (instructions and embedded data).
+    pub fn data(&self) -> &[MaybeRelocatable] {
+        &self.data
+    }
+
+    /// Returns true if there is at least one hint attached to the given pc.
+    pub fn has_hint_at(&self, pc: usize) -> bool {
+        self.hints.get(&pc).map_or(false, |hints| !hints.is_empty())
+    }
+
+    /// Returns the hints attached to the given pc, in execution order.
+    /// Returns an empty slice if there are none.
+    pub fn hints_at(&self, pc: usize) -> &[HintParams] {
+        self.hints.get(&pc).map_or(&[], |hints| hints.as_slice())
+    }
+
+    /// Returns every hint of the program along with its pc, sorted by pc.
+    /// Hints sharing a pc are yielded in execution order.
+    pub fn hints_in_order(&self) -> impl Iterator<Item = (usize, &HintParams)> {
+        let mut pcs: Vec<usize> = self.hints.keys().copied().collect();
+        pcs.sort_unstable();
+        pcs.into_iter()
+            .flat_map(move |pc| self.hints_at(pc).iter().map(move |hint| (pc, hint)))
+    }
+
+    /// Decodes the bytecode from start to end, returning each instruction along with its pc.
+    /// Immediates are consumed with the instruction that uses them. Cells that can't hold an
+    /// instruction encoding (relocatable values or integers beyond 63 bits) are skipped as
+    /// embedded data, while encodings with invalid flags are reported as errors.
+    pub fn decode_all(&self) -> Result<Vec<(usize, Instruction)>, VirtualMachineError> {
+        let mut instructions = Vec::new();
+        let mut pc = 0;
+        while pc < self.data.len() {
+            let encoding = match &self.data[pc] {
+                MaybeRelocatable::Int(value) => value.to_i64(),
+                MaybeRelocatable::RelocatableValue(_) => None,
+            };
+            let encoding = match encoding {
+                Some(encoding) => encoding,
+                None => {
+                    pc += 1;
+                    continue;
+                }
+            };
+            let imm = match self.data.get(pc + 1) {
+                Some(MaybeRelocatable::Int(imm)) => Some(imm),
+                _ => None,
+            };
+            let instruction = decode_instruction(encoding, imm)?;
+            let size = instruction.size();
+            instructions.push((pc, instruction));
+            pc += size;
+        }
+        Ok(instructions)
+    }
 }
 
 impl Default for Program {
@@ -111,6 +172,7 @@ impl Default for Program {
 mod tests {
     use super::*;
     use crate::serde::deserialize_program::{ApTracking, FlowTrackingData};
+    use crate::types::instruction::Opcode;
     use crate::utils::test_utils::mayberelocatable;
     use felt::felt_str;
     use num_traits::Zero;
@@ -485,6 +547,114 @@ mod tests {
         assert_eq!(program.constants, constants);
     }
 
+    #[test]
+    fn get_program_data() {
+        let program = Program::from_file(
+            Path::new("cairo_programs/manually_compiled/valid_program_a.json"),
+            Some("main"),
+        )
+        .expect("Failed to deserialize program");
+
+        // The program consists of two `[ap] = imm; ap++` instructions, an addition and a `ret`,
+        // each immediate taking its own cell.
+        assert_eq!(program.data().len(), 6);
+        assert_eq!(program.data(), program.data.as_slice());
+    }
+
+    #[test]
+    fn has_hint_at_and_hints_at() {
+        let program = Program::from_file(
+            Path::new("cairo_programs/manually_compiled/valid_program_a.json"),
+            Some("main"),
+        )
+        .expect("Failed to deserialize program");
+
+        assert!(program.has_hint_at(0));
+        assert_eq!(program.hints_at(0).len(), 1);
+        assert_eq!(program.hints_at(0)[0].code, "memory[ap] = segments.add()");
+
+        assert!(!program.has_hint_at(1));
+        assert!(program.hints_at(1).is_empty());
+    }
+
+    #[test]
+    fn hints_in_order_sorted_by_pc() {
+        let hint = |code: &str| HintParams {
+            code: code.to_string(),
+            accessible_scopes: Vec::new(),
+            flow_tracking_data: FlowTrackingData {
+                ap_tracking: ApTracking::new(),
+                reference_ids: HashMap::new(),
+            },
+        };
+        let program = Program {
+            hints: HashMap::from([
+                (7, vec![hint("c")]),
+                (2, vec![hint("a"), hint("b")]),
+                (4, Vec::new()),
+            ]),
+            ..Default::default()
+        };
+
+        let hints: Vec<(usize, &str)> = program
+            .hints_in_order()
+            .map(|(pc, hint)| (pc, hint.code.as_str()))
+            .collect();
+        assert_eq!(hints, vec![(2, "a"), (2, "b"), (7, "c")]);
+    }
+
+    #[test]
+    fn decode_all_skips_immediates() {
+        //[ap] = 1000, ap++; [ap] = 2000, ap++; [ap] = [ap - 2] + [ap - 1], ap++; ret
+        let program = Program {
+            data: vec![
+                mayberelocatable!(5189976364521848832),
+                mayberelocatable!(1000),
+                mayberelocatable!(5189976364521848832),
+                mayberelocatable!(2000),
+                mayberelocatable!(5201798304953696256),
+                mayberelocatable!(2345108766317314046),
+            ],
+            ..Default::default()
+        };
+        let instructions = program.decode_all().unwrap();
+        assert_eq!(instructions.len(), 4);
+        let pcs: Vec<usize> = instructions.iter().map(|(pc, _)| *pc).collect();
+        assert_eq!(pcs, vec![0, 2, 4, 5]);
+        assert_eq!(instructions[1].1.imm, Some(Felt::new(2000)));
+        assert_eq!(instructions[3].1.opcode, Opcode::Ret);
+    }
+
+    #[test]
+    fn decode_all_skips_data_cells() {
+        //ret followed by a pointer and a felt that can't be instruction encodings
+        let program = Program {
+            data: vec![
+                mayberelocatable!(2345108766317314046),
+                mayberelocatable!(1, 0),
+                mayberelocatable!(-1),
+                mayberelocatable!(2345108766317314046),
+            ],
+            ..Default::default()
+        };
+        let pcs: Vec<usize> = program
+            .decode_all()
+            .unwrap()
+            .into_iter()
+            .map(|(pc, _)| pc)
+            .collect();
+        assert_eq!(pcs, vec![0, 3]);
+    }
+
+    #[test]
+    fn decode_all_missing_immediate() {
+        let program = Program {
+            data: vec![mayberelocatable!(5189976364521848832)],
+            ..Default::default()
+        };
+        assert_eq!(program.decode_all(), Err(VirtualMachineError::NoImm));
+    }
+
     #[test]
     fn default_program() {
         let program = Program {
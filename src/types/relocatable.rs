@@ -159,7 +159,7 @@ impl Relocatable {
     }
 
     ///Adds a Felt to self
-    pub fn add_int(&self, other: &Felt) -> Result<Relocatable, VirtualMachineError> {
+    pub fn add_felt(&self, other: &Felt) -> Result<Relocatable, VirtualMachineError> {
         let big_offset = other + self.offset;
         let new_offset = big_offset
             .to_usize()
@@ -170,6 +170,21 @@ impl Relocatable {
         })
     }
 
+    /// Adds a signed offset to self, failing instead of wrapping if the resulting offset
+    /// doesn't fit in a usize or would be negative
+    pub fn add_int(&self, other: i32) -> Result<Relocatable, VirtualMachineError> {
+        let new_offset = if other >= 0 {
+            self.offset.checked_add(other as usize)
+        } else {
+            self.offset.checked_sub(other.unsigned_abs() as usize)
+        }
+        .ok_or(VirtualMachineError::RelocatableAddOffsetOutOfRange(
+            self.offset,
+            other,
+        ))?;
+        Ok(relocatable!(self.segment_index, new_offset))
+    }
+
     /// Adds a MaybeRelocatable to self
     /// Cant add two relocatable values
     pub fn add_maybe(&self, other: &MaybeRelocatable) -> Result<Relocatable, VirtualMachineError> {
@@ -200,6 +215,11 @@ impl Relocatable {
         let result = self.offset - other.offset;
         Ok(result)
     }
+
+    /// Returns true if self points into the (non-temporary) segment with the given index
+    pub fn is_in_segment(&self, index: usize) -> bool {
+        usize::try_from(self.segment_index) == Ok(index)
+    }
 }
 
 impl MaybeRelocatable {
@@ -271,9 +291,9 @@ impl MaybeRelocatable {
                 MaybeRelocatable::RelocatableValue(rel_b),
             ) => {
                 if rel_a.segment_index == rel_b.segment_index {
-                    return Ok(MaybeRelocatable::from(Felt::new(
-                        rel_a.offset - rel_b.offset,
-                    )));
+                    return Ok(MaybeRelocatable::from(
+                        Felt::new(rel_a.offset) - rel_b.offset,
+                    ));
                 }
                 Err(VirtualMachineError::DiffIndexSub)
             }
@@ -322,6 +342,14 @@ impl MaybeRelocatable {
             MaybeRelocatable::Int(_) => Err(VirtualMachineError::ExpectedRelocatable(self.clone())),
         }
     }
+
+    /// Returns true if self is a relocatable value pointing into the segment with the given index
+    pub fn is_relocatable_in(&self, index: usize) -> bool {
+        match self {
+            MaybeRelocatable::RelocatableValue(rel) => rel.is_in_segment(index),
+            MaybeRelocatable::Int(_) => false,
+        }
+    }
 }
 
 impl<'a> Add<usize> for &'a Relocatable {
@@ -414,6 +442,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add_int_to_relocatable() {
+        let addr = relocatable!(1, 5);
+        assert_eq!(addr.add_int(3), Ok(relocatable!(1, 8)));
+        assert_eq!(addr.add_int(-5), Ok(relocatable!(1, 0)));
+    }
+
+    #[test]
+    fn add_int_to_relocatable_overflow() {
+        let addr = relocatable!(1, usize::MAX);
+        assert_eq!(
+            addr.add_int(1),
+            Err(VirtualMachineError::RelocatableAddOffsetOutOfRange(
+                usize::MAX,
+                1
+            ))
+        );
+    }
+
+    #[test]
+    fn add_int_to_relocatable_negative_underflow() {
+        let addr = relocatable!(1, 2);
+        assert_eq!(
+            addr.add_int(-3),
+            Err(VirtualMachineError::RelocatableAddOffsetOutOfRange(2, -3))
+        );
+    }
+
     #[test]
     fn add_usize_to_relocatable() {
         let addr = MaybeRelocatable::RelocatableValue(relocatable!(7, 65));
@@ -541,6 +597,16 @@ mod tests {
         assert_eq!(Ok(MaybeRelocatable::from(Felt::new(10))), sub_addr);
     }
 
+    #[test]
+    fn sub_relocatable_from_relocatable_negative_difference() {
+        let addr_a = &MaybeRelocatable::from((7, 7));
+        let addr_b = &MaybeRelocatable::from((7, 17));
+        assert_eq!(
+            addr_a.sub(addr_b),
+            Ok(MaybeRelocatable::from(Felt::new(-10)))
+        );
+    }
+
     #[test]
     fn sub_relocatable_from_relocatable_diff_offset() {
         let addr_a = &MaybeRelocatable::from((7, 17));
@@ -639,24 +705,24 @@ mod tests {
     }
 
     #[test]
-    fn relocatable_add_int() {
+    fn relocatable_add_felt() {
         assert_eq!(
             Ok(relocatable!(1, 6)),
-            relocatable!(1, 2).add_int(&Felt::new(4))
+            relocatable!(1, 2).add_felt(&Felt::new(4))
         );
         assert_eq!(
             Ok(relocatable!(3, 2)),
-            relocatable!(3, 2).add_int(&Felt::zero())
+            relocatable!(3, 2).add_felt(&Felt::zero())
         );
     }
 
     #[test]
-    fn relocatable_add_int_mod_offset_exceeded_error() {
+    fn relocatable_add_felt_offset_exceeded_error() {
         assert_eq!(
             Err(VirtualMachineError::OffsetExceeded(
                 Felt::new(usize::MAX) + 1_usize
             )),
-            relocatable!(0, 0).add_int(&(Felt::new(usize::MAX) + 1_usize))
+            relocatable!(0, 0).add_felt(&(Felt::new(usize::MAX) + 1_usize))
         );
     }
 
@@ -785,4 +851,26 @@ mod tests {
             String::from("6")
         )
     }
+
+    #[test]
+    fn relocatable_is_in_segment() {
+        let rel = relocatable!(2, 5);
+        assert!(rel.is_in_segment(2));
+        assert!(!rel.is_in_segment(0));
+        assert!(!rel.is_in_segment(3));
+    }
+
+    #[test]
+    fn temporary_relocatable_is_not_in_any_segment() {
+        let rel = relocatable!(-1, 0);
+        assert!(!rel.is_in_segment(1));
+        assert!(!rel.is_in_segment(usize::MAX));
+    }
+
+    #[test]
+    fn maybe_relocatable_is_relocatable_in() {
+        assert!(mayberelocatable!(1, 3).is_relocatable_in(1));
+        assert!(!mayberelocatable!(1, 3).is_relocatable_in(0));
+        assert!(!mayberelocatable!(1).is_relocatable_in(1));
+    }
 }
@@ -7,7 +7,6 @@ use crate::{
         memory_errors::MemoryError::AddressNotRelocatable, vm_errors::VirtualMachineError,
     },
 };
-use num_traits::ToPrimitive;
 
 pub struct RunContext {
     pub(crate) pc: Relocatable,
@@ -34,13 +33,8 @@ impl RunContext {
             Register::AP => self.get_ap(),
             Register::FP => self.get_fp(),
         };
-        let new_offset = instruction.off0 + base_addr.offset as isize;
-        Ok(Relocatable::from((
-            base_addr.segment_index,
-            new_offset
-                .to_usize()
-                .ok_or(VirtualMachineError::BigintToUsizeFail)?,
-        )))
+        //Decoded offsets are 16-bit values, so they always fit in an i32
+        base_addr.add_int(instruction.off0 as i32)
     }
 
     pub fn compute_op0_addr(
@@ -51,13 +45,7 @@ impl RunContext {
             Register::AP => self.get_ap(),
             Register::FP => self.get_fp(),
         };
-        let new_offset = instruction.off1 + base_addr.offset as isize;
-        Ok(Relocatable::from((
-            base_addr.segment_index,
-            new_offset
-                .to_usize()
-                .ok_or(VirtualMachineError::BigintToUsizeFail)?,
-        )))
+        base_addr.add_int(instruction.off1 as i32)
     }
 
     pub fn compute_op1_addr(
@@ -78,13 +66,7 @@ impl RunContext {
                 None => return Err(VirtualMachineError::UnknownOp0),
             },
         };
-        let new_offset = instruction.off2 + base_addr.offset as isize;
-        Ok(Relocatable::from((
-            base_addr.segment_index,
-            new_offset
-                .to_usize()
-                .ok_or(VirtualMachineError::BigintToUsizeFail)?,
-        )))
+        base_addr.add_int(instruction.off2 as i32)
     }
 
     #[doc(hidden)]
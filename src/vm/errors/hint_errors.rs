@@ -1,4 +1,4 @@
-use felt::Felt;
+use felt::{Felt, MathError};
 use num_bigint::{BigInt, BigUint};
 use thiserror::Error;
 
@@ -40,6 +40,8 @@ pub enum HintError {
     NoValueForKeyFindElement(Felt),
     #[error("Assertion failed, a = {0} % PRIME is not less than b = {1} % PRIME")]
     AssertLtFelt(Felt, Felt),
+    #[error("Assertion failed, a = {0} is not less than b = {1}")]
+    AssertLtFeltSigned(BigInt, BigInt),
     #[error("find_element() can only be used with n_elms <= {0}.\nGot: n_elms = {1}")]
     FindElemMaxSize(Felt, Felt),
     #[error(
@@ -108,24 +110,42 @@ pub enum HintError {
     SecpSplitOutOfRange(BigUint),
     #[error("verify_zero: Invalid input {0}")]
     SecpVerifyZero(BigInt),
+    #[error("verify_nonzero: {0} is zero modulo SECP_P")]
+    SecpVerifyNonZero(BigInt),
+    #[error("Attempted to divide by {0}, which is zero modulo {1}")]
+    PackedDivisionByZero(BigInt, BigInt),
+    #[error("sqrt_mod: {0} is not a quadratic residue modulo {1}")]
+    QuadraticNonResidue(BigInt, BigInt),
     #[error("unsafe_keccak() can only be used with length<={0}. Got: length={1}")]
     KeccakMaxSize(Felt, Felt),
     #[error("Invalid word size: {0}")]
     InvalidWordSize(Felt),
+    #[error("blake2s output length must be in the range (0, 32], got: {0}")]
+    InvalidBlake2sOutputLength(Felt),
     #[error("Invalid input length, Got: length={0}")]
     InvalidKeccakInputLength(Felt),
+    #[error("KECCAK_FULL_RATE_IN_BYTES must be in the range (0, 200], got: {0}")]
+    InvalidKeccakRate(Felt),
+    #[error("ids.n_bytes must be a byte count that fits in a usize, got: {0}")]
+    InvalidKeccakByteCount(Felt),
     #[error(transparent)]
     FromScopeError(#[from] ExecScopeError),
+    #[error(transparent)]
+    Math(#[from] MathError),
     #[error("assert_not_equal failed: {0} =  {1}")]
     AssertNotEqualFail(MaybeRelocatable, MaybeRelocatable),
     #[error("split_int(): value is out of range")]
     SplitIntNotZero,
     #[error("split_int(): Limb {0} is out of range.")]
     SplitIntLimbOutOfRange(Felt),
+    #[error("split_int(): Expected a positive limb count, got: {0}")]
+    InvalidSplitIntLimbCount(Felt),
     #[error("Expected size to be in the range from [0, 100), got: {0}")]
     InvalidKeccakStateSizeFelts(Felt),
     #[error("Expected size to be in range from [0, 10), got: {0}")]
     InvalidBlockSize(Felt),
+    #[error("Expected at most {1} keccak blocks, got {0}")]
+    KeccakBlockCountExceeded(Felt, Felt),
     #[error("Couldn't convert BigInt to u32")]
     BigintToU32Fail,
     #[error("BigInt to BigUint failed, BigInt is negative")]
@@ -142,6 +162,46 @@ pub enum HintError {
     ValueOutsideValidRange(Felt),
     #[error("Assertion failed, {0}, is not less or equal to {1}")]
     NonLeFelt(Felt, Felt),
+    #[error("assert_uint256_le failed: a = {0} is not less than or equal to b = {1}")]
+    NonLeUint256(BigUint, BigUint),
+    #[error("assert_250_bit: the {0} part {1} is out of range")]
+    Assert250BitPartOutOfRange(String, Felt),
+    #[error("join_split_output: the {0} part {1} is not a 128-bit value")]
+    JoinOutputPartOutOfRange(String, Felt),
+    #[error("Output page id {0} is too big")]
+    InvalidOutputPageId(Felt),
+    #[error("split_felt: the program constants don't satisfy {0}")]
+    SplitFeltConstantsCheckFailed(String),
+    #[error("Can't subtract {1} from {0}, both must be integers or pointers")]
+    InvalidSubOperands(MaybeRelocatable, MaybeRelocatable),
+    #[error("unsigned_div_rem: the quotient {0} exceeds the range check bound {1}")]
+    QuotientOutOfRange(Felt, Felt),
+    #[error("Array pointers {0} and {1} belong to different segments")]
+    ArrayPointersInDifferentSegments(Relocatable, Relocatable),
+    #[error("Array element size must be a positive integer, got {0}")]
+    InvalidArrayElmSize(Felt),
+    #[error("Array length {0} is not a multiple of the element size {1}")]
+    ArrayLengthNotMultipleOfElmSize(usize, usize),
+    #[error("Selector {0} is out of the jump table range [0, {1})")]
+    JumpTableSelectorOutOfRange(Felt, Felt),
+    #[error("Attempted to divide a uint256 by zero")]
+    Uint256DivisionByZero,
+    #[error("a * b = {0} overflows a uint256")]
+    Uint256MulOverflow(BigUint),
+    #[error("uint256 limb ids.{0} = {1} doesn't fit in 128 bits")]
+    Uint256LimbOutOfRange(String, Felt),
+    #[error("Scalar multiplication on the STARK curve reached the point at infinity")]
+    EcMulPointAtInfinity,
+    #[error("recover_y: there is no point on the STARK curve with x = {0}")]
+    RecoverYPointNotOnCurve(Felt),
+    #[error("Reference {0} has no type information")]
+    MissingReferenceType(String),
+    #[error("Unknown struct: {0}")]
+    UnknownStruct(String),
+    #[error("Struct {0} has no member {1}")]
+    UnknownStructMember(String, String),
     #[error("Unknown Hint: {0}")]
     UnknownHint(String),
+    #[error("Failed to write a hint metrics event: {0}")]
+    MetricsSinkWriteFailed(String),
 }
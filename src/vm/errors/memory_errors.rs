@@ -1,3 +1,4 @@
+use felt::Felt;
 use thiserror::Error;
 
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
@@ -38,8 +39,8 @@ pub enum MemoryError {
     SegmentNotFinalized(usize),
     #[error("Invalid memory value at address {0:?}: {1:?}")]
     InvalidMemoryValue(Relocatable, MaybeRelocatable),
-    #[error("Found a memory gap when calling get_continuous_range")]
-    GetRangeMemoryGap,
+    #[error("Found a memory gap at {0} when calling get_continuous_range")]
+    GetRangeMemoryGap(MaybeRelocatable),
     #[error("Error calculating builtin memory units")]
     ErrorCalculatingMemoryUnits,
     #[error("Number of steps is insufficient in the builtin.")]
@@ -62,4 +63,10 @@ pub enum MemoryError {
     ErrorVerifyingSignature,
     #[error("Couldn't obtain a mutable accessed offset")]
     CantGetMutAccessedOffset,
+    #[error("Couldn't read memory dump: {0}")]
+    MemoryDumpRead(String),
+    #[error("Memory dump of {0} bytes isn't made of 40-byte (address, value) pairs")]
+    MalformedMemoryDump(usize),
+    #[error("Memory doesn't match the dump at relocated address {0}: expected {1:?}, found {2:?}")]
+    MemoryDumpMismatch(usize, Option<Felt>, Option<Felt>),
 }
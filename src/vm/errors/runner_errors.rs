@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use super::memory_errors::MemoryError;
-use crate::types::relocatable::MaybeRelocatable;
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use felt::Felt;
 use thiserror::Error;
 
@@ -51,8 +51,16 @@ pub enum RunnerError {
     IntegerBiggerThanPowerOfTwo(MaybeRelocatable, u32, Felt),
     #[error("{0}")]
     EcOpSameXCoordinate(String),
-    #[error("EcOpBuiltin: point {0:?} is not on the curve")]
-    PointNotOnCurve((usize, usize)),
+    #[error("Pedersen hash with custom points added two points with the same x coordinate")]
+    PedersenSameXCoordinate,
+    #[error("EcOpBuiltin: point ({0}, {1}) is not on the curve")]
+    PointNotOnCurve(Felt, Felt),
+    #[error("Number of steps must be at least {0} for the {1} builtin")]
+    MinStepNotReached(usize, String),
+    #[error("The {0} builtin used {1} cells, but only {2} were allocated for it")]
+    InsufficientAllocatedCells(String, usize, usize),
+    #[error("The number of steps ({0}) must be a multiple of the ratio of the {1} builtin ({2})")]
+    StepsNotMultipleOfRatio(usize, String, u32),
     #[error("Builtin(s) {0:?} not present in layout {1}")]
     NoBuiltinForInstance(HashSet<String>, String),
     #[error("Invalid layout {0}")]
@@ -67,8 +75,14 @@ pub enum RunnerError {
     BuiltinSegmentNameCollision(&'static str),
     #[error("Error while finalizing segments: {0}")]
     FinalizeSegements(MemoryError),
+    #[error("Output page start {0} is not in the output segment {1}")]
+    PageNotInOutputSegment(Relocatable, isize),
+    #[error("Output page {0} exceeds the used cells of the output segment")]
+    PageOutOfOutputSegment(usize),
     #[error("finalize_segments called but proof_mode is not enabled")]
     FinalizeSegmentsNoProofMode,
+    #[error("Segments must be finalized before computing the public memory")]
+    SegmentsNotFinalized,
     #[error("Final stack error")]
     FinalStack,
     #[error("Invalid stop pointer for {0} ")]
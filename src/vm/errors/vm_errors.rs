@@ -55,8 +55,13 @@ pub enum VirtualMachineError {
     NotImplemented,
     #[error("Can only subtract two relocatable values of the same segment")]
     DiffIndexSub,
-    #[error("Inconsistent auto-deduction for builtin {0}, expected {1}, got {2:?}")]
-    InconsistentAutoDeduction(String, MaybeRelocatable, Option<MaybeRelocatable>),
+    #[error("Inconsistent auto-deduction for builtin {0} at address {1}, expected {2}, got {3:?}")]
+    InconsistentAutoDeduction(
+        String,
+        Relocatable,
+        MaybeRelocatable,
+        Option<MaybeRelocatable>,
+    ),
     #[error(transparent)]
     RunnerError(#[from] RunnerError),
     #[error("Invalid hint encoding at pc: {0}")]
@@ -67,6 +72,8 @@ pub enum VirtualMachineError {
     NoRangeCheckBuiltin,
     #[error("Expected ecdsa builtin to be present")]
     NoSignatureBuiltin,
+    #[error("Expected output builtin to be present")]
+    NoOutputBuiltin,
     #[error("Failed to retrieve value from address {0}")]
     MemoryGet(MaybeRelocatable),
     #[error("Expected integer at address {0}")]
@@ -117,6 +124,8 @@ pub enum VirtualMachineError {
     NoImm,
     #[error("Cant substract {0} from offset {1}, offsets cant be negative")]
     CantSubOffset(usize, usize),
+    #[error("Adding {1} to the offset {0} gives an out of range offset")]
+    RelocatableAddOffsetOutOfRange(usize, i32),
     #[error("Execution reached the end of the program. Requested remaining steps: {0}.")]
     EndOfProgram(usize),
     #[error(transparent)]
@@ -133,6 +142,8 @@ pub enum VirtualMachineError {
     ErrorMessageAttribute(String, Box<VirtualMachineError>),
     #[error("Got an exception while executing a hint: {1}")]
     Hint(usize, Box<HintError>),
+    #[error("A hint wrote to {0}, which is also written by the instruction of the same step")]
+    HintWriteConflict(Relocatable),
     #[error("Unexpected Failure")]
     Unexpected,
 }
@@ -0,0 +1,85 @@
+use felt::Felt;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// Private input of the AIR prover: the paths of the relocated trace and memory files, and the
+/// input cells of every builtin instance used by the run, keyed by builtin name.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AirPrivateInput {
+    pub trace_path: PathBuf,
+    pub memory_path: PathBuf,
+    #[serde(flatten)]
+    pub builtins: BTreeMap<String, Vec<PrivateInput>>,
+}
+
+/// Input cells of a single builtin instance, named as the prover expects them
+/// (e.g. `x` and `y` for pedersen). Values are serialized as hex strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrivateInput {
+    pub index: usize,
+    pub values: Vec<(String, Felt)>,
+    pub signature_input: Option<SignatureInput>,
+}
+
+/// ECDSA signature in the form consumed by the prover, where `w` is the inverse of `s` modulo
+/// the curve order.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SignatureInput {
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub r: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub w: Felt,
+}
+
+fn felt_to_hex(value: &Felt) -> String {
+    format!("0x{}", value.to_str_radix(16))
+}
+
+fn serialize_felt_hex<S: Serializer>(value: &Felt, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&felt_to_hex(value))
+}
+
+impl Serialize for PrivateInput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("index", &self.index)?;
+        for (name, value) in self.values.iter() {
+            map.serialize_entry(name, &felt_to_hex(value))?;
+        }
+        if let Some(ref signature_input) = self.signature_input {
+            map.serialize_entry("signature_input", signature_input)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_private_input_flattens_builtins() {
+        let air_private_input = AirPrivateInput {
+            trace_path: PathBuf::from("trace.bin"),
+            memory_path: PathBuf::from("memory.bin"),
+            builtins: BTreeMap::from([(
+                "ecdsa".to_string(),
+                vec![PrivateInput {
+                    index: 0,
+                    values: vec![
+                        ("pubkey".to_string(), Felt::new(255)),
+                        ("msg".to_string(), Felt::new(2)),
+                    ],
+                    signature_input: Some(SignatureInput {
+                        r: Felt::new(16),
+                        w: Felt::new(1),
+                    }),
+                }],
+            )]),
+        };
+        assert_eq!(
+            serde_json::to_string(&air_private_input).unwrap(),
+            r#"{"trace_path":"trace.bin","memory_path":"memory.bin","ecdsa":[{"index":0,"pubkey":"0xff","msg":"0x2","signature_input":{"r":"0x10","w":"0x1"}}]}"#
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::math_utils::{ec_add, ec_double, safe_div_usize};
+use crate::math_utils::{ec_double_and_add, safe_div_usize};
 use crate::types::instance_definitions::ec_op_instance_def::{
     EcOpInstanceDef, CELLS_PER_EC_OP, INPUT_CELLS_PER_EC_OP,
 };
@@ -9,11 +9,18 @@ use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
 use felt::Felt;
-use num_bigint::{BigInt, ToBigInt};
+use num_bigint::BigInt;
 use num_integer::{div_ceil, Integer};
-use num_traits::{Num, One, Pow, Zero};
+use num_traits::{Num, One, Pow};
 use std::borrow::Cow;
 
+///Returns the `beta` coefficient of the STARK curve: y^2 = x^3 + alpha * x + beta (mod prime), with alpha = 1.
+pub(crate) fn stark_curve_beta() -> Felt {
+    let beta_low: Felt = Felt::new(0x609ad26c15c915c1f4cdfcb99cee9e89_u128);
+    let beta_high: Felt = Felt::new(0x6f21413efbe40de150e596d72f7a8c5_u128);
+    (beta_high << 128_usize) + beta_low
+}
+
 #[derive(Debug, Clone)]
 pub struct EcOpBuiltinRunner {
     ratio: u32,
@@ -23,7 +30,7 @@ pub struct EcOpBuiltinRunner {
     ec_op_builtin: EcOpInstanceDef,
     pub(crate) stop_ptr: Option<usize>,
     _included: bool,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
 }
 
 impl EcOpBuiltinRunner {
@@ -60,50 +67,31 @@ impl EcOpBuiltinRunner {
         prime: &BigInt,
         height: u32,
     ) -> Result<(BigInt, BigInt), RunnerError> {
-        let mut slope = m
-            .clone()
-            .to_biguint()
-            .to_bigint()
-            .ok_or(RunnerError::FoundNonInt)?;
-        let mut partial_sum_b = (
-            partial_sum
-                .0
-                .to_biguint()
-                .to_bigint()
-                .ok_or(RunnerError::FoundNonInt)?,
-            partial_sum
-                .1
-                .to_biguint()
-                .to_bigint()
-                .ok_or(RunnerError::FoundNonInt)?,
+        let partial_sum_b: (BigInt, BigInt) = (
+            partial_sum.0.to_biguint().into(),
+            partial_sum.1.to_biguint().into(),
         );
-        let mut doubled_point_b = (
-            doubled_point
-                .0
-                .to_biguint()
-                .to_bigint()
-                .ok_or(RunnerError::FoundNonInt)?,
-            doubled_point
-                .1
-                .to_biguint()
-                .to_bigint()
-                .ok_or(RunnerError::FoundNonInt)?,
+        let doubled_point_b: (BigInt, BigInt) = (
+            doubled_point.0.to_biguint().into(),
+            doubled_point.1.to_biguint().into(),
         );
-        for _ in 0..height {
-            if (doubled_point_b.0.clone() - partial_sum_b.0.clone()).is_zero() {
-                return Err(RunnerError::EcOpSameXCoordinate(Self::format_ec_op_error(
-                    partial_sum_b,
-                    m.clone().to_bigint(),
-                    doubled_point_b,
-                )));
-            };
-            if !(slope.clone() & &BigInt::one()).is_zero() {
-                partial_sum_b = ec_add(partial_sum_b, doubled_point_b.clone(), prime);
-            }
-            doubled_point_b = ec_double(doubled_point_b, alpha, prime);
-            slope = slope.clone() >> 1_u32;
-        }
-        Ok(partial_sum_b)
+        // Starting from a point, the partial sum only becomes the point at infinity by adding a
+        // point with the same x coordinate, which fails first
+        ec_double_and_add(
+            Some(partial_sum_b.clone()),
+            doubled_point_b.clone(),
+            &m.to_biguint().into(),
+            alpha,
+            prime,
+            height,
+        )?
+        .ok_or_else(|| {
+            RunnerError::EcOpSameXCoordinate(Self::format_ec_op_error(
+                partial_sum_b,
+                m.to_biguint().into(),
+                doubled_point_b,
+            ))
+        })
     }
 
     pub fn initialize_segments(
@@ -143,9 +131,7 @@ impl EcOpBuiltinRunner {
         const EC_POINT_INDICES: [(usize, usize); 3] = [(0, 1), (2, 3), (5, 6)];
         const OUTPUT_INDICES: (usize, usize) = EC_POINT_INDICES[2];
         let alpha: Felt = Felt::one();
-        let beta_low: Felt = Felt::new(0x609ad26c15c915c1f4cdfcb99cee9e89_u128);
-        let beta_high: Felt = Felt::new(0x6f21413efbe40de150e596d72f7a8c5_u128);
-        let beta: Felt = (beta_high << 128_usize) + beta_low;
+        let beta: Felt = stark_curve_beta();
 
         let index = address
             .offset
@@ -180,17 +166,14 @@ impl EcOpBuiltinRunner {
             ));
         }*/
 
-        // Assert that if the current address is part of a point, the point is on the curve
-        for pair in &EC_POINT_INDICES[0..1] {
-            if !EcOpBuiltinRunner::point_on_curve(
-                input_cells[pair.0].as_ref(),
-                input_cells[pair.1].as_ref(),
-                &alpha,
-                &beta,
-            ) {
-                return Err(RunnerError::PointNotOnCurve(*pair));
+        // Assert that both input points (P and Q) are on the curve
+        for pair in &EC_POINT_INDICES[0..2] {
+            let (x, y) = (input_cells[pair.0].as_ref(), input_cells[pair.1].as_ref());
+            if !EcOpBuiltinRunner::point_on_curve(x, y, &alpha, &beta) {
+                return Err(RunnerError::PointNotOnCurve(x.clone(), y.clone()));
             };
         }
+        // m is a felt, so it is already reduced modulo the prime and fits in scalar_height bits
         let prime = BigInt::from_str_radix(&felt::PRIME_STR[2..], 16)
             .map_err(|_| RunnerError::CouldntParsePrime)?;
         let result = EcOpBuiltinRunner::ec_op_impl(
@@ -940,6 +923,81 @@ mod tests {
         );*/
     }
 
+    // Memory holding a single ec_op instance at segment 3 with the given inputs
+    fn ec_op_instance_memory(p: (&str, &str), m: &str, q: (&str, &str)) -> Memory {
+        let mut memory = Memory::new();
+        memory.data = vec![Vec::new(); 3];
+        memory.data.push(
+            [p.0, p.1, q.0, q.1, m]
+                .iter()
+                .map(|value| Some(MaybeRelocatable::from(felt_str!(value))))
+                .collect(),
+        );
+        memory
+    }
+
+    const P_X: &str =
+        "2962412995502985605007699495352191122971573493113767820301112397466445942584";
+    const P_Y: &str = "214950771763870898744428659242275426967582168179217139798831865603966154129";
+    const Q_X: &str = "874739451078007766457464989774322083649278607533249481151382481072868806602";
+    const Q_Y: &str = "152666792071518830868575557812948353041420400780739481342941381225525861407";
+
+    #[test]
+    fn deduce_memory_cell_ec_op_valid_points() {
+        let memory = ec_op_instance_memory((P_X, P_Y), "34", (Q_X, Q_Y));
+        let builtin = EcOpBuiltinRunner::new(&EcOpInstanceDef::default(), true);
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((3, 5)), &memory),
+            Ok(Some(MaybeRelocatable::from(felt_str!(
+                "2778063437308421278851140253538604815869848682781135193774472480292420096757"
+            ))))
+        );
+    }
+
+    #[test]
+    fn deduce_memory_cell_ec_op_p_not_on_curve() {
+        let p_y = "214950771763870898744428659242275426967582168179217139798831865603966154130";
+        let memory = ec_op_instance_memory((P_X, p_y), "34", (Q_X, Q_Y));
+        let builtin = EcOpBuiltinRunner::new(&EcOpInstanceDef::default(), true);
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((3, 5)), &memory),
+            Err(RunnerError::PointNotOnCurve(felt_str!(P_X), felt_str!(p_y)))
+        );
+    }
+
+    #[test]
+    fn deduce_memory_cell_ec_op_q_not_on_curve() {
+        let memory = ec_op_instance_memory((P_X, P_Y), "34", (Q_X, "1"));
+        let builtin = EcOpBuiltinRunner::new(&EcOpInstanceDef::default(), true);
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((3, 6)), &memory),
+            Err(RunnerError::PointNotOnCurve(felt_str!(Q_X), Felt::one()))
+        );
+    }
+
+    #[test]
+    fn deduce_memory_cell_ec_op_large_scalar() {
+        //m = PRIME - 1
+        let memory = ec_op_instance_memory(
+            (P_X, P_Y),
+            "3618502788666131213697322783095070105623107215331596699973092056135872020480",
+            (Q_X, Q_Y),
+        );
+        let builtin = EcOpBuiltinRunner::new(&EcOpInstanceDef::default(), true);
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((3, 5)), &memory),
+            Ok(Some(MaybeRelocatable::from(felt_str!(
+                "756624533868665322949834283579147304336594905989560095256624999302135560320"
+            ))))
+        );
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((3, 6)), &memory),
+            Ok(Some(MaybeRelocatable::from(felt_str!(
+                "163915726923271219645838854477366861578225926188989078065631851804557912515"
+            ))))
+        );
+    }
+
     #[test]
     fn get_memory_segment_addresses() {
         let builtin = EcOpBuiltinRunner::new(&EcOpInstanceDef::default(), true);
@@ -1,6 +1,7 @@
 use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
 
-use crate::math_utils::safe_div_usize;
+use crate::math_utils::{ec_add, ec_double, safe_div_usize};
 use crate::types::instance_definitions::pedersen_instance_def::{
     CELLS_PER_HASH, INPUT_CELLS_PER_HASH,
 };
@@ -10,10 +11,164 @@ use crate::vm::errors::runner_errors::RunnerError;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
-use felt::Felt;
+use felt::{felt_str, Felt};
+use num_bigint::{BigInt, BigUint};
 use num_integer::{div_ceil, Integer};
+use num_traits::{One, Zero};
 use starknet_crypto::{pedersen_hash, FieldElement};
 
+/// Constant points used to compute the Pedersen hash H(x, y) as
+/// shift_point + x_low * P0 + x_high * P1 + y_low * P2 + y_high * P3,
+/// where the low parts are the 248 least significant bits of each input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PedersenPoints {
+    pub shift_point: (Felt, Felt),
+    pub points: [(Felt, Felt); 4],
+}
+
+impl PedersenPoints {
+    /// Returns the points used by the StarkNet Pedersen hash
+    pub fn standard() -> Self {
+        let point = |x: &str, y: &str| (felt_str!(x, 16), felt_str!(y, 16));
+        PedersenPoints {
+            shift_point: point(
+                "49ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804",
+                "3ca0cfe4b3bc6ddf346d49d06ea0ed34e621062c0e056c1d0405d266e10268a",
+            ),
+            points: [
+                point(
+                    "234287dcbaffe7f969c748655fca9e58fa8120b6d56eb0c1080d17957ebe47b",
+                    "3b056f100f96fb21e889527d41f4e39940135dd7a6c94cc6ed0268ee89e5615",
+                ),
+                point(
+                    "4fa56f376c83db33f9dab2656558f3399099ec1de5e3018b7a6932dba8aa378",
+                    "3fa0984c931c9e38113e0c0e47e4401562761f92a7a23b45168f4e80ff5b54d",
+                ),
+                point(
+                    "4ba4cc166be8dec764910f75b45f74b40c690c74709e90f3aa372f0bd2d6997",
+                    "40301cf5c1751f4b971e46c4ede85fcac5c59a5ce5ae7c48151f27b24b219c",
+                ),
+                point(
+                    "54302dcb0e6cc1c6e44cca8f61a63bb2ca65048d53fb325d36ff12c49a58202",
+                    "1b77b3e37d13504b348046268d8ae25ce98ad783c25561a879dcc77e99c2426",
+                ),
+            ],
+        }
+    }
+
+    /// Computes the Pedersen hash of x and y using these points, over the STARK curve
+    pub fn hash(&self, x: &Felt, y: &Felt) -> Result<Felt, RunnerError> {
+        let prime: BigInt = Felt::prime().into();
+        let to_bigint_point = |point: &(Felt, Felt)| -> (BigInt, BigInt) {
+            (point.0.to_biguint().into(), point.1.to_biguint().into())
+        };
+        let low_mask = (BigUint::one() << 248_u32) - BigUint::one();
+        let mut result = to_bigint_point(&self.shift_point);
+        for (i, input) in [x, y].into_iter().enumerate() {
+            let input = input.to_biguint();
+            let parts = [&input & &low_mask, input >> 248_u32];
+            for (j, part) in parts.into_iter().enumerate() {
+                let point = to_bigint_point(&self.points[2 * i + j]);
+                result = Self::add_multiple(result, point, part, &prime)?;
+            }
+        }
+        Ok(Felt::new(result.0.to_biguint().unwrap_or_default()))
+    }
+
+    // Returns partial_sum + scalar * point
+    fn add_multiple(
+        mut partial_sum: (BigInt, BigInt),
+        mut point: (BigInt, BigInt),
+        mut scalar: BigUint,
+        prime: &BigInt,
+    ) -> Result<(BigInt, BigInt), RunnerError> {
+        let alpha = BigInt::one();
+        while !scalar.is_zero() {
+            if scalar.is_odd() {
+                if partial_sum.0 == point.0 {
+                    return Err(RunnerError::PedersenSameXCoordinate);
+                }
+                partial_sum = ec_add(partial_sum, point.clone(), prime);
+            }
+            scalar >>= 1_u32;
+            if !scalar.is_zero() {
+                point = ec_double(point, &alpha, prime);
+            }
+        }
+        Ok(partial_sum)
+    }
+}
+
+/// Bounded cache of Pedersen results keyed on the hashed pair. When full, the least recently
+/// used entry is evicted.
+#[derive(Debug, Clone)]
+pub(crate) struct PedersenCache {
+    pub(crate) capacity: usize,
+    // Maps each pair to its hash and the tick at which it was last used
+    entries: HashMap<(Felt, Felt), (Felt, u64)>,
+    // Uses of the pairs, oldest first. A use is stale once its pair was used again at a later
+    // tick, stale uses are skipped on eviction and dropped when they pile up
+    uses: VecDeque<((Felt, Felt), u64)>,
+    tick: u64,
+}
+
+impl PedersenCache {
+    fn new(capacity: usize) -> Self {
+        PedersenCache {
+            capacity,
+            entries: HashMap::new(),
+            uses: VecDeque::new(),
+            tick: 0,
+        }
+    }
+
+    fn get(&mut self, key: &(Felt, Felt)) -> Option<Felt> {
+        let (result, last_used) = self.entries.get_mut(key)?;
+        self.tick += 1;
+        *last_used = self.tick;
+        let result = result.clone();
+        self.record_use(key.clone());
+        Some(result)
+    }
+
+    fn insert(&mut self, key: (Felt, Felt), result: Felt) {
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            self.evict_least_recently_used();
+        }
+        self.tick += 1;
+        self.entries.insert(key.clone(), (result, self.tick));
+        self.record_use(key);
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        while let Some((key, tick)) = self.uses.pop_front() {
+            if is_current_use(&self.entries, &key, tick) {
+                self.entries.remove(&key);
+                return;
+            }
+        }
+    }
+
+    fn record_use(&mut self, key: (Felt, Felt)) {
+        self.uses.push_back((key, self.tick));
+        // Keeps the queue within a constant factor of the entries, so each use is dropped in
+        // amortized constant time
+        if self.uses.len() > 2 * self.capacity {
+            let entries = &self.entries;
+            self.uses
+                .retain(|(key, tick)| is_current_use(entries, key, *tick));
+        }
+    }
+}
+
+fn is_current_use(
+    entries: &HashMap<(Felt, Felt), (Felt, u64)>,
+    key: &(Felt, Felt),
+    tick: u64,
+) -> bool {
+    matches!(entries.get(key), Some((_, last_used)) if *last_used == tick)
+}
+
 #[derive(Debug, Clone)]
 pub struct HashBuiltinRunner {
     pub base: isize,
@@ -22,10 +177,14 @@ pub struct HashBuiltinRunner {
     pub(crate) n_input_cells: u32,
     pub(crate) stop_ptr: Option<usize>,
     pub(crate) _included: bool,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
     // This act as a cache to optimize calls to deduce_memory_cell
     // Therefore need interior mutability
     pub(self) verified_addresses: RefCell<Vec<Relocatable>>,
+    // When set, hashes are computed with these points instead of starknet_crypto's
+    pedersen_points: Option<PedersenPoints>,
+    // When set, hash results are reused for pairs that were already hashed
+    pub(crate) cache: RefCell<Option<PedersenCache>>,
 }
 
 impl HashBuiltinRunner {
@@ -39,6 +198,24 @@ impl HashBuiltinRunner {
             verified_addresses: RefCell::new(Vec::new()),
             _included: included,
             instances_per_component: 1,
+            pedersen_points: None,
+            cache: RefCell::new(None),
+        }
+    }
+
+    /// Keeps the results of the last `capacity` distinct pairs hashed by the runner, so
+    /// programs hashing the same pairs repeatedly don't recompute them. Disabled by default,
+    /// as it trades memory for speed. A capacity of 0 disables it.
+    pub fn set_cache_capacity(&mut self, capacity: usize) {
+        *self.cache.get_mut() = (capacity > 0).then(|| PedersenCache::new(capacity));
+    }
+
+    /// Creates a runner whose hashes are computed with the given constant points, for
+    /// experimenting with alternative Pedersen parameters
+    pub fn new_with_points(ratio: u32, included: bool, pedersen_points: PedersenPoints) -> Self {
+        HashBuiltinRunner {
+            pedersen_points: Some(pedersen_points),
+            ..HashBuiltinRunner::new(ratio, included)
         }
     }
 
@@ -98,26 +275,41 @@ impl HashBuiltinRunner {
         ) {
             self.verified_addresses.borrow_mut().push(*address);
 
-            //Convert MaybeRelocatable to FieldElement
-            let a_string = num_a.to_str_radix(10);
-            let b_string = num_b.to_str_radix(10);
-            let (y, x) = match (
-                FieldElement::from_dec_str(&a_string),
-                FieldElement::from_dec_str(&b_string),
-            ) {
-                (Ok(field_element_a), Ok(field_element_b)) => (field_element_a, field_element_b),
-                _ => return Err(RunnerError::FailedStringConversion),
-            };
-            //Compute pedersen Hash
-            let fe_result = pedersen_hash(&x, &y);
-            //Convert result from FieldElement to MaybeRelocatable
-            let r_byte_slice = fe_result.to_bytes_be();
-            let result = Felt::from_bytes_be(&r_byte_slice);
-            return Ok(Some(MaybeRelocatable::from(result)));
+            if let Some(cache) = self.cache.borrow_mut().as_mut() {
+                let key = (num_b.clone(), num_a.clone());
+                if let Some(result) = cache.get(&key) {
+                    return Ok(Some(MaybeRelocatable::from(result)));
+                }
+                let result = self.hash(num_b, num_a)?;
+                cache.insert(key, result.clone());
+                return Ok(Some(MaybeRelocatable::from(result)));
+            }
+            return Ok(Some(MaybeRelocatable::from(self.hash(num_b, num_a)?)));
         }
         Ok(None)
     }
 
+    // Computes the Pedersen hash of x and y
+    fn hash(&self, x: &Felt, y: &Felt) -> Result<Felt, RunnerError> {
+        if let Some(pedersen_points) = &self.pedersen_points {
+            return pedersen_points.hash(x, y);
+        }
+
+        //Convert Felt to FieldElement
+        let (x, y) = match (
+            FieldElement::from_dec_str(&x.to_str_radix(10)),
+            FieldElement::from_dec_str(&y.to_str_radix(10)),
+        ) {
+            (Ok(field_element_x), Ok(field_element_y)) => (field_element_x, field_element_y),
+            _ => return Err(RunnerError::FailedStringConversion),
+        };
+        //Compute pedersen Hash
+        let fe_result = pedersen_hash(&x, &y);
+        //Convert result from FieldElement to Felt
+        let r_byte_slice = fe_result.to_bytes_be();
+        Ok(Felt::from_bytes_be(&r_byte_slice))
+    }
+
     pub fn get_allocated_memory_units(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let value = safe_div_usize(vm.current_step, self.ratio as usize)
             .map_err(|_| MemoryError::ErrorCalculatingMemoryUnits)?;
@@ -428,6 +620,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deduce_memory_cell_pedersen_cached_matches_uncached() {
+        let mut cached = HashBuiltinRunner::new(8, true);
+        cached.set_cache_capacity(2);
+        let uncached = HashBuiltinRunner::new(8, true);
+        //Three instances, the third one hashing the same pair as the first one
+        let memories: Vec<Memory> = [cached, uncached]
+            .iter()
+            .map(|builtin| {
+                let mut memory = memory![
+                    ((0, 0), 32),
+                    ((0, 1), 72),
+                    ((0, 3), 5),
+                    ((0, 4), 7),
+                    ((0, 6), 32),
+                    ((0, 7), 72)
+                ];
+                for offset in [2, 5, 8] {
+                    let address = Relocatable::from((0, offset));
+                    let value = builtin
+                        .deduce_memory_cell(&address, &memory)
+                        .unwrap()
+                        .unwrap();
+                    memory.insert_value(&address, value).unwrap();
+                }
+                memory
+            })
+            .collect();
+        assert_eq!(memories[0].data, memories[1].data);
+        assert_eq!(
+            memories[0]
+                .get_integer(&Relocatable::from((0, 8)))
+                .unwrap()
+                .as_ref(),
+            &felt_str!(
+                "3270867057177188607814717243084834301278723532952411121381966378910183338911"
+            )
+        );
+    }
+
+    #[test]
+    fn pedersen_cache_evicts_least_recently_used() {
+        let pair = |a: i32, b: i32| (Felt::new(a), Felt::new(b));
+        let mut cache = PedersenCache::new(2);
+        cache.insert(pair(1, 2), Felt::new(3));
+        cache.insert(pair(4, 5), Felt::new(9));
+        assert_eq!(cache.get(&pair(1, 2)), Some(Felt::new(3)));
+        //(4, 5) is the least recently used entry
+        cache.insert(pair(6, 7), Felt::new(13));
+        assert_eq!(cache.get(&pair(4, 5)), None);
+        assert_eq!(cache.get(&pair(1, 2)), Some(Felt::new(3)));
+        assert_eq!(cache.get(&pair(6, 7)), Some(Felt::new(13)));
+    }
+
+    #[test]
+    fn pedersen_cache_use_queue_stays_bounded() {
+        let pair = |a: i32, b: i32| (Felt::new(a), Felt::new(b));
+        let mut cache = PedersenCache::new(2);
+        cache.insert(pair(1, 2), Felt::new(3));
+        cache.insert(pair(4, 5), Felt::new(9));
+        for _ in 0..10 {
+            assert_eq!(cache.get(&pair(4, 5)), Some(Felt::new(9)));
+        }
+        assert!(cache.uses.len() <= 4);
+        //The stale uses dropped on the way don't affect which entry is evicted
+        cache.insert(pair(6, 7), Felt::new(13));
+        assert_eq!(cache.get(&pair(1, 2)), None);
+        assert_eq!(cache.get(&pair(4, 5)), Some(Felt::new(9)));
+    }
+
+    #[test]
+    fn deduce_memory_cell_pedersen_with_standard_points() {
+        let memory = memory![((0, 3), 32), ((0, 4), 72), ((0, 5), 0)];
+        let builtin = HashBuiltinRunner::new_with_points(8, true, PedersenPoints::standard());
+
+        let result = builtin.deduce_memory_cell(&Relocatable::from((0, 5)), &memory);
+        assert_eq!(
+            result,
+            Ok(Some(MaybeRelocatable::from(felt_str!(
+                "3270867057177188607814717243084834301278723532952411121381966378910183338911"
+            ))))
+        );
+    }
+
+    #[test]
+    fn pedersen_points_standard_hash_known_value() {
+        let x = felt_str!(
+            "3d937c035c878245caf64531a5756109c53068da139362728feb561405371cb",
+            16
+        );
+        let y = felt_str!(
+            "208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a",
+            16
+        );
+        assert_eq!(
+            PedersenPoints::standard().hash(&x, &y),
+            Ok(felt_str!(
+                "30e480bed5fe53fa909cc0f8c4d99b8f9f2c016be4c41e13a4848797979c662",
+                16
+            ))
+        );
+    }
+
     #[test]
     fn deduce_memory_cell_pedersen_for_preset_memory_incorrect_offset() {
         let memory = memory![((0, 4), 32), ((0, 5), 72), ((0, 6), 0)];
@@ -1,6 +1,7 @@
 use crate::hint_processor::builtin_hint_processor::cairo_keccak::keccak_hints::{
     maybe_reloc_vec_to_u64_array, u64_array_to_mayberelocatable_vec,
 };
+use crate::hint_processor::builtin_hint_processor::cairo_keccak::keccak_permutation::keccak_f1600;
 use crate::hint_processor::builtin_hint_processor::keccak_utils::left_pad_u64;
 use crate::math_utils::safe_div_usize;
 use crate::types::instance_definitions::keccak_instance_def::KeccakInstanceDef;
@@ -26,7 +27,7 @@ pub struct KeccakBuiltinRunner {
     pub(crate) stop_ptr: Option<usize>,
     _included: bool,
     state_rep: Vec<u32>,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
 }
 
 impl KeccakBuiltinRunner {
@@ -135,7 +136,7 @@ impl KeccakBuiltinRunner {
                 .try_into()
                 .map_err(|_| RunnerError::SliceToArrayError)?;
 
-            keccak::f1600(&mut input_felts_u64);
+            keccak_f1600(&mut input_felts_u64);
 
             let bigint_values = u64_array_to_mayberelocatable_vec(&input_felts_u64);
 
@@ -2,6 +2,7 @@ use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::{self, MemoryError};
 use crate::vm::errors::runner_errors::RunnerError;
 use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::runners::air_private_input::PrivateInput;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
@@ -16,10 +17,11 @@ mod signature;
 
 pub use self::keccak::KeccakBuiltinRunner;
 pub use bitwise::BitwiseBuiltinRunner;
+pub(crate) use ec_op::stark_curve_beta;
 pub use ec_op::EcOpBuiltinRunner;
 pub use hash::HashBuiltinRunner;
 use num_integer::div_floor;
-pub use output::OutputBuiltinRunner;
+pub use output::{OutputBuiltinRunner, PageInfo};
 pub use range_check::RangeCheckBuiltinRunner;
 pub use signature::SignatureBuiltinRunner;
 
@@ -225,6 +227,97 @@ impl BuiltinRunner {
         }
     }
 
+    /// Number of memory cells used by each instance of the builtin
+    pub fn cells_per_instance(&self) -> usize {
+        match self {
+            BuiltinRunner::Bitwise(ref bitwise) => bitwise.cells_per_instance as usize,
+            BuiltinRunner::EcOp(ref ec) => ec.cells_per_instance as usize,
+            BuiltinRunner::Hash(ref hash) => hash.cells_per_instance as usize,
+            BuiltinRunner::Output(_) => 1,
+            BuiltinRunner::RangeCheck(ref range_check) => range_check.cells_per_instance as usize,
+            BuiltinRunner::Keccak(ref keccak) => keccak.cells_per_instance as usize,
+            BuiltinRunner::Signature(ref signature) => signature.cells_per_instance as usize,
+        }
+    }
+
+    /// Number of instances which make up a component of the builtin in the AIR
+    pub fn instances_per_component(&self) -> u32 {
+        match self {
+            BuiltinRunner::Bitwise(ref bitwise) => bitwise.instances_per_component,
+            BuiltinRunner::EcOp(ref ec) => ec.instances_per_component,
+            BuiltinRunner::Hash(ref hash) => hash.instances_per_component,
+            BuiltinRunner::Output(_) => 1,
+            BuiltinRunner::RangeCheck(ref range_check) => range_check.instances_per_component,
+            BuiltinRunner::Keccak(ref keccak) => keccak.instances_per_component,
+            BuiltinRunner::Signature(ref signature) => signature.instances_per_component,
+        }
+    }
+
+    /// Number of cells of each instance which are written by the program, the rest are deduced
+    /// by the builtin
+    pub fn n_input_cells(&self) -> usize {
+        match self {
+            BuiltinRunner::Bitwise(ref bitwise) => bitwise.n_input_cells as usize,
+            BuiltinRunner::EcOp(ref ec) => ec.n_input_cells as usize,
+            BuiltinRunner::Hash(ref hash) => hash.n_input_cells as usize,
+            BuiltinRunner::Output(_) => 1,
+            BuiltinRunner::RangeCheck(ref range_check) => range_check.n_input_cells as usize,
+            BuiltinRunner::Keccak(ref keccak) => keccak.n_input_cells as usize,
+            BuiltinRunner::Signature(ref signature) => signature.n_input_cells as usize,
+        }
+    }
+
+    /// Names under which the prover expects the input cells of each instance, in memory order
+    fn input_cell_names(&self) -> Vec<String> {
+        let names: &[&str] = match self {
+            BuiltinRunner::Bitwise(_) | BuiltinRunner::Hash(_) => &["x", "y"],
+            BuiltinRunner::EcOp(_) => &["p_x", "p_y", "q_x", "q_y", "m"],
+            BuiltinRunner::RangeCheck(_) => &["value"],
+            BuiltinRunner::Keccak(ref keccak) => {
+                return (0..keccak.n_input_cells)
+                    .map(|i| format!("input_s{i}"))
+                    .collect()
+            }
+            BuiltinRunner::Output(_) | BuiltinRunner::Signature(_) => &[],
+        };
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    /// Returns the input cells of every instance written by the program, as required by the
+    /// AIR private input. The output builtin has no private input.
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        if let BuiltinRunner::Signature(ref signature) = self {
+            return signature.air_private_input(memory);
+        }
+        let names = self.input_cell_names();
+        let segment = match usize::try_from(self.base())
+            .ok()
+            .and_then(|base| memory.data.get(base))
+        {
+            Some(segment) if !names.is_empty() => segment,
+            _ => return Vec::new(),
+        };
+        segment
+            .chunks(self.cells_per_instance())
+            .enumerate()
+            .filter_map(|(index, cells)| {
+                let values: Vec<_> = names
+                    .iter()
+                    .zip(cells)
+                    .filter_map(|(name, cell)| match cell {
+                        Some(MaybeRelocatable::Int(value)) => Some((name.clone(), value.clone())),
+                        _ => None,
+                    })
+                    .collect();
+                (!values.is_empty()).then_some(PrivateInput {
+                    index,
+                    values,
+                    signature_input: None,
+                })
+            })
+            .collect()
+    }
+
     pub fn get_range_check_usage(&self, memory: &Memory) -> Option<(usize, usize)> {
         match self {
             BuiltinRunner::RangeCheck(ref range_check) => range_check.get_range_check_usage(memory),
@@ -262,15 +355,8 @@ impl BuiltinRunner {
             return Ok(());
         }
 
-        let (cells_per_instance, n_input_cells) = match self {
-            BuiltinRunner::Bitwise(x) => (x.cells_per_instance, x.n_input_cells),
-            BuiltinRunner::EcOp(x) => (x.cells_per_instance, x.n_input_cells),
-            BuiltinRunner::Hash(x) => (x.cells_per_instance, x.n_input_cells),
-            BuiltinRunner::RangeCheck(x) => (x.cells_per_instance, x.n_input_cells),
-            BuiltinRunner::Output(_) => unreachable!(),
-            BuiltinRunner::Keccak(x) => (x.cells_per_instance, x.n_input_cells),
-            BuiltinRunner::Signature(ref x) => (x.cells_per_instance, x.n_input_cells),
-        };
+        let cells_per_instance = self.cells_per_instance();
+        let n_input_cells = self.n_input_cells();
 
         let base = self.base();
         let offsets = vm
@@ -292,8 +378,8 @@ impl BuiltinRunner {
         let n = offsets
             .iter()
             .max()
-            .map_or(0, |x| div_floor(*x, cells_per_instance as usize) + 1);
-        if n > div_floor(offsets.len(), n_input_cells as usize) {
+            .map_or(0, |x| div_floor(*x, cells_per_instance) + 1);
+        if n > div_floor(offsets.len(), n_input_cells) {
             return Err(MemoryError::MissingMemoryCells(match self {
                 BuiltinRunner::Bitwise(_) => "bitwise",
                 BuiltinRunner::EcOp(_) => "ec_op",
@@ -311,8 +397,8 @@ impl BuiltinRunner {
         let mut offsets_iter = offsets.into_iter().peekable();
         let mut missing_offsets = Vec::new();
         for i in 0..n {
-            let expected_offset_base = cells_per_instance as usize * i;
-            for j in 0..n_input_cells as usize {
+            let expected_offset_base = cells_per_instance * i;
+            for j in 0..n_input_cells {
                 let expected_offset = expected_offset_base + j;
                 let current_offset = loop {
                     match offsets_iter.peek() {
@@ -348,8 +434,8 @@ impl BuiltinRunner {
 
         let mut should_validate_auto_deductions = false;
         for i in 0..n {
-            for j in n_input_cells as usize..cells_per_instance as usize {
-                let addr: Relocatable = (base, cells_per_instance as usize * i + j).into();
+            for j in n_input_cells..cells_per_instance {
+                let addr: Relocatable = (base, cells_per_instance * i + j).into();
                 if !vm.memory.validated_addresses.contains(&addr.into()) {
                     should_validate_auto_deductions = true;
                 }
@@ -452,6 +538,26 @@ mod tests {
         vm::vm_core::VirtualMachine,
     };
 
+    #[test]
+    fn cells_per_instance_and_n_input_cells() {
+        let pedersen: BuiltinRunner = HashBuiltinRunner::new(8, true).into();
+        assert_eq!(pedersen.cells_per_instance(), 3);
+        assert_eq!(pedersen.n_input_cells(), 2);
+
+        let range_check: BuiltinRunner = RangeCheckBuiltinRunner::new(8, 8, true).into();
+        assert_eq!(range_check.cells_per_instance(), 1);
+        assert_eq!(range_check.n_input_cells(), 1);
+
+        let bitwise: BuiltinRunner =
+            BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true).into();
+        assert_eq!(bitwise.cells_per_instance(), 5);
+        assert_eq!(bitwise.n_input_cells(), 2);
+
+        let output: BuiltinRunner = OutputBuiltinRunner::new(true).into();
+        assert_eq!(output.cells_per_instance(), 1);
+        assert_eq!(output.n_input_cells(), 1);
+    }
+
     #[test]
     fn get_memory_accesses_missing_segment_used_sizes() {
         let builtin: BuiltinRunner =
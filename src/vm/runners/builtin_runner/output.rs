@@ -4,10 +4,20 @@ use crate::vm::errors::runner_errors::RunnerError;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+use std::collections::HashMap;
+
+/// A range of the output segment, given as an offset from its base, which is published as a
+/// separate public memory page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageInfo {
+    pub start: usize,
+    pub size: usize,
+}
 
 #[derive(Debug, Clone)]
 pub struct OutputBuiltinRunner {
     base: isize,
+    pages: HashMap<usize, PageInfo>,
     pub(crate) stop_ptr: Option<usize>,
     pub(crate) _included: bool,
 }
@@ -16,6 +26,7 @@ impl OutputBuiltinRunner {
     pub fn new(included: bool) -> OutputBuiltinRunner {
         OutputBuiltinRunner {
             base: 0,
+            pages: HashMap::new(),
             stop_ptr: None,
             _included: included,
         }
@@ -41,6 +52,46 @@ impl OutputBuiltinRunner {
         self.base
     }
 
+    /// Assigns the `size` output cells starting at `start` to the public memory page `page_id`.
+    /// Cells that don't belong to any page are part of page 0.
+    pub fn add_page(
+        &mut self,
+        page_id: usize,
+        start: Relocatable,
+        size: usize,
+    ) -> Result<(), RunnerError> {
+        if start.segment_index != self.base {
+            return Err(RunnerError::PageNotInOutputSegment(start, self.base));
+        }
+        self.pages.insert(
+            page_id,
+            PageInfo {
+                start: start.offset,
+                size,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get_pages(&self) -> &HashMap<usize, PageInfo> {
+        &self.pages
+    }
+
+    /// Returns the public memory of the output segment as (offset, page_id) pairs, given the
+    /// amount of used cells
+    pub fn get_public_memory(&self, size: usize) -> Result<Vec<(usize, usize)>, RunnerError> {
+        let mut public_memory: Vec<(usize, usize)> = (0..size).map(|i| (i, 0)).collect();
+        for (page_id, page) in self.pages.iter() {
+            for offset in page.start..page.start + page.size {
+                public_memory
+                    .get_mut(offset)
+                    .ok_or(RunnerError::PageOutOfOutputSegment(*page_id))?
+                    .1 = *page_id;
+            }
+        }
+        Ok(public_memory)
+    }
+
     pub fn add_validation_rule(&self, _memory: &mut Memory) -> Result<(), RunnerError> {
         Ok(())
     }
@@ -134,6 +185,39 @@ mod tests {
         },
     };
 
+    #[test]
+    fn add_page_and_get_public_memory() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.base = 2;
+        assert_eq!(builtin.add_page(1, (2, 0).into(), 2), Ok(()));
+        assert_eq!(builtin.add_page(2, (2, 2).into(), 1), Ok(()));
+        assert_eq!(
+            builtin.get_pages(),
+            &HashMap::from([
+                (1, PageInfo { start: 0, size: 2 }),
+                (2, PageInfo { start: 2, size: 1 })
+            ])
+        );
+        assert_eq!(
+            builtin.get_public_memory(4),
+            Ok(vec![(0, 1), (1, 1), (2, 2), (3, 0)])
+        );
+        assert_eq!(
+            builtin.get_public_memory(2),
+            Err(RunnerError::PageOutOfOutputSegment(2))
+        );
+    }
+
+    #[test]
+    fn add_page_outside_output_segment() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        assert_eq!(
+            builtin.add_page(1, (3, 0).into(), 2),
+            Err(RunnerError::PageNotInOutputSegment((3, 0).into(), 0))
+        );
+        assert!(builtin.get_pages().is_empty());
+    }
+
     #[test]
     fn get_used_instances() {
         let builtin = OutputBuiltinRunner::new(true);
@@ -32,7 +32,7 @@ pub struct RangeCheckBuiltinRunner {
     pub _bound: Option<Felt>,
     pub(crate) _included: bool,
     n_parts: u32,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
 }
 
 impl RangeCheckBuiltinRunner {
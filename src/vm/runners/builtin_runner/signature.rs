@@ -1,11 +1,12 @@
 use crate::{
-    math_utils::safe_div_usize,
+    math_utils::{div_mod, safe_div_usize},
     types::{
         instance_definitions::ecdsa_instance_def::EcdsaInstanceDef,
         relocatable::{MaybeRelocatable, Relocatable},
     },
     vm::{
         errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+        runners::air_private_input::{PrivateInput, SignatureInput},
         vm_core::VirtualMachine,
         vm_memory::{
             memory::{Memory, ValidationRule},
@@ -14,11 +15,22 @@ use crate::{
     },
 };
 use felt::Felt;
+use lazy_static::lazy_static;
+use num_bigint::{BigInt, Sign};
 use num_integer::{div_ceil, Integer};
-use num_traits::ToPrimitive;
+use num_traits::{One, ToPrimitive};
 use starknet_crypto::{verify, FieldElement, Signature};
 use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
+lazy_static! {
+    // Order of the STARK curve's generator
+    static ref EC_ORDER: BigInt = BigInt::parse_bytes(
+        b"800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f",
+        16
+    )
+    .unwrap();
+}
+
 #[derive(Debug, Clone)]
 pub struct SignatureBuiltinRunner {
     included: bool,
@@ -28,7 +40,7 @@ pub struct SignatureBuiltinRunner {
     pub(crate) n_input_cells: u32,
     _total_n_bits: u32,
     pub(crate) stop_ptr: Option<usize>,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
     signatures: Rc<RefCell<HashMap<Relocatable, Signature>>>,
 }
 
@@ -73,6 +85,32 @@ impl SignatureBuiltinRunner {
 
         Ok(())
     }
+
+    /// Returns the public key, message and signature of every signed instance, sorted by
+    /// instance index. Signatures whose cells were never written are skipped.
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        let mut private_inputs: Vec<PrivateInput> = self
+            .signatures
+            .borrow()
+            .iter()
+            .filter(|(addr, _)| addr.segment_index == self.base)
+            .filter_map(|(addr, signature)| {
+                let pubkey = memory.get_integer(addr).ok()?.into_owned();
+                let msg = memory.get_integer(&(addr + 1)).ok()?.into_owned();
+                let s = BigInt::from_bytes_be(Sign::Plus, &signature.s.to_bytes_be());
+                Some(PrivateInput {
+                    index: addr.offset / self.cells_per_instance as usize,
+                    values: vec![("pubkey".to_string(), pubkey), ("msg".to_string(), msg)],
+                    signature_input: Some(SignatureInput {
+                        r: Felt::from_bytes_be(&signature.r.to_bytes_be()),
+                        w: Felt::new(div_mod(&BigInt::one(), &s, &EC_ORDER)),
+                    }),
+                })
+            })
+            .collect();
+        private_inputs.sort_by_key(|private_input| private_input.index);
+        private_inputs
+    }
 }
 
 impl SignatureBuiltinRunner {
@@ -1,5 +1,8 @@
 use crate::{
-    hint_processor::hint_processor_definition::{HintProcessor, HintReference},
+    hint_processor::{
+        builtin_hint_processor::hint_rng::{HintRng, HINT_RNG},
+        hint_processor_definition::{HintProcessor, HintReference},
+    },
     math_utils::safe_div_usize,
     serde::deserialize_program::OffsetValue,
     types::{
@@ -24,6 +27,7 @@ use crate::{
         trace::get_perm_range_check_limits,
         vm_memory::memory::RelocateValue,
         {
+            runners::air_private_input::AirPrivateInput,
             runners::builtin_runner::{
                 BitwiseBuiltinRunner, BuiltinRunner, EcOpBuiltinRunner, HashBuiltinRunner,
                 OutputBuiltinRunner, RangeCheckBuiltinRunner, SignatureBuiltinRunner,
@@ -36,10 +40,12 @@ use crate::{
 use felt::Felt;
 use num_integer::div_rem;
 use num_traits::Zero;
+use rand_core::SeedableRng;
 use std::{
     any::Any,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     io,
+    path::Path,
 };
 
 use super::builtin_runner::KeccakBuiltinRunner;
@@ -62,6 +68,33 @@ impl From<Vec<MaybeRelocatable>> for CairoArg {
     }
 }
 
+/// Optional settings for a run, on top of the program, layout and proof mode.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CairoRunConfig {
+    /// If true, builtins declared by the program but lacking a runner (unknown, or not part of the
+    /// layout) are given a plain segment and skipped during validation, instead of failing.
+    pub allow_missing_builtins: bool,
+    /// Seed for the generator consumed by nondeterministic hints (see `hint_rng`). When None,
+    /// the generator is seeded from the system clock.
+    pub hint_seed: Option<u64>,
+    /// Expected number of instances used by each builtin, by name. The builtin segments are
+    /// preallocated to fit them, which avoids reallocations during long runs. The execution is
+    /// the same regardless of the values given.
+    pub builtin_instance_hints: HashMap<String, usize>,
+    /// If true, memory inserts skip the consistency check on already written cells, keeping their
+    /// first value, and allocate missing segments instead of failing. This must only be used to
+    /// run trusted programs that are known to execute correctly, as invalid memory accesses go
+    /// unnoticed.
+    pub skip_memory_checks: bool,
+    /// If true, failing hints are collected instead of aborting the run, and the operands the
+    /// following instruction can't deduce are taken as zero. The errors are available from
+    /// `VirtualMachine::get_hint_errors`. Meant for analyzing partially broken programs.
+    pub lenient_hints: bool,
+    /// Number of distinct pairs whose Pedersen hash is kept by the pedersen builtin runner, see
+    /// `HashBuiltinRunner::set_cache_capacity`. 0, the default, disables the cache.
+    pub pedersen_cache_capacity: usize,
+}
+
 pub struct CairoRunner {
     pub(crate) program: Program,
     layout: CairoLayout,
@@ -79,6 +112,8 @@ pub struct CairoRunner {
     pub relocated_memory: Vec<Option<Felt>>,
     pub relocated_trace: Option<Vec<RelocatedTraceEntry>>,
     pub exec_scopes: ExecutionScopes,
+    run_config: CairoRunConfig,
+    missing_builtin_bases: HashMap<String, Relocatable>,
 }
 
 impl CairoRunner {
@@ -86,6 +121,15 @@ impl CairoRunner {
         program: &Program,
         layout: &str,
         proof_mode: bool,
+    ) -> Result<CairoRunner, RunnerError> {
+        CairoRunner::new_with_config(program, layout, proof_mode, CairoRunConfig::default())
+    }
+
+    pub fn new_with_config(
+        program: &Program,
+        layout: &str,
+        proof_mode: bool,
+        run_config: CairoRunConfig,
     ) -> Result<CairoRunner, RunnerError> {
         let cairo_layout = match layout {
             "plain" => CairoLayout::plain_instance(),
@@ -97,6 +141,10 @@ impl CairoRunner {
             "all" => CairoLayout::all_instance(),
             name => return Err(RunnerError::InvalidLayoutName(name.to_string())),
         };
+        let mut exec_scopes = ExecutionScopes::new();
+        if let Some(seed) = run_config.hint_seed {
+            exec_scopes.insert_value(HINT_RNG, HintRng::seed_from_u64(seed));
+        }
         Ok(CairoRunner {
             program: program.clone(),
             layout: cairo_layout,
@@ -112,8 +160,10 @@ impl CairoRunner {
             original_steps: None,
             relocated_memory: Vec::new(),
             relocated_trace: None,
-            exec_scopes: ExecutionScopes::new(),
+            exec_scopes,
             execution_public_memory: if proof_mode { Some(Vec::new()) } else { None },
+            run_config,
+            missing_builtin_bases: HashMap::new(),
         })
     }
 
@@ -135,7 +185,17 @@ impl CairoRunner {
             String::from("ec_op"),
             String::from("keccak"),
         ];
-        if !is_subsequence(&self.program.builtins, &builtin_ordered_list) {
+        // Unknown builtins can't be ordered, so they are left out of the check when allowed
+        let known_builtins: Vec<String> = self
+            .program
+            .builtins
+            .iter()
+            .filter(|name| {
+                !self.run_config.allow_missing_builtins || builtin_ordered_list.contains(name)
+            })
+            .cloned()
+            .collect();
+        if !is_subsequence(&known_builtins, &builtin_ordered_list) {
             return Err(RunnerError::DisorderedBuiltins);
         };
         let mut builtin_runners = Vec::<(String, BuiltinRunner)>::new();
@@ -153,10 +213,9 @@ impl CairoRunner {
         if let Some(instance_def) = self.layout.builtins.pedersen.as_ref() {
             let included = self.program.builtins.contains(&"pedersen".to_string());
             if included || self.proof_mode {
-                builtin_runners.push((
-                    "pedersen".to_string(),
-                    HashBuiltinRunner::new(instance_def.ratio, included).into(),
-                ));
+                let mut hash_runner = HashBuiltinRunner::new(instance_def.ratio, included);
+                hash_runner.set_cache_capacity(self.run_config.pedersen_cache_capacity);
+                builtin_runners.push(("pedersen".to_string(), hash_runner.into()));
             }
         }
 
@@ -222,7 +281,9 @@ impl CairoRunner {
         let program_builtins: HashSet<&String> =
             self.program.builtins.iter().collect::<HashSet<&String>>();
         // Get the builtins that belong to the program but weren't inserted (those who dont belong to the instance)
-        if !program_builtins.is_subset(&inserted_builtins) {
+        if !self.run_config.allow_missing_builtins
+            && !program_builtins.is_subset(&inserted_builtins)
+        {
             return Err(RunnerError::NoBuiltinForInstance(
                 program_builtins
                     .difference(&inserted_builtins)
@@ -249,11 +310,14 @@ impl CairoRunner {
             String::from("keccak"),
         ];
 
-        fn initialize_builtin(name: &str, vm: &mut VirtualMachine) {
+        fn initialize_builtin(name: &str, vm: &mut VirtualMachine, pedersen_cache_capacity: usize) {
             match name {
-                "pedersen" => vm
-                    .builtin_runners
-                    .push((name.to_string(), HashBuiltinRunner::new(32, true).into())),
+                "pedersen" => {
+                    let mut hash_runner = HashBuiltinRunner::new(32, true);
+                    hash_runner.set_cache_capacity(pedersen_cache_capacity);
+                    vm.builtin_runners
+                        .push((name.to_string(), hash_runner.into()))
+                }
                 "range_check" => vm.builtin_runners.push((
                     name.to_string(),
                     RangeCheckBuiltinRunner::new(1, 8, true).into(),
@@ -281,12 +345,13 @@ impl CairoRunner {
             }
         }
 
+        let pedersen_cache_capacity = self.run_config.pedersen_cache_capacity;
         for builtin_name in &self.program.builtins {
-            initialize_builtin(builtin_name, vm);
+            initialize_builtin(builtin_name, vm, pedersen_cache_capacity);
         }
         for builtin_name in starknet_preset_builtins {
             if !self.program.builtins.contains(&builtin_name) {
-                initialize_builtin(&builtin_name, vm)
+                initialize_builtin(&builtin_name, vm, pedersen_cache_capacity)
             }
         }
         Ok(())
@@ -298,14 +363,52 @@ impl CairoRunner {
         vm: &mut VirtualMachine,
         program_base: Option<Relocatable>,
     ) {
+        vm.memory.skip_checks = self.run_config.skip_memory_checks;
         self.program_base = match program_base {
             Some(base) => Some(base),
             None => Some(vm.segments.add(&mut vm.memory)),
         };
         self.execution_base = Some(vm.segments.add(&mut vm.memory));
-        for (_key, builtin_runner) in vm.builtin_runners.iter_mut() {
+        for (name, builtin_runner) in vm.builtin_runners.iter_mut() {
             builtin_runner.initialize_segments(&mut vm.segments, &mut vm.memory);
+            if let Some(instances) = self.run_config.builtin_instance_hints.get(name) {
+                if let Some(segment) = vm.memory.data.get_mut(builtin_runner.base() as usize) {
+                    segment.reserve(instances * builtin_runner.cells_per_instance());
+                }
+            }
         }
+        if self.run_config.allow_missing_builtins {
+            for builtin_name in self.program.builtins.iter() {
+                if !vm
+                    .builtin_runners
+                    .iter()
+                    .any(|(name, _)| name == builtin_name)
+                {
+                    let base = vm.segments.add(&mut vm.memory);
+                    self.missing_builtin_bases
+                        .insert(builtin_name.clone(), base);
+                }
+            }
+        }
+    }
+
+    /// Loads the bytecode of another program into a new segment and returns its base, so that the
+    /// running program can jump into it (e.g. a bootloader running several tasks in one memory space).
+    /// Only the data is loaded, hints belonging to `program` are not registered in this runner.
+    pub fn load_additional_program(
+        &self,
+        program: &Program,
+        vm: &mut VirtualMachine,
+    ) -> Result<Relocatable, RunnerError> {
+        let base = vm.segments.add(&mut vm.memory);
+        vm.segments
+            .load_data(
+                &mut vm.memory,
+                &MaybeRelocatable::RelocatableValue(base),
+                &program.data,
+            )
+            .map_err(RunnerError::MemoryInitializationError)?;
+        Ok(base)
     }
 
     fn initialize_state(
@@ -376,8 +479,24 @@ impl CairoRunner {
         vm: &mut VirtualMachine,
     ) -> Result<Relocatable, RunnerError> {
         let mut stack = Vec::new();
-        for (_name, builtin_runner) in vm.builtin_runners.iter() {
-            stack.append(&mut builtin_runner.initial_stack());
+        if self.missing_builtin_bases.is_empty() {
+            for (_name, builtin_runner) in vm.builtin_runners.iter() {
+                stack.append(&mut builtin_runner.initial_stack());
+            }
+        } else {
+            // Follow the program's declaration order, so that the missing builtins' segments
+            // are passed in the right argument slots
+            for builtin_name in self.program.builtins.iter() {
+                if let Some(base) = self.missing_builtin_bases.get(builtin_name) {
+                    stack.push(MaybeRelocatable::from(*base));
+                } else if let Some((_, builtin_runner)) = vm
+                    .builtin_runners
+                    .iter()
+                    .find(|(name, _)| name == builtin_name)
+                {
+                    stack.append(&mut builtin_runner.initial_stack());
+                }
+            }
         }
         //Different process if proof_mode is enabled
         if self.proof_mode {
@@ -424,6 +543,7 @@ impl CairoRunner {
 
     pub fn initialize_vm(&mut self, vm: &mut VirtualMachine) -> Result<(), RunnerError> {
         vm.run_context.pc = *self.initial_pc.as_ref().ok_or(RunnerError::NoPC)?;
+        vm.set_lenient_hints(self.run_config.lenient_hints);
         vm.run_context.ap = self.initial_ap.as_ref().ok_or(RunnerError::NoAP)?.offset;
         vm.run_context.fp = self.initial_fp.as_ref().ok_or(RunnerError::NoFP)?.offset;
         vm._program_base = Some(MaybeRelocatable::from(
@@ -531,6 +651,7 @@ impl CairoRunner {
                 &self.program.constants,
             )?;
         }
+        vm.mark_run_ended();
         Ok(())
     }
 
@@ -555,6 +676,9 @@ impl CairoRunner {
                 &hint_data_dictionary,
                 &self.program.constants,
             )?;
+            if self.final_pc.as_ref() == Some(&vm.run_context.pc) {
+                vm.mark_run_ended();
+            }
         }
 
         Ok(())
@@ -630,6 +754,12 @@ impl CairoRunner {
         Ok(())
     }
 
+    /// Returns the length of the execution trace, one entry per executed step. The relocated
+    /// trace has exactly this many entries, so it can be used to preallocate prover buffers.
+    pub fn get_trace_length(&self, vm: &VirtualMachine) -> usize {
+        vm.current_step
+    }
+
     /// Count the number of holes present in the segments.
     pub fn get_memory_holes(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let program_addresses =
@@ -730,11 +860,38 @@ impl CairoRunner {
                 self.run_until_next_power_of_2(vm, hint_processor)?;
             }
         }
+        if self.proof_mode {
+            self.check_builtins_usage(vm, disable_trace_padding)?;
+        }
 
         self.run_ended = true;
         Ok(())
     }
 
+    /// Returns every memory cell as an (address, value) pair, with addresses and relocatable
+    /// values relocated as in `relocate`. Holes are skipped. The segment sizes must have been
+    /// computed, as done by `end_run`.
+    pub fn get_relocated_memory_pairs(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<Vec<(usize, Felt)>, MemoryError> {
+        let relocation_table = vm.segments.relocate_segments()?;
+        let mut pairs = Vec::new();
+        for (index, segment) in vm.memory.data.iter().enumerate() {
+            for (seg_offset, element) in segment.iter().enumerate() {
+                if let Some(elem) = element {
+                    let relocated_addr = relocate_address(
+                        Relocatable::from((index as isize, seg_offset)),
+                        &relocation_table,
+                    )?;
+                    let value = relocate_value(elem.clone(), &relocation_table)?;
+                    pairs.push((relocated_addr, value));
+                }
+            }
+        }
+        Ok(pairs)
+    }
+
     /// Relocates the VM's memory, turning bidimensional indexes into contiguous numbers, and values
     /// into Felts. Uses the relocation_table to asign each index a number according to the value
     /// on its segment number.
@@ -792,14 +949,26 @@ impl CairoRunner {
         Ok(())
     }
 
-    pub fn relocate(&mut self, vm: &mut VirtualMachine) -> Result<(), TraceError> {
+    /// Returns the relocation table: the absolute address at which each segment starts once
+    /// relocated. Computes the effective segment sizes if they weren't computed yet.
+    pub fn get_relocation_table(&self, vm: &mut VirtualMachine) -> Result<Vec<usize>, MemoryError> {
         vm.segments.compute_effective_sizes(&vm.memory);
-        // relocate_segments can fail if compute_effective_sizes is not called before.
-        // The expect should be unreachable.
-        let relocation_table = vm
-            .segments
-            .relocate_segments()
-            .expect("compute_effective_sizes called but relocate_memory still returned error");
+        vm.segments.relocate_segments()
+    }
+
+    /// Formats the relocation table with one `segment N -> base M` line per segment, for
+    /// debugging.
+    pub fn print_relocation_table(&self, vm: &mut VirtualMachine) -> Result<String, MemoryError> {
+        Ok(self
+            .get_relocation_table(vm)?
+            .iter()
+            .enumerate()
+            .map(|(index, base)| format!("segment {index} -> base {base}\n"))
+            .collect())
+    }
+
+    pub fn relocate(&mut self, vm: &mut VirtualMachine) -> Result<(), TraceError> {
+        let relocation_table = self.get_relocation_table(vm)?;
         if let Err(memory_error) = self.relocate_memory(vm, &relocation_table) {
             return Err(TraceError::MemoryError(memory_error));
         }
@@ -809,6 +978,28 @@ impl CairoRunner {
         Ok(())
     }
 
+    /// Collects the private input of the AIR prover: the given trace and memory file paths,
+    /// and the input cells of each builtin used by the run (including the ECDSA signatures).
+    /// Builtins without private input, like output, are left out.
+    pub fn get_air_private_input(
+        &self,
+        vm: &VirtualMachine,
+        trace_path: &Path,
+        memory_path: &Path,
+    ) -> AirPrivateInput {
+        let builtins = vm
+            .builtin_runners
+            .iter()
+            .filter(|(_, builtin)| !matches!(builtin, BuiltinRunner::Output(_)))
+            .map(|(name, builtin)| (name.clone(), builtin.air_private_input(&vm.memory)))
+            .collect::<BTreeMap<_, _>>();
+        AirPrivateInput {
+            trace_path: trace_path.to_path_buf(),
+            memory_path: memory_path.to_path_buf(),
+            builtins,
+        }
+    }
+
     pub fn get_builtin_segments_info(
         &self,
         vm: &VirtualMachine,
@@ -830,6 +1021,25 @@ impl CairoRunner {
         Ok(builtin_segments)
     }
 
+    /// Returns the name, segment index and used size of each builtin, in the order they were initialized.
+    /// Requires the segment sizes to have been computed beforehand.
+    pub fn get_builtin_segment_info(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<Vec<(&'static str, usize, usize)>, RunnerError> {
+        let mut builtin_segment_info = Vec::with_capacity(vm.builtin_runners.len());
+
+        for (_, builtin) in &vm.builtin_runners {
+            let (name, (base, _)) = builtin.get_memory_segment_addresses();
+            let index: usize = base
+                .try_into()
+                .map_err(|_| MemoryError::AddressInTemporarySegment(base))?;
+            builtin_segment_info.push((name, index, builtin.get_used_cells(vm)?));
+        }
+
+        Ok(builtin_segment_info)
+    }
+
     pub fn get_execution_resources(
         &self,
         vm: &VirtualMachine,
@@ -944,13 +1154,34 @@ impl CairoRunner {
             let (_, size) = builtin_runner
                 .get_used_cells_and_allocated_size(vm)
                 .map_err(RunnerError::FinalizeSegements)?;
-            vm.segments
-                .finalize(Some(size), builtin_runner.base() as usize, None)
+            let public_memory = match builtin_runner {
+                BuiltinRunner::Output(output) => Some(output.get_public_memory(size)?),
+                _ => None,
+            };
+            vm.segments.finalize(
+                Some(size),
+                builtin_runner.base() as usize,
+                public_memory.as_ref(),
+            )
         }
         self.segments_finalized = true;
         Ok(())
     }
 
+    /// Returns the public memory of the run as (relocated address, page id) pairs. It includes the
+    /// program segment, the execution public memory and the builtins' public memory, so
+    /// finalize_segments() must be called before this method.
+    pub fn get_public_memory_addresses(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<Vec<(usize, usize)>, RunnerError> {
+        if !self.segments_finalized {
+            return Err(RunnerError::SegmentsNotFinalized);
+        }
+        let relocation_table = vm.segments.relocate_segments()?;
+        Ok(vm.segments.get_public_memory_addresses(&relocation_table)?)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn run_from_entrypoint(
         &mut self,
@@ -979,6 +1210,45 @@ impl CairoRunner {
         Ok(())
     }
 
+    // Checks that each builtin's usage is consistent with the number of steps and its ratio in
+    // the layout: the steps reach the builtin's minimum and the builtin didn't use more cells
+    // than the ones allocated for steps / ratio instances. In proof mode, unless the trace padding
+    // is disabled, the steps must also be a multiple of the ratio. Builtins without a ratio
+    // (output) aren't checked. The segment sizes must have been computed.
+    pub fn check_builtins_usage(
+        &self,
+        vm: &VirtualMachine,
+        disable_trace_padding: bool,
+    ) -> Result<(), RunnerError> {
+        for (name, builtin) in vm.builtin_runners.iter() {
+            let ratio = match builtin.ratio() {
+                Some(ratio) if ratio > 0 => ratio as usize,
+                _ => continue,
+            };
+            let min_step = ratio * builtin.instances_per_component() as usize;
+            if vm.current_step < min_step {
+                return Err(RunnerError::MinStepNotReached(min_step, name.clone()));
+            }
+            let used = builtin.get_used_cells(vm)?;
+            let allocated = builtin.cells_per_instance() * (vm.current_step / ratio);
+            if used > allocated {
+                return Err(RunnerError::InsufficientAllocatedCells(
+                    name.clone(),
+                    used,
+                    allocated,
+                ));
+            }
+            if self.proof_mode && !disable_trace_padding && vm.current_step % ratio != 0 {
+                return Err(RunnerError::StepsNotMultipleOfRatio(
+                    vm.current_step,
+                    name.clone(),
+                    ratio as u32,
+                ));
+            }
+        }
+        Ok(())
+    }
+
     // Returns Ok(()) if there are enough allocated cells for the builtins.
     // If not, the number of steps should be increased or a different layout should be used.
     pub fn check_used_cells(&self, vm: &VirtualMachine) -> Result<(), VirtualMachineError> {
@@ -1071,6 +1341,12 @@ impl CairoRunner {
                 .find(|(name, _builtin)| builtin_name == name);
 
             match builtin_runner {
+                // Skip the returned pointer of a builtin without a runner
+                None if self.missing_builtin_bases.contains_key(builtin_name) => {
+                    pointer = pointer
+                        .sub_usize(1)
+                        .map_err(|_| RunnerError::InvalidStopPointer(builtin_name.to_string()))?;
+                }
                 None => return Err(RunnerError::MissingBuiltin(builtin_name.to_string())),
                 Some((_, builtin)) => {
                     let (new_pointer, _) = builtin.final_stack(vm, pointer)?;
@@ -1133,18 +1409,34 @@ pub struct ExecutionResources {
 mod tests {
     use super::*;
     use crate::{
-        hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
+        hint_processor::builtin_hint_processor::{
+            builtin_hint_processor_definition::{BuiltinHintProcessor, HintFunc},
+            hint_code,
+            hint_rng::get_hint_rng,
+            math_utils::is_nn,
+        },
         relocatable,
-        serde::deserialize_program::{Identifier, ReferenceManager},
+        serde::deserialize_program::{ApTracking, Identifier, ReferenceManager},
         types::instance_definitions::bitwise_instance_def::BitwiseInstanceDef,
         utils::test_utils::*,
-        vm::{trace::trace_entry::TraceEntry, vm_memory::memory::Memory},
+        vm::{
+            errors::hint_errors::HintError,
+            runners::air_private_input::{PrivateInput, SignatureInput},
+            trace::trace_entry::TraceEntry,
+            vm_memory::memory::Memory,
+        },
     };
     use felt::felt_str;
     use num_traits::One;
+    use rand_core::RngCore;
     use std::{
         collections::{HashMap, HashSet},
         path::Path,
+        rc::Rc,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
     };
 
     #[test]
@@ -1198,6 +1490,115 @@ mod tests {
         let _cairo_runner = cairo_runner!(program);
     }
 
+    #[test]
+    fn initialize_builtins_unknown_builtin_rejected_by_default() {
+        let program = program!["output", "poseidon"];
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::DisorderedBuiltins)
+        );
+    }
+
+    #[test]
+    fn initialize_builtins_layout_missing_builtin_rejected_by_default() {
+        let program = program!["output", "bitwise"];
+        let cairo_runner = cairo_runner!(program, "plain");
+        let mut vm = vm!();
+        assert!(matches!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::NoBuiltinForInstance(_, _))
+        ));
+    }
+
+    #[test]
+    fn hint_seed_makes_hint_rng_reproducible() {
+        let program = program!(main = Some(0),);
+        let run_config = CairoRunConfig {
+            hint_seed: Some(1234),
+            ..Default::default()
+        };
+        let draw = |run_config: &CairoRunConfig| {
+            let mut cairo_runner =
+                CairoRunner::new_with_config(&program, "all", false, run_config.clone()).unwrap();
+            let rng = get_hint_rng(&mut cairo_runner.exec_scopes).unwrap();
+            (rng.next_u64(), rng.next_u64())
+        };
+        assert_eq!(draw(&run_config), draw(&run_config));
+        assert_ne!(
+            draw(&run_config),
+            draw(&CairoRunConfig {
+                hint_seed: Some(4321),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn hint_seed_same_run_for_random_ec_point_program() {
+        let program = Program::from_file(
+            Path::new("cairo_programs/random_ec_point.json"),
+            Some("main"),
+        )
+        .expect("Call to `Program::from_file()` failed.");
+        let run = |hint_seed: u64| {
+            let mut hint_processor = BuiltinHintProcessor::new_empty();
+            let mut cairo_runner = CairoRunner::new_with_config(
+                &program,
+                "all",
+                false,
+                CairoRunConfig {
+                    hint_seed: Some(hint_seed),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            let mut vm = vm!(true);
+            let end = cairo_runner.initialize(&mut vm).unwrap();
+            cairo_runner
+                .run_until_pc(end, &mut vm, &mut hint_processor)
+                .unwrap();
+            cairo_runner.relocate(&mut vm).unwrap();
+            (cairo_runner.relocated_memory, cairo_runner.relocated_trace)
+        };
+        assert_eq!(run(1234), run(1234));
+        //The random point the ec_op hint adds is written to memory
+        assert_ne!(run(1234).0, run(4321).0);
+    }
+
+    #[test]
+    fn initialize_unknown_builtin_allowed() {
+        let program = program!(
+            builtins = vec![String::from("output"), String::from("poseidon")],
+            main = Some(0),
+        );
+        let mut cairo_runner = CairoRunner::new_with_config(
+            &program,
+            "all",
+            false,
+            CairoRunConfig {
+                allow_missing_builtins: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let mut vm = vm!();
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        assert_eq!(vm.builtin_runners.len(), 1);
+        assert_eq!(vm.builtin_runners[0].0, "output");
+
+        cairo_runner.initialize_segments(&mut vm, None);
+        // program: 0, execution: 1, output: 2, poseidon: 3
+        assert_eq!(
+            cairo_runner.missing_builtin_bases,
+            HashMap::from([(String::from("poseidon"), relocatable!(3, 0))])
+        );
+
+        cairo_runner.initialize_main_entrypoint(&mut vm).unwrap();
+        check_memory![vm.memory, ((1, 0), (2, 0)), ((1, 1), (3, 0))];
+    }
+
     #[test]
     fn initialize_segments_with_base() {
         //This test works with basic Program definition, will later be updated to use Program::new() when fully defined
@@ -1259,6 +1660,30 @@ mod tests {
         assert_eq!(vm.segments.num_segments, 3);
     }
 
+    #[test]
+    fn load_additional_programs_in_distinct_segments() {
+        let program = program!(data = vec![mayberelocatable!(1)],);
+        let mut cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        cairo_runner.initialize_segments(&mut vm, None);
+
+        let program_a = program!(data = vec![mayberelocatable!(2), mayberelocatable!(3)],);
+        let program_b = program!(data = vec![mayberelocatable!(4)],);
+        let base_a = cairo_runner
+            .load_additional_program(&program_a, &mut vm)
+            .unwrap();
+        let base_b = cairo_runner
+            .load_additional_program(&program_b, &mut vm)
+            .unwrap();
+
+        assert_eq!(base_a, relocatable!(2, 0));
+        assert_eq!(base_b, relocatable!(3, 0));
+        assert_ne!(Some(base_a), cairo_runner.program_base);
+        check_memory![vm.memory, ((2, 0), 2), ((2, 1), 3), ((3, 0), 4)];
+        assert_eq!(vm.memory.data[2].len(), 2);
+        assert_eq!(vm.memory.data[3].len(), 1);
+    }
+
     #[test]
     fn initialize_state_empty_data_and_stack() {
         //This test works with basic Program definition, will later be updated to use Program::new() when fully defined
@@ -1468,6 +1893,189 @@ mod tests {
             .contains(&Relocatable::from((1, 0))));
     }
 
+    #[test]
+    fn builtin_instance_hints_preallocate_segments() {
+        let program = Program::from_file(
+            Path::new("cairo_programs/bitwise_recursion.json"),
+            Some("main"),
+        )
+        .expect("Call to `Program::from_file()` failed.");
+        let run = |run_config: CairoRunConfig| {
+            let mut hint_processor = BuiltinHintProcessor::new_empty();
+            let mut cairo_runner =
+                CairoRunner::new_with_config(&program, "all", false, run_config).unwrap();
+            let mut vm = vm!();
+            let end = cairo_runner.initialize(&mut vm).unwrap();
+            let bitwise_base = vm.get_builtin_runner("bitwise").unwrap().base() as usize;
+            let capacity = vm.memory.data[bitwise_base].capacity();
+            cairo_runner
+                .run_until_pc(end, &mut vm, &mut hint_processor)
+                .unwrap();
+            cairo_runner.relocate(&mut vm).unwrap();
+            (capacity, cairo_runner.relocated_memory)
+        };
+
+        let (capacity_without_hints, memory_without_hints) = run(CairoRunConfig::default());
+        let (capacity_with_hints, memory_with_hints) = run(CairoRunConfig {
+            builtin_instance_hints: HashMap::from([(String::from("bitwise"), 100)]),
+            ..Default::default()
+        });
+        assert_eq!(capacity_without_hints, 0);
+        // Each bitwise instance spans 5 cells
+        assert!(capacity_with_hints >= 500);
+        assert_eq!(memory_with_hints, memory_without_hints);
+    }
+
+    #[test]
+    fn skip_memory_checks_same_result_for_trusted_program() {
+        let program = Program::from_file(Path::new("cairo_programs/math_cmp.json"), Some("main"))
+            .expect("Call to `Program::from_file()` failed.");
+        let run = |skip_memory_checks: bool| {
+            let mut hint_processor = BuiltinHintProcessor::new_empty();
+            let mut cairo_runner = CairoRunner::new_with_config(
+                &program,
+                "all",
+                false,
+                CairoRunConfig {
+                    skip_memory_checks,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            let mut vm = vm!(true);
+            let end = cairo_runner.initialize(&mut vm).unwrap();
+            assert_eq!(vm.memory.skip_checks, skip_memory_checks);
+            cairo_runner
+                .run_until_pc(end, &mut vm, &mut hint_processor)
+                .unwrap();
+            cairo_runner.relocate(&mut vm).unwrap();
+            (cairo_runner.relocated_memory, cairo_runner.relocated_trace)
+        };
+        assert_eq!(run(true), run(false));
+    }
+
+    #[test]
+    fn lenient_hints_run_completes_and_reports_failing_hint() {
+        let program = Program::from_file(Path::new("cairo_programs/math_cmp.json"), Some("main"))
+            .expect("Call to `Program::from_file()` failed.");
+        let run = |lenient_hints: bool| {
+            //The first is_nn hint fails, the later ones run normally. Its memory[ap] output is
+            //then taken as zero, which is the right answer for is_nn(0)
+            let failed = Arc::new(AtomicBool::new(false));
+            let fail_once = HintFunc(Box::new(
+                move |vm: &mut VirtualMachine,
+                      _: &mut ExecutionScopes,
+                      ids_data: &HashMap<String, HintReference>,
+                      ap_tracking: &ApTracking,
+                      _: &HashMap<String, Felt>| {
+                    if failed.swap(true, Ordering::Relaxed) {
+                        is_nn(vm, ids_data, ap_tracking)
+                    } else {
+                        Err(HintError::CustomHint(String::from("broken is_nn")))
+                    }
+                },
+            ));
+            let mut hint_processor = BuiltinHintProcessor::new_empty();
+            hint_processor.add_hint(String::from(hint_code::IS_NN), Rc::new(fail_once));
+            let mut cairo_runner = CairoRunner::new_with_config(
+                &program,
+                "all",
+                false,
+                CairoRunConfig {
+                    lenient_hints,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            let mut vm = vm!();
+            let end = cairo_runner.initialize(&mut vm).unwrap();
+            let result = cairo_runner.run_until_pc(end, &mut vm, &mut hint_processor);
+            let hint_errors: Vec<(isize, usize, String)> = vm
+                .get_hint_errors()
+                .iter()
+                .map(|(pc, hint_index, error)| (pc.segment_index, *hint_index, error.to_string()))
+                .collect();
+            (result, hint_errors)
+        };
+
+        let (result, hint_errors) = run(true);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            hint_errors,
+            vec![(
+                0,
+                0,
+                HintError::CustomHint(String::from("broken is_nn")).to_string()
+            )]
+        );
+
+        let (result, hint_errors) = run(false);
+        assert!(matches!(result, Err(VirtualMachineError::Hint(0, _))));
+        assert!(hint_errors.is_empty());
+    }
+
+    #[test]
+    fn pedersen_cache_capacity_applied_to_hash_runner() {
+        let program =
+            Program::from_file(Path::new("cairo_programs/pedersen_test.json"), Some("main"))
+                .expect("Call to `Program::from_file()` failed.");
+        let run = |pedersen_cache_capacity: usize| {
+            let mut hint_processor = BuiltinHintProcessor::new_empty();
+            let mut cairo_runner = CairoRunner::new_with_config(
+                &program,
+                "all",
+                false,
+                CairoRunConfig {
+                    pedersen_cache_capacity,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            let mut vm = vm!();
+            let end = cairo_runner.initialize(&mut vm).unwrap();
+            let capacity = match vm.get_builtin_runner("pedersen") {
+                Some(BuiltinRunner::Hash(hash)) => {
+                    hash.cache.borrow().as_ref().map(|cache| cache.capacity)
+                }
+                _ => panic!("Expected a pedersen builtin runner"),
+            };
+            cairo_runner
+                .run_until_pc(end, &mut vm, &mut hint_processor)
+                .unwrap();
+            cairo_runner.relocate(&mut vm).unwrap();
+            (capacity, cairo_runner.relocated_memory)
+        };
+        let (uncached_capacity, uncached_memory) = run(0);
+        let (cached_capacity, cached_memory) = run(16);
+        assert_eq!(uncached_capacity, None);
+        assert_eq!(cached_capacity, Some(16));
+        assert_eq!(cached_memory, uncached_memory);
+    }
+
+    #[test]
+    fn get_trace_length_equals_steps_executed() {
+        let program = Program::from_file(Path::new("cairo_programs/fibonacci.json"), Some("main"))
+            .expect("Call to `Program::from_file()` failed.");
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut cairo_runner = cairo_runner!(program);
+        let mut vm = vm!(true);
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        assert_eq!(cairo_runner.get_trace_length(&vm), 0);
+        cairo_runner
+            .run_until_pc(end, &mut vm, &mut hint_processor)
+            .unwrap();
+        assert_eq!(cairo_runner.get_trace_length(&vm), vm.current_step);
+        assert_eq!(
+            cairo_runner.get_trace_length(&vm),
+            vm.trace.as_ref().unwrap().len()
+        );
+        cairo_runner.relocate(&mut vm).unwrap();
+        assert_eq!(
+            cairo_runner.get_trace_length(&vm),
+            cairo_runner.relocated_trace.as_ref().unwrap().len()
+        );
+    }
+
     #[test]
     fn initialize_vm_no_builtins() {
         //This test works with basic Program definition, will later be updated to use Program::new() when fully defined
@@ -2195,6 +2803,154 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_relocation_table_three_segments() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.memory = memory![
+            ((0, 0), 1),
+            ((0, 1), 2),
+            ((0, 2), 3),
+            ((1, 4), 4),
+            ((2, 0), 5),
+            ((2, 1), 6)
+        ];
+
+        assert_eq!(
+            cairo_runner.get_relocation_table(&mut vm),
+            Ok(vec![1, 4, 9])
+        );
+        assert_eq!(vm.segments.segment_used_sizes, Some(vec![3, 5, 2]));
+    }
+
+    #[test]
+    fn print_relocation_table_three_segments() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.memory = memory![((0, 2), 3), ((1, 4), 4), ((2, 1), 6)];
+
+        assert_eq!(
+            cairo_runner.print_relocation_table(&mut vm),
+            Ok(String::from(
+                "segment 0 -> base 1\nsegment 1 -> base 4\nsegment 2 -> base 9\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn get_air_private_input_with_signature_and_pedersen() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        let mut signature = SignatureBuiltinRunner::new(&EcdsaInstanceDef::default(), true);
+        let mut hash = HashBuiltinRunner::new(8, true);
+        let mut output = OutputBuiltinRunner::new(true);
+        signature.initialize_segments(&mut vm.segments, &mut vm.memory);
+        hash.initialize_segments(&mut vm.segments, &mut vm.memory);
+        output.initialize_segments(&mut vm.segments, &mut vm.memory);
+        let r = felt_str!(
+            "1839793652349538280924927302501143912227271479439798783640887258675143576352"
+        );
+        let s = felt_str!(
+            "1819432147005223164874083361865404672584671743718628757598322238853218813979"
+        );
+        signature
+            .add_signature(Relocatable::from((0, 0)), &(r.clone(), s))
+            .unwrap();
+        let pubkey = felt_str!(
+            "874739451078007766457464989774322083649278607533249481151382481072868806602"
+        );
+        vm.memory = memory![
+            ((0, 1), 2),
+            ((1, 0), 3),
+            ((1, 1), 4),
+            ((1, 2), 5),
+            ((2, 0), 7)
+        ];
+        vm.memory
+            .insert_value(&Relocatable::from((0, 0)), pubkey.clone())
+            .unwrap();
+        vm.builtin_runners = vec![
+            ("ecdsa".to_string(), signature.into()),
+            ("pedersen".to_string(), hash.into()),
+            ("output".to_string(), output.into()),
+        ];
+
+        let air_private_input = cairo_runner.get_air_private_input(
+            &vm,
+            Path::new("trace.bin"),
+            Path::new("memory.bin"),
+        );
+        assert_eq!(air_private_input.trace_path, Path::new("trace.bin"));
+        assert_eq!(air_private_input.memory_path, Path::new("memory.bin"));
+        assert_eq!(
+            air_private_input.builtins.keys().collect::<Vec<_>>(),
+            vec!["ecdsa", "pedersen"]
+        );
+        assert_eq!(
+            air_private_input.builtins["ecdsa"],
+            vec![PrivateInput {
+                index: 0,
+                values: vec![
+                    ("pubkey".to_string(), pubkey),
+                    ("msg".to_string(), Felt::new(2)),
+                ],
+                signature_input: Some(SignatureInput {
+                    r,
+                    w: felt_str!(
+                        "816304504594032531021318450651659450204105061515482111952393924585064221841"
+                    ),
+                }),
+            }]
+        );
+        assert_eq!(
+            air_private_input.builtins["pedersen"],
+            vec![PrivateInput {
+                index: 0,
+                values: vec![
+                    ("x".to_string(), Felt::new(3)),
+                    ("y".to_string(), Felt::new(4))
+                ],
+                signature_input: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn get_relocated_memory_pairs_skips_holes() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.memory = memory![
+            ((0, 0), 4613515612218425347_i64),
+            ((0, 1), 5),
+            ((0, 2), 2345108766317314046_i64),
+            ((1, 0), (2, 0)),
+            ((1, 1), (3, 0)),
+            ((1, 5), 5)
+        ];
+        // Segments 2 and 3 are empty
+        vm.memory.data.resize(4, Vec::new());
+        assert_eq!(
+            cairo_runner.get_relocated_memory_pairs(&vm),
+            Err(MemoryError::EffectiveSizesNotCalled)
+        );
+        vm.segments.compute_effective_sizes(&vm.memory);
+        assert_eq!(
+            cairo_runner.get_relocated_memory_pairs(&vm),
+            Ok(vec![
+                (1, Felt::new(4613515612218425347_i64)),
+                (2, Felt::new(5)),
+                (3, Felt::new(2345108766317314046_i64)),
+                (4, Felt::new(10)),
+                (5, Felt::new(10)),
+                (9, Felt::new(5)),
+            ])
+        );
+    }
+
     #[test]
     /*Memory from this test is taken from a cairo program execution
     Program used:
@@ -2848,6 +3604,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_run_ended_after_step_limit_and_full_run() {
+        //Same program as in run_for_steps
+        let program = program!(
+            builtins = vec![String::from("range_check")],
+            data = vec_data!(
+                (4612671182993129469_i64),
+                (5189976364521848832_i64),
+                (18446744073709551615_i128),
+                (5199546496550207487_i64),
+                (4612389712311386111_i64),
+                (5198983563776393216_i64),
+                (2),
+                (2345108766317314046_i64),
+                (5191102247248822272_i64),
+                (5189976364521848832_i64),
+                (7),
+                (1226245742482522112_i64),
+                ((
+                    "3618502788666131213697322783095070105623107215331596699973092056135872020470",
+                    10
+                )),
+                (2345108766317314046_i64)
+            ),
+            main = Some(8),
+        );
+
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut cairo_runner = cairo_runner!(&program);
+
+        let mut vm = vm!(true);
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        cairo_runner.initialize_segments(&mut vm, None);
+
+        let end = cairo_runner.initialize_main_entrypoint(&mut vm).unwrap();
+        cairo_runner.initialize_vm(&mut vm).unwrap();
+
+        // Full takes 10 steps.
+        assert_eq!(
+            cairo_runner.run_for_steps(8, &mut vm, &mut hint_processor),
+            Ok(())
+        );
+        assert!(!vm.is_run_ended());
+        assert_eq!(
+            cairo_runner.run_until_pc(end, &mut vm, &mut hint_processor),
+            Ok(())
+        );
+        assert!(vm.is_run_ended());
+    }
+
     #[test]
     /*Program used:
     %builtins range_check
@@ -3276,6 +4082,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_builtin_segment_info_pedersen_and_range_check() {
+        let program = program!["pedersen", "range_check"];
+
+        let mut cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        cairo_runner.initialize_segments(&mut vm, None);
+        vm.segments.segment_used_sizes = Some(vec![0, 0, 6, 2]);
+        assert_eq!(
+            cairo_runner.get_builtin_segment_info(&vm),
+            Ok(vec![("pedersen", 2, 6), ("range_check", 3, 2)]),
+        );
+    }
+
+    #[test]
+    fn get_builtin_segment_info_missing_used_sizes() {
+        let program = program!["range_check"];
+
+        let mut cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        cairo_runner.initialize_segments(&mut vm, None);
+        assert_eq!(
+            cairo_runner.get_builtin_segment_info(&vm),
+            Err(RunnerError::MemoryError(
+                MemoryError::MissingSegmentUsedSizes
+            )),
+        );
+    }
+
     #[test]
     fn get_execution_resources_trace_not_enabled() {
         let program = program!();
@@ -3472,6 +4311,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn finalize_segments_includes_output_pages_in_public_memory() {
+        let mut program = program!();
+        program.data = vec_data![(1), (2)];
+        let mut cairo_runner = cairo_runner!(program, "plain", true);
+        cairo_runner.program_base = Some(Relocatable::from((0, 0)));
+        cairo_runner.execution_base = Some(Relocatable::from((1, 0)));
+        cairo_runner.execution_public_memory = Some(Vec::new());
+        cairo_runner.run_ended = true;
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        output_builtin.add_page(1, (2, 0).into(), 2).unwrap();
+        output_builtin.add_page(2, (2, 2).into(), 1).unwrap();
+        vm.builtin_runners
+            .push((String::from("output"), output_builtin.into()));
+        for offset in 0..4 {
+            vm.insert_value(&(2, offset).into(), Felt::new(offset))
+                .unwrap();
+        }
+        vm.segments.compute_effective_sizes(&vm.memory);
+
+        assert_eq!(cairo_runner.finalize_segments(&mut vm), Ok(()));
+        assert_eq!(vm.segments.segment_sizes.get(&2), Some(&4));
+        assert_eq!(
+            vm.segments.public_memory_offsets.get(&2),
+            Some(&vec![(0, 1), (1, 1), (2, 2), (3, 0)])
+        );
+    }
+
+    #[test]
+    fn get_public_memory_addresses_includes_program_and_output_pages() {
+        let mut program = program!();
+        program.data = vec_data![(1), (2)];
+        let mut cairo_runner = cairo_runner!(program, "plain", true);
+        cairo_runner.program_base = Some(Relocatable::from((0, 0)));
+        cairo_runner.execution_base = Some(Relocatable::from((1, 0)));
+        cairo_runner.execution_public_memory = Some(Vec::new());
+        cairo_runner.run_ended = true;
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        output_builtin.add_page(1, (2, 0).into(), 2).unwrap();
+        vm.builtin_runners
+            .push((String::from("output"), output_builtin.into()));
+        for offset in 0..3 {
+            vm.insert_value(&(2, offset).into(), Felt::new(offset))
+                .unwrap();
+        }
+        vm.segments.compute_effective_sizes(&vm.memory);
+        assert_eq!(
+            cairo_runner.get_public_memory_addresses(&vm),
+            Err(RunnerError::SegmentsNotFinalized)
+        );
+
+        cairo_runner.finalize_segments(&mut vm).unwrap();
+        // program: 1-2, execution: empty, output: 3-5
+        assert_eq!(
+            cairo_runner.get_public_memory_addresses(&vm),
+            Ok(vec![(1, 0), (2, 0), (3, 1), (4, 1), (5, 0)])
+        );
+    }
+
     /// Test that ensures get_perm_range_check_limits() returns an error when
     /// trace is not enabled.
     #[test]
@@ -3649,6 +4553,87 @@ mod tests {
         assert_eq!(Some(relocatable!(1, 2)), cairo_runner.get_initial_fp());
     }
 
+    #[test]
+    fn check_builtins_usage_over_used_builtin_in_proof_mode() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program, "all", true);
+        let mut vm = vm!();
+        vm.builtin_runners = vec![(
+            "range_check".to_string(),
+            RangeCheckBuiltinRunner::new(8, 8, true).into(),
+        )];
+        //8 steps allocate a single range check instance, but three were used
+        vm.current_step = 8;
+        vm.segments.segment_used_sizes = Some(vec![3]);
+        assert_eq!(
+            cairo_runner.check_builtins_usage(&vm, false),
+            Err(RunnerError::InsufficientAllocatedCells(
+                "range_check".to_string(),
+                3,
+                1
+            ))
+        );
+        //20 steps allocate 20 / 8 = 2 instances
+        vm.current_step = 20;
+        assert_eq!(
+            cairo_runner.check_builtins_usage(&vm, true),
+            Err(RunnerError::InsufficientAllocatedCells(
+                "range_check".to_string(),
+                3,
+                2
+            ))
+        );
+        vm.current_step = 24;
+        assert_eq!(cairo_runner.check_builtins_usage(&vm, false), Ok(()));
+    }
+
+    #[test]
+    fn check_builtins_usage_min_step_not_reached() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program, "all", true);
+        let mut vm = vm!();
+        vm.builtin_runners = vec![(
+            "range_check".to_string(),
+            RangeCheckBuiltinRunner::new(8, 8, true).into(),
+        )];
+        vm.current_step = 4;
+        vm.segments.segment_used_sizes = Some(vec![0]);
+        assert_eq!(
+            cairo_runner.check_builtins_usage(&vm, false),
+            Err(RunnerError::MinStepNotReached(8, "range_check".to_string()))
+        );
+    }
+
+    #[test]
+    fn check_builtins_usage_steps_not_multiple_of_ratio() {
+        let program = program!();
+        let mut vm = vm!();
+        vm.builtin_runners = vec![(
+            "range_check".to_string(),
+            RangeCheckBuiltinRunner::new(8, 8, true).into(),
+        )];
+        vm.current_step = 12;
+        vm.segments.segment_used_sizes = Some(vec![1]);
+        assert_eq!(
+            cairo_runner!(program, "all", true).check_builtins_usage(&vm, false),
+            Err(RunnerError::StepsNotMultipleOfRatio(
+                12,
+                "range_check".to_string(),
+                8
+            ))
+        );
+        //Without trace padding the trace is left as run
+        assert_eq!(
+            cairo_runner!(program, "all", true).check_builtins_usage(&vm, true),
+            Ok(())
+        );
+        //Outside proof mode the steps don't have to be a multiple of the ratio
+        assert_eq!(
+            cairo_runner!(program, "all", false).check_builtins_usage(&vm, false),
+            Ok(())
+        );
+    }
+
     #[test]
     fn check_used_cells_valid_case() {
         let program = program!["range_check", "output"];
@@ -1,2 +1,3 @@
+pub mod air_private_input;
 pub mod builtin_runner;
 pub mod cairo_runner;
@@ -49,6 +49,20 @@ pub fn get_perm_range_check_limits(
         })
 }
 
+/// Return the index of the first step at which two traces differ, along with the entries of
+/// both traces at that step, or None if they match.
+/// When one trace is a prefix of the other, they differ at the first step past the end of the
+/// shorter one, for which its entry is None.
+pub fn compare_traces(
+    a: &[TraceEntry],
+    b: &[TraceEntry],
+) -> Option<(usize, Option<TraceEntry>, Option<TraceEntry>)> {
+    (0..a.len().max(b.len()))
+        .map(|step| (step, a.get(step), b.get(step)))
+        .find(|(_, entry_a, entry_b)| entry_a != entry_b)
+        .map(|(step, entry_a, entry_b)| (step, entry_a.cloned(), entry_b.cloned()))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -113,4 +127,74 @@ mod test {
             Ok(Some((-31440, 16383))),
         );
     }
+
+    #[test]
+    fn compare_traces_identical() {
+        let trace = vec![
+            TraceEntry {
+                pc: (0, 0).into(),
+                ap: (1, 2).into(),
+                fp: (1, 2).into(),
+            },
+            TraceEntry {
+                pc: (0, 2).into(),
+                ap: (1, 3).into(),
+                fp: (1, 2).into(),
+            },
+        ];
+        assert_eq!(compare_traces(&trace, &trace.clone()), None);
+    }
+
+    #[test]
+    fn compare_traces_different_lengths() {
+        let trace = vec![
+            TraceEntry {
+                pc: (0, 0).into(),
+                ap: (1, 2).into(),
+                fp: (1, 2).into(),
+            },
+            TraceEntry {
+                pc: (0, 2).into(),
+                ap: (1, 3).into(),
+                fp: (1, 2).into(),
+            },
+        ];
+        assert_eq!(
+            compare_traces(&[], &trace),
+            Some((0, None, Some(trace[0].clone())))
+        );
+        assert_eq!(
+            compare_traces(&trace, &trace[..1]),
+            Some((1, Some(trace[1].clone()), None))
+        );
+    }
+
+    #[test]
+    fn compare_traces_divergent() {
+        let first = TraceEntry {
+            pc: (0, 0).into(),
+            ap: (1, 2).into(),
+            fp: (1, 2).into(),
+        };
+        let a = vec![
+            first.clone(),
+            TraceEntry {
+                pc: (0, 2).into(),
+                ap: (1, 3).into(),
+                fp: (1, 2).into(),
+            },
+        ];
+        let b = vec![
+            first,
+            TraceEntry {
+                pc: (0, 2).into(),
+                ap: (1, 4).into(),
+                fp: (1, 2).into(),
+            },
+        ];
+        assert_eq!(
+            compare_traces(&a, &b),
+            Some((1, Some(a[1].clone()), Some(b[1].clone())))
+        );
+    }
 }
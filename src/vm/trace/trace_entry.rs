@@ -1,16 +1,32 @@
 use crate::vm::errors::trace_errors::TraceError;
-use crate::{types::relocatable::Relocatable, vm::errors::memory_errors::MemoryError};
+use crate::vm::vm_core::{Operands, OperandsAddresses};
+use crate::{
+    types::{instruction::Instruction, relocatable::Relocatable},
+    vm::errors::memory_errors::MemoryError,
+};
 use serde::{Deserialize, Serialize};
 
 ///A trace entry for every instruction that was executed.
 ///Holds the register values before the instruction was executed.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TraceEntry {
     pub pc: Relocatable,
     pub ap: Relocatable,
     pub fp: Relocatable,
 }
 
+///An execution log entry for every instruction that was executed while the execution log was
+///enabled. Holds the decoded instruction, its resolved operands and their addresses, and the
+///register values before and after the instruction was executed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionLogEntry {
+    pub instruction: Instruction,
+    pub operands: Operands,
+    pub operands_addresses: OperandsAddresses,
+    pub registers_before: TraceEntry,
+    pub registers_after: TraceEntry,
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RelocatedTraceEntry {
     pub ap: usize,
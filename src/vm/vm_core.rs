@@ -12,11 +12,13 @@ use crate::{
         context::run_context::RunContext,
         decoding::decoder::decode_instruction,
         errors::{
-            exec_scope_errors::ExecScopeError, memory_errors::MemoryError,
+            exec_scope_errors::ExecScopeError, hint_errors::HintError, memory_errors::MemoryError,
             vm_errors::VirtualMachineError,
         },
-        runners::builtin_runner::{BuiltinRunner, RangeCheckBuiltinRunner, SignatureBuiltinRunner},
-        trace::trace_entry::TraceEntry,
+        runners::builtin_runner::{
+            BuiltinRunner, OutputBuiltinRunner, RangeCheckBuiltinRunner, SignatureBuiltinRunner,
+        },
+        trace::trace_entry::{ExecutionLogEntry, TraceEntry},
         vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
     },
 };
@@ -26,7 +28,7 @@ use std::{any::Any, borrow::Cow, collections::HashMap, ops::Add};
 
 const MAX_TRACEBACK_ENTRIES: u32 = 20;
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Operands {
     dst: MaybeRelocatable,
     res: Option<MaybeRelocatable>,
@@ -34,13 +36,45 @@ pub struct Operands {
     op1: MaybeRelocatable,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+impl Operands {
+    pub fn dst(&self) -> &MaybeRelocatable {
+        &self.dst
+    }
+
+    pub fn res(&self) -> Option<&MaybeRelocatable> {
+        self.res.as_ref()
+    }
+
+    pub fn op0(&self) -> &MaybeRelocatable {
+        &self.op0
+    }
+
+    pub fn op1(&self) -> &MaybeRelocatable {
+        &self.op1
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct OperandsAddresses {
     dst_addr: Relocatable,
     op0_addr: Relocatable,
     op1_addr: Relocatable,
 }
 
+impl OperandsAddresses {
+    pub fn dst_addr(&self) -> Relocatable {
+        self.dst_addr
+    }
+
+    pub fn op0_addr(&self) -> Relocatable {
+        self.op0_addr
+    }
+
+    pub fn op1_addr(&self) -> Relocatable {
+        self.op1_addr
+    }
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 pub struct DeducedOperands(u8);
 
@@ -82,11 +116,20 @@ pub struct VirtualMachine {
     pub(crate) memory: Memory,
     pub(crate) accessed_addresses: Option<Vec<Relocatable>>,
     pub(crate) trace: Option<Vec<TraceEntry>>,
+    execution_log: Option<Vec<ExecutionLogEntry>>,
     pub(crate) current_step: usize,
+    hint_invocation_count: usize,
     skip_instruction_execution: bool,
     run_finished: bool,
+    check_hint_writes: bool,
+    lenient_hints: bool,
+    hint_errors: Vec<(Relocatable, usize, HintError)>,
+    substitute_unknown_operands: bool,
 }
 
+/// Number of cells of each segment included in VirtualMachine::dump_state
+pub const DUMP_STATE_MEMORY_SAMPLE_SIZE: usize = 10;
+
 impl HintData {
     pub fn new(
         hint_code: &str,
@@ -124,10 +167,16 @@ impl VirtualMachine {
             // we could not change this value and faced an Error. This is the behaviour that the original VM implements also.
             accessed_addresses: Some(Vec::new()),
             trace,
+            execution_log: None,
             current_step: 0,
+            hint_invocation_count: 0,
             skip_instruction_execution: false,
             segments: MemorySegmentManager::new(),
             run_finished: false,
+            check_hint_writes: false,
+            lenient_hints: false,
+            hint_errors: Vec::new(),
+            substitute_unknown_operands: false,
         }
     }
 
@@ -200,7 +249,7 @@ impl VirtualMachine {
             },
             PcUpdate::JumpRel => match operands.res.clone() {
                 Some(res) => match res {
-                    MaybeRelocatable::Int(num_res) => self.run_context.pc.add_int(&num_res)?,
+                    MaybeRelocatable::Int(num_res) => self.run_context.pc.add_felt(&num_res)?,
 
                     _ => return Err(VirtualMachineError::PureValue),
                 },
@@ -447,17 +496,34 @@ impl VirtualMachine {
     }
 
     fn run_instruction(&mut self, instruction: Instruction) -> Result<(), VirtualMachineError> {
-        let (operands, operands_addresses, deduced_operands) =
-            self.compute_operands(&instruction)?;
+        let (operands, operands_addresses, deduced_operands) = loop {
+            match self.compute_operands(&instruction) {
+                Err(
+                    VirtualMachineError::FailedToComputeOperands(_, _) | VirtualMachineError::NoDst,
+                ) if self.substitute_unknown_operands
+                    && self.insert_zero_into_unknown_operand(&instruction)? => {}
+                result => break result?,
+            }
+        };
+        self.substitute_unknown_operands = false;
         self.insert_deduced_operands(deduced_operands, &operands, &operands_addresses)?;
         self.opcode_assertions(&instruction, &operands)?;
 
+        let registers_before = TraceEntry {
+            pc: self.run_context.pc,
+            ap: self.run_context.get_ap(),
+            fp: self.run_context.get_fp(),
+        };
+        let pending_log_entry = self.execution_log.is_some().then(|| ExecutionLogEntry {
+            instruction: instruction.clone(),
+            operands: operands.clone(),
+            operands_addresses: operands_addresses.clone(),
+            registers_before: registers_before.clone(),
+            registers_after: registers_before.clone(),
+        });
+
         if let Some(ref mut trace) = &mut self.trace {
-            trace.push(TraceEntry {
-                pc: self.run_context.pc,
-                ap: self.run_context.get_ap(),
-                fp: self.run_context.get_fp(),
-            });
+            trace.push(registers_before);
         }
 
         if let Some(ref mut accessed_addresses) = self.accessed_addresses {
@@ -467,6 +533,17 @@ impl VirtualMachine {
         }
 
         self.update_registers(instruction, operands)?;
+
+        if let (Some(execution_log), Some(mut entry)) = (&mut self.execution_log, pending_log_entry)
+        {
+            entry.registers_after = TraceEntry {
+                pc: self.run_context.pc,
+                ap: self.run_context.get_ap(),
+                fp: self.run_context.get_fp(),
+            };
+            execution_log.push(entry);
+        }
+
         self.current_step += 1;
         Ok(())
     }
@@ -493,16 +570,147 @@ impl VirtualMachine {
         hint_data_dictionary: &HashMap<usize, Vec<Box<dyn Any>>>,
         constants: &HashMap<String, Felt>,
     ) -> Result<(), VirtualMachineError> {
-        if let Some(hint_list) = hint_data_dictionary.get(&self.run_context.pc.offset) {
+        let pc = self.run_context.pc;
+        self.execute_hints_at_pc(
+            hint_executor,
+            exec_scopes,
+            hint_data_dictionary,
+            constants,
+            &pc,
+        )
+    }
+
+    ///Executes the hints attached to the given pc without running any instruction.
+    ///Together with step_instruction, it allows callers to build their own run loop.
+    pub fn execute_hints_at_pc(
+        &mut self,
+        hint_executor: &mut dyn HintProcessor,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data_dictionary: &HashMap<usize, Vec<Box<dyn Any>>>,
+        constants: &HashMap<String, Felt>,
+        pc: &Relocatable,
+    ) -> Result<(), VirtualMachineError> {
+        if let Some(hint_list) = hint_data_dictionary.get(&pc.offset) {
+            let instruction_cells = if self.check_hint_writes {
+                self.unknown_instruction_written_cells()?
+            } else {
+                Vec::new()
+            };
             for (hint_index, hint_data) in hint_list.iter().enumerate() {
-                hint_executor
-                    .execute_hint(self, exec_scopes, hint_data, constants)
-                    .map_err(|err| VirtualMachineError::Hint(hint_index, Box::new(err)))?
+                self.hint_invocation_count += 1;
+                if let Err(err) =
+                    hint_executor.execute_hint(self, exec_scopes, hint_data, constants)
+                {
+                    if !self.lenient_hints {
+                        return Err(VirtualMachineError::Hint(hint_index, Box::new(err)));
+                    }
+                    self.hint_errors.push((*pc, hint_index, err));
+                    self.substitute_unknown_operands = true;
+                }
+            }
+            for addr in instruction_cells {
+                if self.memory.get(&addr)?.is_some() {
+                    return Err(VirtualMachineError::HintWriteConflict(addr));
+                }
             }
         }
         Ok(())
     }
 
+    ///Enables a debug check that fails when a hint writes to a memory cell which the
+    ///instruction executed in the same step writes too (the destination of an assert_eq or
+    ///a call, and the return pc cell of a call). Disabled by default.
+    pub fn set_check_hint_writes(&mut self, check_hint_writes: bool) {
+        self.check_hint_writes = check_hint_writes;
+    }
+
+    ///Enables a lenient mode for analyzing partially broken programs: a failing hint is recorded
+    ///and the execution continues instead of aborting. Whatever the hint wrote before failing is
+    ///kept. If the instruction at the same pc then can't compute or deduce one of its operands,
+    ///zero is written to that operand's cell. Disabled by default, see
+    ///`CairoRunConfig::lenient_hints`.
+    pub fn set_lenient_hints(&mut self, lenient_hints: bool) {
+        self.lenient_hints = lenient_hints;
+    }
+
+    ///Returns the pc, index within the pc's hints and error of each hint which failed while
+    ///running in lenient mode, in order
+    pub fn get_hint_errors(&self) -> &[(Relocatable, usize, HintError)] {
+        &self.hint_errors
+    }
+
+    ///Writes zero to the first operand cell of the instruction without a value, in op0, op1 and
+    ///dst order. Returns false if all of them hold a value already.
+    fn insert_zero_into_unknown_operand(
+        &mut self,
+        instruction: &Instruction,
+    ) -> Result<bool, VirtualMachineError> {
+        let op0_addr = self.run_context.compute_op0_addr(instruction)?;
+        let op0 = self.memory.get(&op0_addr)?.map(Cow::into_owned);
+        let addr = match op0 {
+            None => op0_addr,
+            Some(op0) => {
+                let op1_addr = self.run_context.compute_op1_addr(instruction, Some(&op0))?;
+                let dst_addr = self.run_context.compute_dst_addr(instruction)?;
+                if self.memory.get(&op1_addr)?.is_none() {
+                    op1_addr
+                } else if self.memory.get(&dst_addr)?.is_none() {
+                    dst_addr
+                } else {
+                    return Ok(false);
+                }
+            }
+        };
+        self.memory
+            .insert(&addr, &MaybeRelocatable::from(Felt::zero()))?;
+        Ok(true)
+    }
+
+    ///Returns the number of instructions executed by this VM, which is its current step
+    pub fn instruction_count(&self) -> usize {
+        self.current_step
+    }
+
+    ///Returns the number of hint executions made by this VM, counting every hint of a pc each
+    ///time the pc is reached, including the failing hints recorded in lenient mode
+    pub fn hint_invocation_count(&self) -> usize {
+        self.hint_invocation_count
+    }
+
+    ///Enables recording an entry per executed instruction, holding the decoded instruction, its
+    ///operands and the registers before and after it. As every step is kept, this is meant for
+    ///debugging short runs. Disabled by default, disabling it drops the recorded entries.
+    pub fn set_execution_log_enabled(&mut self, enabled: bool) {
+        self.execution_log = enabled.then(Vec::new);
+    }
+
+    ///Returns the entries recorded since the execution log was enabled, in execution order
+    pub fn get_execution_log(&self) -> Option<&[ExecutionLogEntry]> {
+        self.execution_log.as_deref()
+    }
+
+    ///Returns the cells written by the current instruction which don't hold a value yet
+    fn unknown_instruction_written_cells(&self) -> Result<Vec<Relocatable>, VirtualMachineError> {
+        let instruction = self.decode_current_instruction()?;
+        let mut cells = Vec::new();
+        match instruction.opcode {
+            Opcode::AssertEq => cells.push(self.run_context.compute_dst_addr(&instruction)?),
+            Opcode::Call => {
+                cells.push(self.run_context.compute_dst_addr(&instruction)?);
+                cells.push(self.run_context.compute_op0_addr(&instruction)?);
+            }
+            Opcode::NOp | Opcode::Ret => {}
+        }
+        let mut unknown_cells = Vec::new();
+        for addr in cells {
+            if self.memory.get(&addr)?.is_none() {
+                unknown_cells.push(addr);
+            }
+        }
+        Ok(unknown_cells)
+    }
+
+    ///Executes the instruction at the current pc, ignoring its hints
     pub fn step_instruction(&mut self) -> Result<(), VirtualMachineError> {
         let instruction = self.decode_current_instruction()?;
         if !self.skip_instruction_execution {
@@ -666,20 +874,24 @@ impl VirtualMachine {
     }
 
     ///Makes sure that all assigned memory cells are consistent with their auto deduction rules.
-    pub fn verify_auto_deductions(&mut self) -> Result<(), VirtualMachineError> {
-        for (name, builtin) in self.builtin_runners.iter_mut() {
+    ///Returns an error with the conflicting address and values if a builtin cell doesn't match
+    ///the value deduced for it.
+    pub fn verify_auto_deductions(&self) -> Result<(), VirtualMachineError> {
+        for (name, builtin) in self.builtin_runners.iter() {
             let index: usize = builtin
                 .base()
                 .try_into()
                 .map_err(|_| MemoryError::AddressInTemporarySegment(builtin.base()))?;
             for (offset, value) in self.memory.data[index].iter().enumerate() {
+                let addr = Relocatable::from((index as isize, offset));
                 if let Some(deduced_memory_cell) = builtin
-                    .deduce_memory_cell(&Relocatable::from((index as isize, offset)), &self.memory)
+                    .deduce_memory_cell(&addr, &self.memory)
                     .map_err(VirtualMachineError::RunnerError)?
                 {
                     if Some(&deduced_memory_cell) != value.as_ref() && value.is_some() {
                         return Err(VirtualMachineError::InconsistentAutoDeduction(
                             name.to_owned(),
+                            addr,
                             deduced_memory_cell,
                             value.to_owned(),
                         ));
@@ -707,13 +919,21 @@ impl VirtualMachine {
         if !self.run_finished {
             return Err(VirtualMachineError::RunNotFinished);
         }
-        self.accessed_addresses
-            .as_mut()
-            .ok_or(VirtualMachineError::RunNotFinished)?
-            .extend((0..len).map(|i: usize| base + i));
+        if self.accessed_addresses.is_none() {
+            return Err(VirtualMachineError::RunNotFinished);
+        }
+        self.mark_range_as_accessed(base, len);
         Ok(())
     }
 
+    // Adds the range [base, base + len) to the accessed addresses while the run is in progress,
+    // for cells only accessed by hints.
+    pub(crate) fn mark_range_as_accessed(&mut self, base: Relocatable, len: usize) {
+        if let Some(ref mut accessed_addresses) = self.accessed_addresses {
+            accessed_addresses.extend((0..len).map(|i: usize| base + i));
+        }
+    }
+
     // Returns the values (fp, pc) corresponding to each call instruction in the traceback.
     // Returns the most recent call last.
     pub(crate) fn get_traceback_entries(&self) -> Vec<(Relocatable, Relocatable)> {
@@ -792,6 +1012,17 @@ impl VirtualMachine {
         self.run_context.get_pc()
     }
 
+    ///Returns true if the runner reached the end pc of the program, and false if the execution
+    ///was stopped before that (for example, by a step limit)
+    pub fn is_run_ended(&self) -> bool {
+        self.run_finished
+    }
+
+    ///Records that the runner reached the end pc of the program
+    pub(crate) fn mark_run_ended(&mut self) {
+        self.run_finished = true;
+    }
+
     ///Gets the integer value corresponding to the Relocatable address
     pub fn get_integer(&self, key: &Relocatable) -> Result<Cow<Felt>, VirtualMachineError> {
         self.memory.get_integer(key)
@@ -817,6 +1048,38 @@ impl VirtualMachine {
         }
     }
 
+    /// Returns a JSON snapshot of the VM for debugging: the registers, the size of every
+    /// segment and the first DUMP_STATE_MEMORY_SAMPLE_SIZE cells of each segment.
+    /// Addresses and values are written as strings, as felts don't fit in JSON numbers.
+    pub fn dump_state(&self) -> serde_json::Value {
+        let segment_sizes: Vec<usize> = self.memory.data.iter().map(Vec::len).collect();
+        let mut memory_sample = serde_json::Map::new();
+        for (segment_index, segment) in self.memory.data.iter().enumerate() {
+            for (offset, cell) in segment
+                .iter()
+                .take(DUMP_STATE_MEMORY_SAMPLE_SIZE)
+                .enumerate()
+            {
+                if let Some(value) = cell {
+                    memory_sample.insert(
+                        format!("{}:{}", segment_index, offset),
+                        serde_json::Value::String(value.to_string()),
+                    );
+                }
+            }
+        }
+        serde_json::json!({
+            "registers": {
+                "pc": self.run_context.pc.to_string(),
+                "ap": self.run_context.get_ap().to_string(),
+                "fp": self.run_context.get_fp().to_string(),
+            },
+            "current_step": self.current_step,
+            "segment_sizes": segment_sizes,
+            "memory_sample": memory_sample,
+        })
+    }
+
     /// Returns a reference to the vector with all builtins present in the virtual machine
     pub fn get_builtin_runners(&self) -> &Vec<(String, BuiltinRunner)> {
         &self.builtin_runners
@@ -826,6 +1089,22 @@ impl VirtualMachine {
         &mut self.builtin_runners
     }
 
+    /// Returns the builtin runner registered under `name` (e.g. "output" or "ecdsa"), if present
+    pub fn get_builtin_runner(&self, name: &str) -> Option<&BuiltinRunner> {
+        self.builtin_runners
+            .iter()
+            .find(|(builtin_name, _)| builtin_name == name)
+            .map(|(_, builtin)| builtin)
+    }
+
+    /// Returns a mutable reference to the builtin runner registered under `name`, if present
+    pub fn get_builtin_runner_mut(&mut self, name: &str) -> Option<&mut BuiltinRunner> {
+        self.builtin_runners
+            .iter_mut()
+            .find(|(builtin_name, _)| builtin_name == name)
+            .map(|(_, builtin)| builtin)
+    }
+
     ///Inserts a value into a memory address given by a Relocatable value
     pub fn insert_value<T: Into<MaybeRelocatable>>(
         &mut self,
@@ -915,6 +1194,13 @@ impl VirtualMachine {
 
         Err(VirtualMachineError::NoSignatureBuiltin)
     }
+
+    pub fn get_output_builtin(&mut self) -> Result<&mut OutputBuiltinRunner, VirtualMachineError> {
+        match self.get_builtin_runner_mut("output") {
+            Some(BuiltinRunner::Output(output_builtin)) => Ok(output_builtin),
+            _ => Err(VirtualMachineError::NoOutputBuiltin),
+        }
+    }
     pub fn disable_trace(&mut self) {
         self.trace = None
     }
@@ -993,7 +1279,9 @@ mod tests {
         vm::{
             errors::memory_errors::MemoryError,
             runners::{
-                builtin_runner::{BitwiseBuiltinRunner, EcOpBuiltinRunner, HashBuiltinRunner},
+                builtin_runner::{
+                    BitwiseBuiltinRunner, EcOpBuiltinRunner, HashBuiltinRunner, OutputBuiltinRunner,
+                },
                 cairo_runner::CairoRunner,
             },
         },
@@ -2348,6 +2636,41 @@ mod tests {
         assert!(addresses == expected_addresses);
     }
 
+    #[test]
+    fn compute_operands_add_assert_eq_without_executing() {
+        //[ap] = [ap + 1] + [ap + 2], with [ap + 2] deduced from the other operands
+        let inst = Instruction {
+            off0: 0,
+            off1: 1,
+            off2: 2,
+            imm: None,
+            dst_register: Register::AP,
+            op0_register: Register::AP,
+            op1_addr: Op1Addr::AP,
+            res: Res::Add,
+            pc_update: PcUpdate::Regular,
+            ap_update: ApUpdate::Regular,
+            fp_update: FpUpdate::Regular,
+            opcode: Opcode::AssertEq,
+        };
+
+        let mut vm = vm!();
+        run_context!(vm, 0, 0, 0);
+        vm.memory = memory![((1, 0), 5), ((1, 1), 2)];
+
+        let (operands, addresses, _) = vm.compute_operands(&inst).unwrap();
+        assert_eq!(operands.dst(), &MaybeRelocatable::from(Felt::new(5)));
+        assert_eq!(operands.op0(), &MaybeRelocatable::from(Felt::new(2)));
+        assert_eq!(operands.op1(), &MaybeRelocatable::from(Felt::new(3)));
+        assert_eq!(operands.res(), Some(&MaybeRelocatable::from(Felt::new(5))));
+        assert_eq!(addresses.dst_addr(), Relocatable::from((1, 0)));
+        assert_eq!(addresses.op0_addr(), Relocatable::from((1, 1)));
+        assert_eq!(addresses.op1_addr(), Relocatable::from((1, 2)));
+        //Nothing was written or executed
+        assert_eq!(vm.memory.get(&Relocatable::from((1, 2))), Ok(None));
+        assert_eq!(vm.current_step, 0);
+    }
+
     #[test]
     fn compute_operands_mul_fp() {
         let inst = Instruction {
@@ -2701,6 +3024,59 @@ mod tests {
         assert!(accessed_addresses.contains(&Relocatable::from((1, 1))));
     }
 
+    #[test]
+    fn execution_log_records_every_step() {
+        let mut vm = vm!();
+        vm.set_execution_log_enabled(true);
+        run_context!(vm, 0, 0, 0);
+        //[ap] = 1000, ap++; [ap] = 2000, ap++; [ap] = [ap - 2] + [ap - 1], ap++
+        vm.memory = memory![
+            ((0, 0), 5189976364521848832_i64),
+            ((0, 1), 1000),
+            ((0, 2), 5189976364521848832_i64),
+            ((0, 3), 2000),
+            ((0, 4), 5201798304953696256_i64)
+        ];
+        vm.memory.data.push(Vec::new());
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        for _ in 0..3 {
+            assert_eq!(
+                vm.step(
+                    &mut hint_processor,
+                    exec_scopes_ref!(),
+                    &HashMap::new(),
+                    &HashMap::new()
+                ),
+                Ok(())
+            );
+        }
+
+        let log = vm.get_execution_log().unwrap();
+        assert_eq!(log.len(), 3);
+        assert!(log
+            .iter()
+            .all(|entry| entry.instruction.opcode == Opcode::AssertEq));
+        assert_eq!(log[0].instruction.imm, Some(Felt::new(1000)));
+        assert_eq!(log[2].instruction.res, Res::Add);
+        assert_eq!(log[2].operands.op0(), &mayberelocatable!(1000));
+        assert_eq!(log[2].operands.op1(), &mayberelocatable!(2000));
+        assert_eq!(log[2].operands.dst(), &mayberelocatable!(3000));
+        assert_eq!(
+            log[2].operands_addresses.dst_addr(),
+            Relocatable::from((1, 2))
+        );
+        assert_eq!(log[1].registers_before.pc, Relocatable::from((0, 2)));
+        assert_eq!(log[1].registers_after.pc, Relocatable::from((0, 4)));
+        assert_eq!(log[1].registers_after.ap, Relocatable::from((1, 2)));
+        assert_eq!(log[2].registers_after.ap, Relocatable::from((1, 3)));
+    }
+
+    #[test]
+    fn execution_log_disabled_by_default() {
+        let vm = vm!();
+        assert!(vm.get_execution_log().is_none());
+    }
+
     #[test]
     /*
     Test for a simple program execution
@@ -2942,6 +3318,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify_auto_deductions_bitwise_correct_output() {
+        let mut builtin = BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true);
+        builtin.base = 2;
+        let mut vm = vm!();
+        vm.builtin_runners
+            .push((String::from("bitwise"), builtin.into()));
+        // 12 & 10 = 8, 12 ^ 10 = 6, 12 | 10 = 14
+        vm.memory = memory![
+            ((2, 0), 12),
+            ((2, 1), 10),
+            ((2, 2), 8),
+            ((2, 3), 6),
+            ((2, 4), 14)
+        ];
+        assert_eq!(vm.verify_auto_deductions(), Ok(()));
+    }
+
+    #[test]
+    fn verify_auto_deductions_bitwise_incorrect_output() {
+        let mut builtin = BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true);
+        builtin.base = 2;
+        let mut vm = vm!();
+        vm.builtin_runners
+            .push((String::from("bitwise"), builtin.into()));
+        // 12 & 10 = 8, but 9 was written
+        vm.memory = memory![((2, 0), 12), ((2, 1), 10), ((2, 2), 9)];
+        assert_eq!(
+            vm.verify_auto_deductions(),
+            Err(VirtualMachineError::InconsistentAutoDeduction(
+                String::from("bitwise"),
+                Relocatable::from((2, 2)),
+                MaybeRelocatable::from(Felt::new(8)),
+                Some(MaybeRelocatable::from(Felt::new(9)))
+            ))
+        );
+    }
+
     #[test]
     /* Program used:
     %builtins output pedersen
@@ -3280,6 +3694,7 @@ mod tests {
             error,
             Err(VirtualMachineError::InconsistentAutoDeduction(
                 String::from("ec_op"),
+                Relocatable::from((3, 5)),
                 MaybeRelocatable::Int(felt_str!(
                     "2739017437753868763038285897969098325279422804143820990343394856167768859289"
                 )),
@@ -3288,7 +3703,7 @@ mod tests {
                 )))
             ))
         );
-        assert_eq!(error.unwrap_err().to_string(), "Inconsistent auto-deduction for builtin ec_op, expected 2739017437753868763038285897969098325279422804143820990343394856167768859289, got Some(Int(2778063437308421278851140253538604815869848682781135193774472480292420096757))");
+        assert_eq!(error.unwrap_err().to_string(), "Inconsistent auto-deduction for builtin ec_op at address 3:5, expected 2739017437753868763038285897969098325279422804143820990343394856167768859289, got Some(Int(2778063437308421278851140253538604815869848682781135193774472480292420096757))");
     }
 
     #[test]
@@ -3474,6 +3889,293 @@ mod tests {
         );
     }
 
+    #[test]
+    fn manual_step_cycle_matches_step() {
+        //Same program as in test_step_for_preset_memory_with_alloc_hint
+        let hint_data_dictionary = HashMap::from([(
+            0_usize,
+            vec![any_box!(HintProcessorData::new_default(
+                "memory[ap] = segments.add()".to_string(),
+                HashMap::new(),
+            ))],
+        )]);
+        let new_vm = || {
+            let mut vm = vm!(true);
+            run_context!(vm, 3, 2, 2);
+            for _ in 0..2 {
+                vm.segments.add(&mut vm.memory);
+            }
+            vm.memory = memory![
+                ((0, 0), 290341444919459839_i64),
+                ((0, 1), 1),
+                ((0, 2), 2345108766317314046_i64),
+                ((0, 3), 1226245742482522112_i64),
+                (
+                    (0, 4),
+                    (
+                        "3618502788666131213697322783095070105623107215331596699973092056135872020478",
+                        10
+                    )
+                ),
+                ((0, 5), 5189976364521848832_i64),
+                ((0, 6), 1),
+                ((0, 7), 4611826758063128575_i64),
+                ((0, 8), 2345108766317314046_i64),
+                ((1, 0), (2, 0)),
+                ((1, 1), (3, 0))
+            ];
+            vm
+        };
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+
+        let mut vm = new_vm();
+        for _ in 0..6 {
+            assert_eq!(
+                vm.step(
+                    &mut hint_processor,
+                    exec_scopes_ref!(),
+                    &hint_data_dictionary,
+                    &HashMap::new()
+                ),
+                Ok(())
+            );
+        }
+
+        let mut manual_vm = new_vm();
+        for _ in 0..6 {
+            let pc = *manual_vm.get_pc();
+            assert_eq!(
+                manual_vm.execute_hints_at_pc(
+                    &mut hint_processor,
+                    exec_scopes_ref!(),
+                    &hint_data_dictionary,
+                    &HashMap::new(),
+                    &pc
+                ),
+                Ok(())
+            );
+            assert_eq!(manual_vm.step_instruction(), Ok(()));
+        }
+
+        assert_eq!(manual_vm.trace, vm.trace);
+        assert_eq!(manual_vm.run_context.pc, vm.run_context.pc);
+        assert_eq!(manual_vm.run_context.ap, vm.run_context.ap);
+        assert_eq!(manual_vm.run_context.fp, vm.run_context.fp);
+        assert_eq!(manual_vm.memory.data, vm.memory.data);
+    }
+
+    fn alloc_program_vm() -> VirtualMachine {
+        //Same program as in test_step_for_preset_memory_with_alloc_hint
+        let mut vm = vm!();
+        run_context!(vm, 3, 2, 2);
+        for _ in 0..2 {
+            vm.segments.add(&mut vm.memory);
+        }
+        vm.memory = memory![
+            ((0, 0), 290341444919459839_i64),
+            ((0, 1), 1),
+            ((0, 2), 2345108766317314046_i64),
+            ((0, 3), 1226245742482522112_i64),
+            (
+                (0, 4),
+                (
+                    "3618502788666131213697322783095070105623107215331596699973092056135872020478",
+                    10
+                )
+            ),
+            ((0, 5), 5189976364521848832_i64),
+            ((0, 6), 1),
+            ((0, 7), 4611826758063128575_i64),
+            ((0, 8), 2345108766317314046_i64),
+            ((1, 0), (2, 0)),
+            ((1, 1), (3, 0))
+        ];
+        vm
+    }
+
+    #[test]
+    fn check_hint_writes_allows_non_conflicting_hints() {
+        let hint_data_dictionary = HashMap::from([(
+            0_usize,
+            vec![any_box!(HintProcessorData::new_default(
+                "memory[ap] = segments.add()".to_string(),
+                HashMap::new(),
+            ))],
+        )]);
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut vm = alloc_program_vm();
+        vm.set_check_hint_writes(true);
+        for _ in 0..6 {
+            assert_eq!(
+                vm.step(
+                    &mut hint_processor,
+                    exec_scopes_ref!(),
+                    &hint_data_dictionary,
+                    &HashMap::new()
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn check_hint_writes_detects_conflicting_hint() {
+        //The hint writes [ap], which the call instruction at pc 3 uses to store the old fp
+        let hint_data_dictionary = HashMap::from([(
+            3_usize,
+            vec![any_box!(HintProcessorData::new_default(
+                "memory[ap] = segments.add()".to_string(),
+                HashMap::new(),
+            ))],
+        )]);
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut vm = alloc_program_vm();
+        vm.set_check_hint_writes(true);
+        assert_eq!(
+            vm.step(
+                &mut hint_processor,
+                exec_scopes_ref!(),
+                &hint_data_dictionary,
+                &HashMap::new()
+            ),
+            Err(VirtualMachineError::HintWriteConflict((1, 2).into()))
+        );
+    }
+
+    #[test]
+    fn lenient_hints_collects_failing_hint() {
+        let hint_data_dictionary = HashMap::from([(
+            0_usize,
+            vec![
+                any_box!(HintProcessorData::new_default(
+                    "raise Exception('broken hint')".to_string(),
+                    HashMap::new(),
+                )),
+                any_box!(HintProcessorData::new_default(
+                    "memory[ap] = segments.add()".to_string(),
+                    HashMap::new(),
+                )),
+            ],
+        )]);
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut vm = alloc_program_vm();
+        vm.set_lenient_hints(true);
+        for _ in 0..6 {
+            assert_eq!(
+                vm.step(
+                    &mut hint_processor,
+                    exec_scopes_ref!(),
+                    &hint_data_dictionary,
+                    &HashMap::new()
+                ),
+                Ok(())
+            );
+        }
+        assert_eq!(
+            vm.get_hint_errors(),
+            &[(
+                Relocatable::from((0, 0)),
+                0,
+                HintError::UnknownHint("raise Exception('broken hint')".to_string())
+            )]
+        );
+        //The hint following the failing one was still executed
+        assert_eq!(vm.segments.num_segments, 3);
+    }
+
+    #[test]
+    fn instruction_and_hint_invocation_counts() {
+        let hint_data_dictionary = HashMap::from([(
+            0_usize,
+            vec![any_box!(HintProcessorData::new_default(
+                "memory[ap] = segments.add()".to_string(),
+                HashMap::new(),
+            ))],
+        )]);
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut vm = alloc_program_vm();
+        for _ in 0..6 {
+            assert_eq!(
+                vm.step(
+                    &mut hint_processor,
+                    exec_scopes_ref!(),
+                    &hint_data_dictionary,
+                    &HashMap::new()
+                ),
+                Ok(())
+            );
+        }
+        //The program runs 6 instructions and reaches the hint's pc once
+        assert_eq!(vm.instruction_count(), 6);
+        assert_eq!(vm.hint_invocation_count(), 1);
+    }
+
+    #[test]
+    fn failing_hint_aborts_without_lenient_hints() {
+        let hint_data_dictionary = HashMap::from([(
+            0_usize,
+            vec![any_box!(HintProcessorData::new_default(
+                "raise Exception('broken hint')".to_string(),
+                HashMap::new(),
+            ))],
+        )]);
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut vm = alloc_program_vm();
+        assert_eq!(
+            vm.step(
+                &mut hint_processor,
+                exec_scopes_ref!(),
+                &hint_data_dictionary,
+                &HashMap::new()
+            ),
+            Err(VirtualMachineError::Hint(
+                0,
+                Box::new(HintError::UnknownHint(
+                    "raise Exception('broken hint')".to_string()
+                ))
+            ))
+        );
+        assert!(vm.get_hint_errors().is_empty());
+    }
+
+    #[test]
+    fn dump_state_contains_registers() {
+        let mut vm = vm!();
+        run_context!(vm, 3, 2, 1);
+        vm.current_step = 4;
+        vm.memory = memory![((0, 0), 5), ((1, 1), (0, 0))];
+        let state = vm.dump_state();
+        assert_eq!(state["registers"]["pc"], "0:3");
+        assert_eq!(state["registers"]["ap"], "1:2");
+        assert_eq!(state["registers"]["fp"], "1:1");
+        assert_eq!(state["current_step"], 4);
+        assert_eq!(state["segment_sizes"], serde_json::json!([1, 2]));
+        assert_eq!(
+            state["memory_sample"],
+            serde_json::json!({"0:0": "5", "1:1": "0:0"})
+        );
+    }
+
+    #[test]
+    fn dump_state_samples_the_start_of_each_segment() {
+        let mut vm = vm!();
+        vm.memory = Memory::new();
+        vm.memory.data.push(
+            (0..DUMP_STATE_MEMORY_SAMPLE_SIZE + 5)
+                .map(|i| Some(MaybeRelocatable::from(Felt::new(i))))
+                .collect(),
+        );
+        let state = vm.dump_state();
+        assert_eq!(
+            state["segment_sizes"],
+            serde_json::json!([DUMP_STATE_MEMORY_SAMPLE_SIZE + 5])
+        );
+        assert_eq!(
+            state["memory_sample"].as_object().unwrap().len(),
+            DUMP_STATE_MEMORY_SAMPLE_SIZE
+        );
+    }
+
     #[test]
     fn test_get_builtin_runners() {
         let mut vm = vm!();
@@ -3490,6 +4192,57 @@ mod tests {
         assert_eq!(builtins[1].0, "bitwise");
     }
 
+    #[test]
+    fn get_builtin_runner_by_name() {
+        let mut vm = vm!();
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        vm.builtin_runners.push((
+            String::from("pedersen"),
+            HashBuiltinRunner::new(8, true).into(),
+        ));
+        vm.builtin_runners
+            .push((String::from("output"), output_builtin.into()));
+        vm.insert_value(&(0, 0).into(), Felt::new(7)).unwrap();
+        vm.insert_value(&(0, 1).into(), Felt::new(9)).unwrap();
+
+        let output_base = match vm.get_builtin_runner("output") {
+            Some(BuiltinRunner::Output(output)) => output.base(),
+            _ => unreachable!(),
+        };
+        let values: Vec<Felt> = (0..2)
+            .map(|offset| {
+                vm.get_integer(&(output_base, offset).into())
+                    .unwrap()
+                    .into_owned()
+            })
+            .collect();
+        assert_eq!(values, vec![Felt::new(7), Felt::new(9)]);
+        assert!(vm.get_builtin_runner("ecdsa").is_none());
+    }
+
+    #[test]
+    fn get_output_builtin_by_name() {
+        let mut vm = vm!();
+        assert_eq!(
+            vm.get_output_builtin().err(),
+            Some(VirtualMachineError::NoOutputBuiltin)
+        );
+        vm.builtin_runners.push((
+            String::from("pedersen"),
+            HashBuiltinRunner::new(8, true).into(),
+        ));
+        vm.builtin_runners.push((
+            String::from("output"),
+            OutputBuiltinRunner::new(true).into(),
+        ));
+        assert!(vm.get_output_builtin().is_ok());
+        assert!(matches!(
+            vm.get_builtin_runner_mut("pedersen"),
+            Some(BuiltinRunner::Hash(_))
+        ));
+    }
+
     #[test]
     fn disable_trace() {
         let mut vm = VirtualMachine::new(true);
@@ -3562,7 +4315,9 @@ mod tests {
 
         assert_eq!(
             vm.get_continuous_range(&MaybeRelocatable::from((1, 0)), 3),
-            Err(MemoryError::GetRangeMemoryGap)
+            Err(MemoryError::GetRangeMemoryGap(MaybeRelocatable::from((
+                1, 2
+            ))))
         );
     }
 
@@ -6,8 +6,11 @@ use crate::{
 use felt::Felt;
 use std::{
     borrow::Cow,
+    cell::{Ref, RefCell},
     collections::{HashMap, HashSet},
+    fs,
     mem::swap,
+    path::Path,
 };
 
 pub struct ValidationRule(
@@ -16,6 +19,8 @@ pub struct ValidationRule(
 );
 
 pub struct Memory {
+    // Each segment is stored as a vector indexed by offset, so cell access doesn't require hashing.
+    // Gaps between inserted cells are filled with None.
     pub data: Vec<Vec<Option<MaybeRelocatable>>>,
     pub temp_data: Vec<Vec<Option<MaybeRelocatable>>>,
     // relocation_rules's keys map to temp_data's indices and therefore begin at
@@ -23,6 +28,12 @@ pub struct Memory {
     pub(crate) relocation_rules: HashMap<usize, Relocatable>,
     pub validated_addresses: HashSet<MaybeRelocatable>,
     validation_rules: HashMap<usize, ValidationRule>,
+    // Number of reads of each address, only recorded once counting has been enabled.
+    // Reads take &self, hence the RefCell.
+    access_counts: Option<RefCell<HashMap<Relocatable, usize>>>,
+    // When set, inserts allocate missing segments and keep the existing value of a cell instead
+    // of checking it against the new one. Only meant for trusted programs.
+    pub(crate) skip_checks: bool,
 }
 
 impl Memory {
@@ -33,6 +44,8 @@ impl Memory {
             relocation_rules: HashMap::new(),
             validated_addresses: HashSet::<MaybeRelocatable>::new(),
             validation_rules: HashMap::new(),
+            access_counts: None,
+            skip_checks: false,
         }
     }
     ///Inserts an MaybeRelocatable value into an address given by a MaybeRelocatable::Relocatable
@@ -57,6 +70,11 @@ impl Memory {
         };
 
         let data_len = data.len();
+        //MemorySegmentManager catches up with the segments allocated here, see
+        //MemorySegmentManager::add
+        if self.skip_checks && !relocatable.segment_index.is_negative() && value_index >= data_len {
+            data.resize(value_index + 1, Vec::new());
+        }
         let segment = data
             .get_mut(value_index)
             .ok_or(MemoryError::UnallocatedSegment(value_index, data_len))?;
@@ -71,7 +89,7 @@ impl Memory {
         match segment[value_offset] {
             None => segment[value_offset] = Some(val),
             Some(ref current_value) => {
-                if current_value != &val {
+                if !self.skip_checks && current_value != &val {
                     //Existing memory cannot be changed
                     return Err(MemoryError::InconsistentMemory(
                         relocatable.into(),
@@ -104,6 +122,9 @@ impl Memory {
         let (i, j) = from_relocatable_to_indexes(&relocatable);
         if data.len() > i && data[i].len() > j {
             if let Some(ref element) = data[i][j] {
+                if let Some(access_counts) = &self.access_counts {
+                    *access_counts.borrow_mut().entry(relocatable).or_insert(0) += 1;
+                }
                 return Ok(Some(self.relocate_value(element)));
             }
         }
@@ -111,6 +132,19 @@ impl Memory {
         Ok(None)
     }
 
+    /// Starts recording how many times each memory cell is read. Counts gathered before a call
+    /// to this method are kept.
+    pub fn enable_access_counting(&mut self) {
+        self.access_counts.get_or_insert_with(Default::default);
+    }
+
+    /// Returns the number of reads of each address holding a value, or None if access counting
+    /// wasn't enabled. Addresses are the ones requested, before relocation rules are applied.
+    /// The returned guard must be dropped before memory is read again.
+    pub fn access_counts(&self) -> Option<Ref<HashMap<Relocatable, usize>>> {
+        self.access_counts.as_ref().map(RefCell::borrow)
+    }
+
     /// Relocates the memory according to the relocation rules and clears `self.relocaction_rules`.
     pub fn relocate_memory(&mut self) -> Result<(), MemoryError> {
         if self.relocation_rules.is_empty() {
@@ -249,6 +283,13 @@ impl Memory {
         self.validation_rules.insert(segment_index, rule);
     }
 
+    ///Returns the indexes of the segments which have a validation rule attached, in ascending order
+    pub fn validation_rule_segments(&self) -> Vec<usize> {
+        let mut segments: Vec<usize> = self.validation_rules.keys().copied().collect();
+        segments.sort_unstable();
+        segments
+    }
+
     fn validate_memory_cell(&mut self, address: &MaybeRelocatable) -> Result<(), MemoryError> {
         if let &MaybeRelocatable::RelocatableValue(ref rel_addr) = address {
             if !self.validated_addresses.contains(address) {
@@ -297,9 +338,10 @@ impl Memory {
         let mut values = Vec::with_capacity(size);
 
         for i in 0..size {
-            values.push(match self.get(&addr.add_usize(i))? {
+            let cell_addr = addr.add_usize(i);
+            values.push(match self.get(&cell_addr)? {
                 Some(elem) => elem.into_owned(),
-                None => return Err(MemoryError::GetRangeMemoryGap),
+                None => return Err(MemoryError::GetRangeMemoryGap(cell_addr)),
             });
         }
 
@@ -319,6 +361,76 @@ impl Memory {
 
         Ok(values)
     }
+
+    /// Compares the memory against a dump in the binary format written by `cairo-run
+    /// --memory_file`: (address, value) pairs of 8 and 32 little endian bytes. Segments are
+    /// relocated one after the other starting at address 1, as the runner does.
+    /// Returns the first address, in relocated order, at which they differ.
+    pub fn assert_matches_dump(&self, path: &Path) -> Result<(), MemoryError> {
+        let bytes = fs::read(path).map_err(|e| MemoryError::MemoryDumpRead(e.to_string()))?;
+        if bytes.len() % 40 != 0 {
+            return Err(MemoryError::MalformedMemoryDump(bytes.len()));
+        }
+        let mut expected: HashMap<usize, Felt> = HashMap::new();
+        for pair in bytes.chunks_exact(40) {
+            let mut addr = [0; 8];
+            addr.copy_from_slice(&pair[..8]);
+            let mut value = pair[8..].to_vec();
+            value.reverse();
+            expected.insert(
+                u64::from_le_bytes(addr) as usize,
+                Felt::from_bytes_be(&value),
+            );
+        }
+
+        let mut bases = vec![1];
+        for segment in self.data.iter() {
+            bases.push(bases[bases.len() - 1] + segment.len());
+        }
+        let relocate = |addr: &Relocatable| -> Result<usize, MemoryError> {
+            if addr.segment_index < 0 {
+                return Err(MemoryError::TemporarySegmentInRelocation(
+                    addr.segment_index,
+                ));
+            }
+            bases
+                .get(addr.segment_index as usize)
+                .map(|base| base + addr.offset)
+                .ok_or(MemoryError::Relocation)
+        };
+
+        let mut found: Vec<Option<Felt>> = vec![None; bases[bases.len() - 1]];
+        for (index, segment) in self.data.iter().enumerate() {
+            for (offset, cell) in segment.iter().enumerate() {
+                found[bases[index] + offset] = match cell {
+                    Some(MaybeRelocatable::Int(num)) => Some(num.clone()),
+                    Some(MaybeRelocatable::RelocatableValue(rel)) => {
+                        Some(Felt::new(relocate(rel)?))
+                    }
+                    None => None,
+                };
+            }
+        }
+
+        let end = expected
+            .keys()
+            .map(|addr| addr + 1)
+            .max()
+            .unwrap_or(0)
+            .max(found.len());
+        for addr in 0..end {
+            let expected_value = expected.get(&addr);
+            let found_value = found.get(addr).and_then(Option::as_ref);
+            if expected_value != found_value {
+                return Err(MemoryError::MemoryDumpMismatch(
+                    addr,
+                    expected_value.cloned(),
+                    found_value.cloned(),
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 pub(crate) trait RelocateValue<'a, Input: 'a, Output: 'a> {
@@ -490,6 +602,46 @@ mod memory_tests {
         );
     }
 
+    #[test]
+    fn access_counts_disabled_by_default() {
+        let memory = memory![((0, 0), 1)];
+        assert!(memory.get(&Relocatable::from((0, 0))).unwrap().is_some());
+        assert!(memory.access_counts().is_none());
+    }
+
+    #[test]
+    fn access_counts_record_reads() {
+        let mut memory = memory![((0, 0), 1), ((0, 1), 2)];
+        memory.enable_access_counting();
+        let key = Relocatable::from((0, 0));
+        for _ in 0..3 {
+            assert_eq!(memory.get_integer(&key).unwrap().as_ref(), &Felt::new(1));
+        }
+        //Reading an empty cell isn't counted
+        assert!(memory.get(&Relocatable::from((0, 2))).unwrap().is_none());
+        let access_counts = memory.access_counts().unwrap();
+        assert_eq!(access_counts.get(&key), Some(&3));
+        assert_eq!(access_counts.len(), 1);
+    }
+
+    #[test]
+    fn insert_skipping_checks() {
+        let mut memory = Memory::new();
+        memory.skip_checks = true;
+        let key = Relocatable::from((2, 0));
+        //Segment 2 is allocated on insertion
+        assert_eq!(memory.insert(&key, &mayberelocatable!(5)), Ok(()));
+        assert_eq!(memory.data.len(), 3);
+        //The first value written to a cell is kept
+        assert_eq!(memory.insert(&key, &mayberelocatable!(6)), Ok(()));
+        assert_eq!(memory.get_integer(&key).unwrap().as_ref(), &Felt::new(5));
+        //Temporary segments aren't allocated on insertion
+        assert_eq!(
+            memory.insert(&Relocatable::from((-1, 0)), &mayberelocatable!(5)),
+            Err(MemoryError::UnallocatedSegment(0, 0))
+        );
+    }
+
     #[test]
     fn insert_non_allocated_memory() {
         let key = MaybeRelocatable::from((0, 0));
@@ -557,6 +709,39 @@ mod memory_tests {
         assert_eq!(memory.get(&MaybeRelocatable::from((0, 4))).unwrap(), None);
     }
 
+    #[test]
+    fn segment_data_is_indexed_by_offset() {
+        let mut memory = Memory::new();
+        memory.data.push(Vec::new());
+        memory.data.push(Vec::new());
+        for offset in (0..1000).step_by(3) {
+            memory
+                .insert(
+                    &MaybeRelocatable::from((1, offset)),
+                    &MaybeRelocatable::from(Felt::new(offset)),
+                )
+                .unwrap();
+        }
+        // The segment grows up to the last inserted offset, without touching other segments
+        assert_eq!(memory.data[0].len(), 0);
+        assert_eq!(memory.data[1].len(), 1000);
+        for (offset, cell) in memory.data[1].iter().enumerate() {
+            if offset % 3 == 0 {
+                assert_eq!(cell, &Some(MaybeRelocatable::from(Felt::new(offset))));
+                assert_eq!(
+                    memory
+                        .get(&MaybeRelocatable::from((1, offset)))
+                        .unwrap()
+                        .unwrap()
+                        .as_ref(),
+                    &MaybeRelocatable::from(Felt::new(offset))
+                );
+            } else {
+                assert_eq!(cell, &None);
+            }
+        }
+    }
+
     #[test]
     fn from_array_test() {
         let mem = memory_from(
@@ -573,6 +758,19 @@ mod memory_tests {
         ));
     }
 
+    #[test]
+    fn validation_rule_segments_after_range_check_initialization() {
+        let mut segments = MemorySegmentManager::new();
+        let mut memory = Memory::new();
+        assert!(memory.validation_rule_segments().is_empty());
+        //The range check segment isn't the first one
+        segments.add(&mut memory);
+        let mut builtin = RangeCheckBuiltinRunner::new(8, 8, true);
+        builtin.initialize_segments(&mut segments, &mut memory);
+        assert_eq!(builtin.add_validation_rule(&mut memory), Ok(()));
+        assert_eq!(memory.validation_rule_segments(), vec![1]);
+    }
+
     #[test]
     fn validate_existing_memory_for_range_check_within_bounds() {
         let mut builtin = RangeCheckBuiltinRunner::new(8, 8, true);
@@ -937,7 +1135,30 @@ mod memory_tests {
 
         assert_eq!(
             memory.get_continuous_range(&MaybeRelocatable::from((1, 0)), 3),
-            Err(MemoryError::GetRangeMemoryGap)
+            Err(MemoryError::GetRangeMemoryGap(MaybeRelocatable::from((
+                1, 2
+            ))))
+        );
+    }
+
+    #[test]
+    fn get_continuous_range_with_relocatable_values() {
+        let memory = memory![((1, 0), (2, 0)), ((1, 1), 3), ((1, 2), (2, 5)), ((2, 0), 1)];
+
+        assert_eq!(
+            memory.get_continuous_range(&MaybeRelocatable::from((1, 0)), 3),
+            Ok(vec![
+                MaybeRelocatable::from((2, 0)),
+                MaybeRelocatable::from(Felt::new(3)),
+                MaybeRelocatable::from((2, 5)),
+            ])
+        );
+        //The first hole is reported
+        assert_eq!(
+            memory.get_continuous_range(&MaybeRelocatable::from((2, 0)), 3),
+            Err(MemoryError::GetRangeMemoryGap(MaybeRelocatable::from((
+                2, 1
+            ))))
         );
     }
 
@@ -995,4 +1216,53 @@ mod memory_tests {
         );
         assert!(memory.temp_data.is_empty());
     }
+
+    fn write_memory_dump(name: &str, cells: &[(u64, i32)]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let mut bytes = Vec::new();
+        for (addr, value) in cells {
+            bytes.extend(addr.to_le_bytes());
+            let mut value_bytes = vec![0; 32];
+            let be_bytes = Felt::new(*value).to_bytes_be();
+            value_bytes[32 - be_bytes.len()..].copy_from_slice(&be_bytes);
+            value_bytes.reverse();
+            bytes.extend(value_bytes);
+        }
+        fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn assert_matches_dump_matching() {
+        let memory = memory![((0, 0), 5), ((0, 1), (1, 0)), ((1, 0), 7)];
+        //Segment 0 is relocated to address 1 and segment 1 to address 3
+        let path = write_memory_dump("memory_dump_matching.memory", &[(1, 5), (2, 3), (3, 7)]);
+        assert_eq!(memory.assert_matches_dump(&path), Ok(()));
+    }
+
+    #[test]
+    fn assert_matches_dump_mismatching() {
+        let memory = memory![((0, 0), 5), ((0, 1), (1, 0)), ((1, 0), 7)];
+        let path = write_memory_dump(
+            "memory_dump_mismatching.memory",
+            &[(1, 5), (2, 4), (3, 7), (4, 1)],
+        );
+        assert_eq!(
+            memory.assert_matches_dump(&path),
+            Err(MemoryError::MemoryDumpMismatch(
+                2,
+                Some(Felt::new(4)),
+                Some(Felt::new(3))
+            ))
+        );
+        //Cells missing from the memory are reported too
+        let path = write_memory_dump(
+            "memory_dump_extra_cell.memory",
+            &[(1, 5), (2, 3), (3, 7), (4, 1)],
+        );
+        assert_eq!(
+            memory.assert_matches_dump(&path),
+            Err(MemoryError::MemoryDumpMismatch(4, Some(Felt::new(1)), None))
+        );
+    }
 }
@@ -26,7 +26,12 @@ pub struct MemorySegmentManager {
 
 impl MemorySegmentManager {
     ///Adds a new segment and returns its starting location as a RelocatableValue.
+    ///When the memory skips its checks, the segments it allocated on insertion are counted
+    ///first, so the new segment comes after them.
     pub fn add(&mut self, memory: &mut Memory) -> Relocatable {
+        if memory.skip_checks {
+            self.num_segments = self.num_segments.max(memory.data.len());
+        }
         let segment_index = self.num_segments;
         self.num_segments += 1;
         memory.data.push(Vec::new());
@@ -47,6 +52,16 @@ impl MemorySegmentManager {
         }
     }
 
+    ///Returns the number of segments added so far, excluding temporary segments.
+    pub fn num_segments(&self) -> usize {
+        self.num_segments
+    }
+
+    ///Returns the number of temporary segments added so far.
+    pub fn num_temp_segments(&self) -> usize {
+        self.num_temp_segments
+    }
+
     ///Writes data into the memory at address ptr and returns the first address after the data.
     pub fn load_data(
         &mut self,
@@ -72,6 +87,9 @@ impl MemorySegmentManager {
 
     /// Calculates the size (number of non-none elements) of each memory segment.
     pub fn compute_effective_sizes(&mut self, memory: &Memory) -> &Vec<usize> {
+        if memory.skip_checks {
+            self.num_segments = self.num_segments.max(memory.data.len());
+        }
         self.segment_used_sizes
             .get_or_insert_with(|| memory.data.iter().map(Vec::len).collect())
     }
@@ -82,6 +100,8 @@ impl MemorySegmentManager {
         self.segment_used_sizes.as_ref()?.get(index).copied()
     }
 
+    ///Returns the finalized size of the segment, or its used size if it wasn't finalized.
+    ///Returns None if neither is known.
     pub fn get_segment_size(&self, index: usize) -> Option<usize> {
         self.segment_sizes
             .get(&index)
@@ -89,6 +109,16 @@ impl MemorySegmentManager {
             .or_else(|| self.get_segment_used_size(index))
     }
 
+    ///Returns true if addr points into an allocated (non-temporary) segment and, once the size
+    ///of that segment is known, falls within it.
+    pub fn is_valid_address(&self, addr: Relocatable) -> bool {
+        if addr.segment_index < 0 || addr.segment_index as usize >= self.num_segments {
+            return false;
+        }
+        self.get_segment_size(addr.segment_index as usize)
+            .map_or(true, |size| addr.offset < size)
+    }
+
     ///Returns a vector that contains the first relocated address of each memory segment
     pub fn relocate_segments(&self) -> Result<Vec<usize>, MemoryError> {
         let first_addr = 1;
@@ -225,6 +255,30 @@ impl MemorySegmentManager {
             .sum())
     }
 
+    /// Returns the public memory cells of every segment as (relocated address, page id) pairs,
+    /// given the relocated base of each segment (as returned by `relocate_segments`).
+    pub fn get_public_memory_addresses(
+        &self,
+        segment_offsets: &[usize],
+    ) -> Result<Vec<(usize, usize)>, MemoryError> {
+        let mut addresses = Vec::new();
+        for segment_index in 0..self.num_segments {
+            let offsets = match self.public_memory_offsets.get(&segment_index) {
+                Some(offsets) => offsets,
+                None => continue,
+            };
+            let segment_start = segment_offsets
+                .get(segment_index)
+                .ok_or(MemoryError::SegmentNotFinalized(segment_index))?;
+            addresses.extend(
+                offsets
+                    .iter()
+                    .map(|(offset, page_id)| (segment_start + offset, *page_id)),
+            );
+        }
+        Ok(addresses)
+    }
+
     // Writes the following information for the given segment:
     // * size - The size of the segment (to be used in relocate_segments).
     // * public_memory - A list of offsets for memory cells that will be considered as public
@@ -285,6 +339,20 @@ mod tests {
         assert_eq!(segments.num_segments, 2);
     }
 
+    #[test]
+    fn add_segment_after_segments_allocated_skipping_checks() {
+        let mut segments = MemorySegmentManager::new();
+        let mut memory = Memory::new();
+        memory.skip_checks = true;
+        segments.add(&mut memory);
+        memory
+            .insert(&relocatable!(2, 0), &MaybeRelocatable::from(Felt::new(7)))
+            .unwrap();
+        assert_eq!(segments.add(&mut memory), relocatable!(3, 0));
+        assert_eq!(segments.num_segments, 4);
+        assert_eq!(memory.data.len(), 4);
+    }
+
     #[test]
     fn add_one_temporary_segment() {
         let mut segments = MemorySegmentManager::new();
@@ -723,6 +791,42 @@ mod tests {
         assert_eq!(memory_segment_manager.get_segment_size(0), Some(5));
     }
 
+    #[test]
+    fn is_valid_address_within_segment() {
+        let mut segments = MemorySegmentManager::new();
+        let mut memory = Memory::new();
+        segments.add(&mut memory);
+        segments.add(&mut memory);
+        //Until the sizes are known, any offset of an allocated segment is accepted
+        assert!(segments.is_valid_address(relocatable!(1, 100)));
+        segments.segment_used_sizes = Some(vec![3, 2]);
+        assert!(segments.is_valid_address(relocatable!(0, 2)));
+        assert!(segments.is_valid_address(relocatable!(1, 1)));
+    }
+
+    #[test]
+    fn is_valid_address_past_segment_end() {
+        let mut segments = MemorySegmentManager::new();
+        let mut memory = Memory::new();
+        segments.add(&mut memory);
+        segments.segment_used_sizes = Some(vec![3]);
+        assert!(!segments.is_valid_address(relocatable!(0, 3)));
+        //A finalized size takes precedence over the used one
+        segments.segment_sizes = HashMap::from([(0, 5)]);
+        assert!(segments.is_valid_address(relocatable!(0, 4)));
+        assert!(!segments.is_valid_address(relocatable!(0, 5)));
+    }
+
+    #[test]
+    fn is_valid_address_unallocated_segment() {
+        let mut segments = MemorySegmentManager::new();
+        let mut memory = Memory::new();
+        segments.add(&mut memory);
+        segments.add_temporary_segment(&mut memory);
+        assert!(!segments.is_valid_address(relocatable!(1, 0)));
+        assert!(!segments.is_valid_address(relocatable!(-1, 0)));
+    }
+
     /// Test that the call to .gen_arg() with a relocatable just passes the
     /// value through.
     #[test]
@@ -845,6 +949,35 @@ mod tests {
         assert_eq!(segments.segment_sizes, HashMap::from([(0, 42)]));
     }
 
+    #[test]
+    fn get_public_memory_addresses_with_segment_offsets() {
+        let mut segments = MemorySegmentManager::new();
+        segments.num_segments = 3;
+        segments.finalize(None, 0, Some(&vec![(0, 0), (1, 0)]));
+        segments.finalize(None, 2, Some(&vec![(1, 3)]));
+        assert_eq!(
+            segments.get_public_memory_addresses(&[1, 3, 7]),
+            Ok(vec![(1, 0), (2, 0), (8, 3)])
+        );
+        assert_eq!(
+            segments.get_public_memory_addresses(&[1, 3]),
+            Err(MemoryError::SegmentNotFinalized(2))
+        );
+    }
+
+    #[test]
+    fn num_segments_and_num_temp_segments() {
+        let mut segments = MemorySegmentManager::new();
+        let mut memory = Memory::new();
+        for _ in 0..3 {
+            segments.add(&mut memory);
+        }
+        segments.add_temporary_segment(&mut memory);
+        segments.add_temporary_segment(&mut memory);
+        assert_eq!(segments.num_segments(), 3);
+        assert_eq!(segments.num_temp_segments(), 2);
+    }
+
     #[test]
     fn gen_cairo_arg_single() {
         let mut memory_segment_manager = MemorySegmentManager::new();
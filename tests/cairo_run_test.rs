@@ -935,6 +935,21 @@ fn cairo_run_secp_ec() {
     .expect("Couldn't run program");
 }
 
+#[test]
+fn cairo_run_ec_mul_small_scalar() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/ec_mul_small_scalar.json"),
+        "main",
+        false,
+        false,
+        "all",
+        false,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
 #[test]
 fn cairo_run_blake2s_hello_world_hash() {
     let mut hint_executor = BuiltinHintProcessor::new_empty();